@@ -36,19 +36,17 @@ impl SerialisedShrekSuperSlamGameObject for GfDb {
     /// Prefer calling [`Bin::get_object_from_offset`] rather than calling
     /// this method.
     fn new(bin: &Bin, offset: usize) -> Result<GfDb, Error> {
-        let raw = &bin.raw;
         let c = bin.console;
 
         // +14 contains the offset to the start of the array of objects in the DB.
         // +18 contains a count of the number of objects in this array.
         const OBJECT_ENTRY_SIZE: usize = 0x10;
         let objects_initial_offset =
-            Bin::header_length() + c.read_u32(&raw[offset + 0x14..offset + 0x18])? as usize;
-        let objects_count = c.read_u32(&raw[offset + 0x18..offset + 0x1C])? as usize;
-        let objects_end_offset = objects_initial_offset + (objects_count * OBJECT_ENTRY_SIZE);
+            Bin::header_length() + c.read_u32(bin.read_at(offset + 0x14, 0x04)?)? as usize;
+        let objects_count = c.read_u32(bin.read_at(offset + 0x18, 0x04)?)? as usize;
 
-        let objects: Result<Vec<(String, BinObject)>, Error> = raw
-            [objects_initial_offset..objects_end_offset]
+        let objects: Result<Vec<(String, BinObject)>, Error> = bin
+            .read_at(objects_initial_offset, objects_count * OBJECT_ENTRY_SIZE)?
             .chunks(OBJECT_ENTRY_SIZE)
             .map(|entry_bytes| create_object_entry(entry_bytes, bin, c))
             .collect();
@@ -66,7 +64,7 @@ fn create_object_entry(raw: &[u8], bin: &Bin, c: Console) -> Result<(String, Bin
 
     // +04 is the offset to the actual entry in the .bin file
     let object_offset = c.read_u32(&raw[0x04..0x08])?;
-    let object = BinObject::new(&bin.raw, object_offset, c)?;
+    let object = BinObject::with_registry(&bin.raw, object_offset, c, &bin.registry)?;
 
     Ok((name, object))
 }
@@ -1,6 +1,8 @@
 use std::error;
 use std::fmt;
 
+use crate::classes::class_name_for_hash;
+
 /// Error type for errors caused by trying to read or write serialised game
 /// classes from the Shrek SuperSlam .bin files
 #[derive(Debug)]
@@ -15,6 +17,26 @@ pub enum Error {
     /// Caused by requesting an object that does not match the type of object
     /// at the given offset
     IncorrectType { hash: u32 },
+
+    /// Caused by requesting an object at an offset where no object begins
+    ObjectNotFound { offset: u32 },
+
+    /// Caused by trying to remove an object that other parts of the file still
+    /// point to, containing the offsets of each of those references
+    ObjectReferenced { offset: u32, references: Vec<u32> },
+
+    /// Caused by trying to remove an object when values after it that may be
+    /// offsets are not in fields known to hold offsets, containing the
+    /// offsets of each of those values
+    UnknownReferences { offset: u32, references: Vec<u32> },
+
+    /// Caused by trying to write an attack whose number of hitboxes differs
+    /// from the number of hitboxes it has within the file
+    HitboxCountMismatch { hitboxes: usize, offsets: usize },
+
+    /// Caused by trying to write a spitter whose number of keyframes differs
+    /// from the number of keyframes it has within the file
+    KeyframeCountMismatch { keyframes: usize, offsets: usize },
 }
 
 impl error::Error for Error {}
@@ -31,10 +53,42 @@ impl fmt::Display for Error {
                 "File size of {} is not large enough for object of length {} at offset {}",
                 file_size, requested, offset
             ),
-            Error::IncorrectType { hash } => write!(
+            Error::IncorrectType { hash } => match class_name_for_hash(*hash) {
+                Some(name) => write!(
+                    f,
+                    "Incorrect hash at offset - hash was instead 0x{:04X} ({})",
+                    hash, name
+                ),
+                None => write!(
+                    f,
+                    "Incorrect hash at offset - hash was instead 0x{:04X}",
+                    hash
+                ),
+            },
+            Error::ObjectNotFound { offset } => {
+                write!(f, "No object begins at offset 0x{:X}", offset)
+            }
+            Error::ObjectReferenced { offset, references } => write!(
+                f,
+                "Object at offset 0x{:X} is still referenced from {} other location(s)",
+                offset,
+                references.len()
+            ),
+            Error::UnknownReferences { offset, references } => write!(
+                f,
+                "Object at offset 0x{:X} cannot be removed, as {} value(s) may be offsets within unsupported objects",
+                offset,
+                references.len()
+            ),
+            Error::HitboxCountMismatch { hitboxes, offsets } => write!(
+                f,
+                "Attack has {} hitbox(es) but the file has space for {}",
+                hitboxes, offsets
+            ),
+            Error::KeyframeCountMismatch { keyframes, offsets } => write!(
                 f,
-                "Incorrect hash at offset - hash was instead 0x{:04X}",
-                hash
+                "Spitter has {} keyframe(s) but the file has space for {}",
+                keyframes, offsets
             ),
         }
     }
@@ -7,7 +7,7 @@ use crate::files::Bin;
 /// Structure representing the in-game `Game::EventSequence` object type.
 ///
 /// This contains a series of events to be executed in a sequence.
-#[derive(Deserialize, Serialize)]
+#[derive(Clone, Deserialize, PartialEq, Serialize)]
 pub struct EventSequence {
     /// The offsets to the events in the sequence.
     pub event_offsets: Vec<u32>,
@@ -37,11 +37,10 @@ impl SerialisedShrekSuperSlamGameObject for EventSequence {
     /// Prefer calling [`Bin::get_object_from_offset`] rather than calling
     /// this method.
     fn new(bin: &Bin, offset: usize) -> Result<EventSequence, Error> {
-        let raw = &bin.raw;
         let c = bin.console;
 
         // Read numeric fields
-        let event_count = c.read_u32(&raw[offset + 0x08..offset + 0x0C])? as usize;
+        let event_count = c.read_u32(bin.read_at(offset + 0x08, 0x04)?)? as usize;
 
         // Read the offset to the events array, then read each offset in the
         // events array.
@@ -50,11 +49,11 @@ impl SerialisedShrekSuperSlamGameObject for EventSequence {
         //       for these, and so it could be one of many different event types.
         //       Does the current type system for extracting objects from .bin
         //       files handle this? If not, how can it?
-        let events_array_offset = c.read_u32(&raw[offset + 0x04..offset + 0x08])? as usize;
+        let events_array_offset = c.read_u32(bin.read_at(offset + 0x04, 0x04)?)? as usize;
         let event_offsets: Result<Vec<u32>, Error> = (0..event_count)
             .map(|i| {
                 let event_offset_offset = events_array_offset + Bin::header_length() + (i * 4);
-                c.read_u32(&raw[event_offset_offset..event_offset_offset + 4])
+                c.read_u32(bin.read_at(event_offset_offset, 4)?)
             })
             .collect();
         let event_offsets = event_offsets?;
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::Console;
+use crate::classes;
 use crate::classes::{EventSequence, SerialisedShrekSuperSlamGameObject, WriteableShrekSuperSlamGameObject};
 use crate::errors::Error;
 use crate::files::Bin;
@@ -10,7 +10,7 @@ use crate::files::Bin;
 /// A 'spitter' is what players get slammed into, and is responsible for
 /// spawning the player back onto the battlefield, and the slam event
 /// animations.
-#[derive(Deserialize, Serialize)]
+#[derive(Clone, Default, Deserialize, Serialize)]
 pub struct Spitter {
     /// The keyframes of the spitter.
     pub keyframes: Vec<SpitterKeyframe>,
@@ -39,6 +39,21 @@ pub struct Spitter {
     keyframe_offsets: Vec<u32>,
 }
 
+impl PartialEq for Spitter {
+    /// Compares the fields of two spitters, ignoring where their keyframes
+    /// are within the file, so that identical spitters at different offsets
+    /// are equal.
+    fn eq(&self, other: &Spitter) -> bool {
+        self.keyframes == other.keyframes
+            && self.unknown_004 == other.unknown_004
+            && self.unknown_038 == other.unknown_038
+            && self.unknown_044 == other.unknown_044
+            && self.unknown_045 == other.unknown_045
+            && self.unknown_046 == other.unknown_046
+            && self.unknown_047 == other.unknown_047
+    }
+}
+
 impl SerialisedShrekSuperSlamGameObject for Spitter {
     /// Returns the hashcode for the `Game::Spitter` in-game object.
     fn hash() -> u32 {
@@ -63,24 +78,23 @@ impl SerialisedShrekSuperSlamGameObject for Spitter {
     /// Prefer calling [`Bin::get_object_from_offset`] rather than calling
     /// this method.
     fn new(bin: &Bin, offset: usize) -> Result<Spitter, Error> {
-        let raw = &bin.raw;
         let c = bin.console;
 
         // Read the list of keyframe offsets, and use those to read each keyframe
-        let keyframe_offsets = Spitter::keyframe_offsets(&raw, offset, c)?;
+        let keyframe_offsets = Spitter::keyframe_offsets(bin, offset)?;
         let keyframes = keyframe_offsets
             .iter()
-            .map(|o| bin.get_object_from_offset::<SpitterKeyframe>(*o).unwrap())
-            .collect();
+            .map(|o| bin.get_object_from_offset::<SpitterKeyframe>(*o))
+            .collect::<Result<Vec<SpitterKeyframe>, Error>>()?;
 
         // Unknown fields
-        let unknown_004 = c.read_u32(&raw[offset + 0x04..offset + 0x08])?;
-        let unknown_038 = c.read_u32(&raw[offset + 0x38..offset + 0x3C])?;
+        let unknown_004 = c.read_u32(bin.read_at(offset + 0x04, 0x04)?)?;
+        let unknown_038 = c.read_u32(bin.read_at(offset + 0x38, 0x04)?)?;
 
-        let unknown_044 = raw[offset + 0x44];
-        let unknown_045 = raw[offset + 0x45];
-        let unknown_046 = raw[offset + 0x46];
-        let unknown_047 = raw[offset + 0x47];
+        let unknown_044 = c.read_u8(bin.read_at(offset + 0x44, 0x01)?)?;
+        let unknown_045 = c.read_u8(bin.read_at(offset + 0x45, 0x01)?)?;
+        let unknown_046 = c.read_u8(bin.read_at(offset + 0x46, 0x01)?)?;
+        let unknown_047 = c.read_u8(bin.read_at(offset + 0x47, 0x01)?)?;
 
         Ok(Spitter {
             keyframes,
@@ -103,6 +117,23 @@ impl WriteableShrekSuperSlamGameObject for Spitter {
         // invalidate all offsets
         let c = bin.console;
 
+        // Keyframes cannot be added or removed in place, so refuse to write
+        // anything rather than silently dropping some of them. The keyframes
+        // are always written over those of the object being replaced, as this
+        // spitter may have been read from elsewhere in the file
+        let keyframe_offsets: Vec<u32> = Spitter::keyframe_offsets(bin, offset)?
+            .iter()
+            .map(|o| o + Bin::header_length() as u32)
+            .collect();
+        if keyframe_offsets.len() != self.keyframes.len() {
+            return Err(Error::ClassDeserialiseError(
+                classes::Error::KeyframeCountMismatch {
+                    keyframes: self.keyframes.len(),
+                    offsets: keyframe_offsets.len(),
+                },
+            ));
+        }
+
         // Unknown fields
         bin.raw
             .splice(offset + 0x04..offset + 0x08, c.write_u32(self.unknown_004)?);
@@ -115,22 +146,6 @@ impl WriteableShrekSuperSlamGameObject for Spitter {
         bin.raw[offset + 0x47] = self.unknown_047;
 
         // Write the spitter's keyframes back to the .bin file
-        //
-        // If this Spitter was deserialised (e.g. from a JSON version),
-        // we will not know where the keyframes are supposed to go in the .bin
-        // file, so read out the offsets from the object that we are about to
-        // replace
-        let keyframes_count = c.read_u32(&bin.raw[offset + 0x24..offset + 0x28])? as usize;
-        let keyframe_offsets = if keyframes_count > self.keyframe_offsets.len()
-        {
-            Spitter::keyframe_offsets(&bin.raw, offset, c)?
-                .iter()
-                .map(|o| o + Bin::header_length() as u32)
-                .collect()
-        } else {
-            self.keyframe_offsets.clone()
-        };
-
         for (offset, keyframe) in keyframe_offsets.iter().zip(self.keyframes.iter()) {
             keyframe.write(bin, *offset as usize)?;
         }
@@ -140,8 +155,64 @@ impl WriteableShrekSuperSlamGameObject for Spitter {
 }
 
 impl Spitter {
+    /// Returns each of the spitter's keyframes, paired with the
+    /// `Game::EventSequence` run on that keyframe, if any.
+    ///
+    /// # Remarks
+    ///
+    /// The events are read from the given `bin`, which should be the file the
+    /// spitter was read from. A spitter deserialised from elsewhere (e.g. from
+    /// a JSON version) does not know where its keyframes are within the file,
+    /// so each of its keyframes is instead paired with its own
+    /// [`event`](SpitterKeyframe::event).
+    ///
+    /// # Errors
+    ///
+    /// Returns an Err if a keyframe refers to an event that is not a
+    /// `Game::EventSequence`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use shrek_superslam::Console;
+    /// use shrek_superslam::classes::Spitter;
+    /// use shrek_superslam::files::Bin;
+    ///
+    /// // Count the events run throughout a slam animation
+    /// # let my_bin_bytes = vec![0x00, 0x01, 0x02];
+    /// let bin = Bin::new(my_bin_bytes, Console::PC).unwrap();
+    /// let spitter = bin.get_object_from_offset::<Spitter>(0x1000).unwrap();
+    /// let events = spitter
+    ///     .resolved_keyframes(&bin)
+    ///     .unwrap()
+    ///     .iter()
+    ///     .filter(|(_, event)| event.is_some())
+    ///     .count();
+    /// println!("The spitter runs {} events", events);
+    /// ```
+    pub fn resolved_keyframes(
+        &self,
+        bin: &Bin,
+    ) -> Result<Vec<(SpitterKeyframe, Option<EventSequence>)>, Error> {
+        self.keyframes
+            .iter()
+            .enumerate()
+            .map(|(i, keyframe)| {
+                let event = match self.keyframe_offsets.get(i) {
+                    Some(offset) => SpitterKeyframe::read_event(
+                        bin,
+                        Bin::header_length() + *offset as usize,
+                    )?,
+                    None => keyframe.event.clone(),
+                };
+                Ok((keyframe.clone(), event))
+            })
+            .collect()
+    }
+
     /// Retrieve a list of offsets for a spitter's keyframes within the .bin file
-    fn keyframe_offsets(raw: &[u8], offset: usize, console: Console) -> Result<Vec<u32>, Error> {
+    fn keyframe_offsets(bin: &Bin, offset: usize) -> Result<Vec<u32>, Error> {
+        let console = bin.console;
         // Offset 0x20 of the Spitter contains an offset within the .bin
         // file to a list of further offsets, each of which points to an
         // SpitterKeyframe object. These are the keyframes for the Spitter.
@@ -151,12 +222,12 @@ impl Spitter {
         //
         // We later use this information to construct a list of AttackMoveRegion
         // objects for the attack.
-        let keyframes_count = console.read_u32(&raw[offset + 0x24..offset + 0x28])? as usize;
-        let keyframes_offset = console.read_u32(&raw[offset + 0x20..offset + 0x24])?;
+        let keyframes_count = console.read_u32(bin.read_at(offset + 0x24, 0x04)?)? as usize;
+        let keyframes_offset = console.read_u32(bin.read_at(offset + 0x20, 0x04)?)?;
         (0..keyframes_count)
             .map(|i| {
                 let region_offset_offset = keyframes_offset as usize + Bin::header_length() + (i * 4);
-                console.read_u32(&raw[region_offset_offset..region_offset_offset + 4])
+                console.read_u32(bin.read_at(region_offset_offset, 4)?)
             })
             .collect()
     }
@@ -165,7 +236,7 @@ impl Spitter {
 /// Structure representing the in-game `Game::SpitterKeyframe` object type.
 ///
 /// This represents an individual keyframe within a spitter animation.
-#[derive(Deserialize, Serialize)]
+#[derive(Clone, Default, Deserialize, PartialEq, Serialize)]
 pub struct SpitterKeyframe {
     /// Unknown property at offset +00c.
     pub unknown_00c: u32,
@@ -199,24 +270,40 @@ impl SerialisedShrekSuperSlamGameObject for SpitterKeyframe {
     /// Prefer calling [`Bin::get_object_from_offset`] rather than calling
     /// this method.
     fn new(bin: &Bin, offset: usize) -> Result<SpitterKeyframe, Error> {
-        let raw = &bin.raw;
         let c = bin.console;
 
         // Unknown fields
-        let unknown_00c = c.read_u32(&raw[offset + 0x0C..offset + 0x10])?;
+        let unknown_00c = c.read_u32(bin.read_at(offset + 0x0C, 0x04)?)?;
 
-        // The offset to a EventSequence, if any, is at +BC
-        let sequence_event_offset = c.read_u32(&raw[offset + 0xBC..offset + 0xC0])?;
-        let event = if sequence_event_offset != 0 {
-            Some(bin.get_object_from_offset::<EventSequence>(sequence_event_offset)?)
-        } else {
-            None
-        };
+        let event = SpitterKeyframe::read_event(bin, offset)?;
 
         Ok(SpitterKeyframe { unknown_00c, event, })
     }
 }
 
+impl SpitterKeyframe {
+    /// Retrieve the event run on a keyframe
+    ///
+    /// # Parameters
+    ///
+    /// - `bin`: The .bin file containing the keyframe
+    /// - `offset`: The offset the keyframe starts at within the file
+    ///
+    /// # Returns
+    ///
+    /// The `Game::EventSequence` the keyframe runs, or None if the keyframe
+    /// does not run an event.
+    fn read_event(bin: &Bin, offset: usize) -> Result<Option<EventSequence>, Error> {
+        // The offset to a EventSequence, if any, is at +BC
+        let sequence_event_offset = bin.console.read_u32(bin.read_at(offset + 0xBC, 0x04)?)?;
+        if sequence_event_offset != 0 {
+            Ok(Some(bin.get_object_from_offset::<EventSequence>(sequence_event_offset)?))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
 impl WriteableShrekSuperSlamGameObject for SpitterKeyframe {
     /// Writes the object back to its `bin` file at the given `offset`.
     fn write(&self, bin: &mut Bin, offset: usize) -> Result<(), Error> {
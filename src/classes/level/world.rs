@@ -7,7 +7,7 @@ use crate::files::Bin;
 /// Structure representing the in-game `Game::GameWorld` object type.
 ///
 /// This contains information about a level.
-#[derive(Deserialize, Serialize)]
+#[derive(Clone, Default, Deserialize, PartialEq, Serialize)]
 pub struct GameWorld {
     // Setting this field to anything but 1 locks the players in place. It is
     // always set to 1, at least in the files. Possibly modified at runtime?
@@ -49,20 +49,17 @@ impl SerialisedShrekSuperSlamGameObject for GameWorld {
     /// Prefer calling [`Bin::get_object_from_offset`] rather than calling
     /// this method.
     fn new(bin: &Bin, offset: usize) -> Result<GameWorld, Error> {
-        let raw = &bin.raw;
         let c = bin.console;
 
         // Playable flag is at +14
-        let playable = c.read_u32(&raw[offset + 0x14..offset + 0x18])?;
+        let playable = c.read_u32(bin.read_at(offset + 0x14, 0x04)?)?;
 
         // Weird floats set one begin at +30
-        let unknown_float_1_x = c.read_f32(&raw[offset + 0x30..offset + 0x34])?;
-        let unknown_float_1_y = c.read_f32(&raw[offset + 0x34..offset + 0x38])?;
-        let unknown_float_1_z = c.read_f32(&raw[offset + 0x38..offset + 0x3C])?;
+        let [unknown_float_1_x, unknown_float_1_y, unknown_float_1_z] =
+            c.read_vec3(bin.read_at(offset + 0x30, 0x0C)?)?;
 
-        let unknown_float_2_x = c.read_f32(&raw[offset + 0x40..offset + 0x44])?;
-        let unknown_float_2_y = c.read_f32(&raw[offset + 0x44..offset + 0x48])?;
-        let unknown_float_2_z = c.read_f32(&raw[offset + 0x48..offset + 0x4C])?;
+        let [unknown_float_2_x, unknown_float_2_y, unknown_float_2_z] =
+            c.read_vec3(bin.read_at(offset + 0x40, 0x0C)?)?;
 
         Ok(GameWorld {
             playable,
@@ -86,19 +83,23 @@ impl WriteableShrekSuperSlamGameObject for GameWorld {
         bin.raw
             .splice(offset + 0x14..offset + 0x18, c.write_u32(self.playable)?);
 
-        bin.raw
-            .splice(offset + 0x30..offset + 0x34, c.write_f32(self.unknown_float_1_x)?);
-        bin.raw
-            .splice(offset + 0x34..offset + 0x38, c.write_f32(self.unknown_float_1_y)?);
-        bin.raw
-            .splice(offset + 0x38..offset + 0x3C, c.write_f32(self.unknown_float_1_z)?);
+        bin.raw.splice(
+            offset + 0x30..offset + 0x3C,
+            c.write_vec3([
+                self.unknown_float_1_x,
+                self.unknown_float_1_y,
+                self.unknown_float_1_z,
+            ])?,
+        );
 
-        bin.raw
-            .splice(offset + 0x40..offset + 0x44, c.write_f32(self.unknown_float_2_x)?);
-        bin.raw
-            .splice(offset + 0x44..offset + 0x48, c.write_f32(self.unknown_float_2_y)?);
-        bin.raw
-            .splice(offset + 0x48..offset + 0x4C, c.write_f32(self.unknown_float_2_z)?);
+        bin.raw.splice(
+            offset + 0x40..offset + 0x4C,
+            c.write_vec3([
+                self.unknown_float_2_x,
+                self.unknown_float_2_y,
+                self.unknown_float_2_z,
+            ])?,
+        );
 
         Ok(())
     }
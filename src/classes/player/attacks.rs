@@ -1,15 +1,15 @@
 use serde::{Deserialize, Serialize};
 
+use crate::classes;
 use crate::classes::{SerialisedShrekSuperSlamGameObject, WriteableShrekSuperSlamGameObject};
 use crate::errors::Error;
 use crate::files::Bin;
-use crate::Console;
 
 /// Structure representing the in-game `Game::AttackMoveType` object type.
 ///
 /// This type represents a single attack, from a player character or from an
 /// item.
-#[derive(Deserialize, Serialize)]
+#[derive(Clone, Default, Deserialize, PartialEq, Serialize)]
 pub struct AttackMoveType {
     /// The distance at which the attack homes in on the opponent.
     pub aim_range: f32,
@@ -234,16 +234,6 @@ pub struct AttackMoveType {
 
     /// Unknown property at offset +134
     pub unknown_134: f32,
-
-    /// The offsets within the player.db.bin file of each hitbox, in the same
-    /// order they exist within the hitboxes property.
-    #[serde(skip)]
-    hitbox_offsets: Vec<u32>,
-
-    /// The offset within the player.db.bin file the projectile exists at,
-    /// if there is any projectile.
-    #[serde(skip)]
-    projectile_offset: Option<u32>,
 }
 
 impl SerialisedShrekSuperSlamGameObject for AttackMoveType {
@@ -270,101 +260,94 @@ impl SerialisedShrekSuperSlamGameObject for AttackMoveType {
     /// Prefer calling [`Bin::get_object_from_offset`] rather than calling
     /// this method.
     fn new(bin: &Bin, offset: usize) -> Result<AttackMoveType, Error> {
-        let raw = &bin.raw;
         let c = bin.console;
 
         // Read numeric fields
-        let endlag = c.read_f32(&raw[offset + 0x04..offset + 0x08])?;
-        let invincibility = c.read_f32(&raw[offset + 0x0C..offset + 0x10])?;
-        let fall_speed = c.read_f32(&raw[offset + 0x14..offset + 0x18])?;
-        let name_offset = c.read_u32(&raw[offset + 0x28..offset + 0x2C])?;
-        let aim_range = c.read_f32(&raw[offset + 0x74..offset + 0x78])?;
-        let damage1 = c.read_f32(&raw[offset + 0x84..offset + 0x88])?;
-        let damage2 = c.read_f32(&raw[offset + 0x88..offset + 0x8C])?;
-        let damage3 = c.read_f32(&raw[offset + 0x8C..offset + 0x90])?;
-        let charge_effect = c.read_f32(&raw[offset + 0x94..offset + 0x98])?;
-        let charge = c.read_f32(&raw[offset + 0x98..offset + 0x9C])?;
-        let multi_hit_speed = c.read_f32(&raw[offset + 0xA0..offset + 0xA4])?;
-        let stun = c.read_f32(&raw[offset + 0xA4..offset + 0xA8])?;
-        let shield_break_stun_time = c.read_f32(&raw[offset + 0xA8..offset + 0xAC])?;
-        let horizontal_knockback1 = c.read_f32(&raw[offset + 0xAC..offset + 0xB0])?;
-        let horizontal_knockback2 = c.read_f32(&raw[offset + 0xB0..offset + 0xB4])?;
-        let horizontal_knockback3 = c.read_f32(&raw[offset + 0xB4..offset + 0xB8])?;
-        let vertical_knockback1 = c.read_f32(&raw[offset + 0xB8..offset + 0xBC])?;
-        let vertical_knockback2 = c.read_f32(&raw[offset + 0xBC..offset + 0xC0])?;
-        let vertical_knockback3 = c.read_f32(&raw[offset + 0xC0..offset + 0xC4])?;
-        let animation_speed_on_hit = c.read_f32(&raw[offset + 0x138..offset + 0x13C])?;
-        let animation_speed_on_miss = c.read_f32(&raw[offset + 0x13C..offset + 0x140])?;
-
-        let unknown_008 = c.read_f32(&raw[offset + 0x08..offset + 0x0C])?;
-        let unknown_010 = c.read_f32(&raw[offset + 0x10..offset + 0x14])?;
-        let unknown_018 = c.read_f32(&raw[offset + 0x18..offset + 0x1C])?;
-        let unknown_03c = c.read_u32(&raw[offset + 0x3C..offset + 0x40])?;
-        let unknown_070 = c.read_f32(&raw[offset + 0x70..offset + 0x74])?;
-        let unknown_078 = c.read_f32(&raw[offset + 0x78..offset + 0x7C])?;
-        let unknown_07c = c.read_f32(&raw[offset + 0x7C..offset + 0x80])?;
-        let unknown_090 = c.read_f32(&raw[offset + 0x90..offset + 0x94])?;
-        let unknown_0c4 = c.read_f32(&raw[offset + 0xC4..offset + 0xC8])?;
-        let unknown_0c8 = c.read_f32(&raw[offset + 0xC8..offset + 0xCC])?;
-        let unknown_0d8 = c.read_f32(&raw[offset + 0xD8..offset + 0xDC])?;
-        let unknown_0dc = c.read_f32(&raw[offset + 0xDC..offset + 0xE0])?;
-        let unknown_0e0 = c.read_f32(&raw[offset + 0xE0..offset + 0xE4])?;
-        let unknown_0e4 = c.read_f32(&raw[offset + 0xE4..offset + 0xE8])?;
-        let unknown_0e8 = c.read_f32(&raw[offset + 0xE8..offset + 0xEC])?;
-        let unknown_0ec = c.read_f32(&raw[offset + 0xEC..offset + 0xF0])?;
-        let unknown_120 = c.read_f32(&raw[offset + 0x120..offset + 0x124])?;
-        let unknown_124 = c.read_f32(&raw[offset + 0x124..offset + 0x128])?;
-        let unknown_128 = c.read_f32(&raw[offset + 0x128..offset + 0x12C])?;
-        let unknown_12c = c.read_f32(&raw[offset + 0x12C..offset + 0x130])?;
-        let unknown_130 = c.read_f32(&raw[offset + 0x130..offset + 0x134])?;
-        let unknown_134 = c.read_f32(&raw[offset + 0x134..offset + 0x138])?;
+        let endlag = c.read_f32(bin.read_at(offset + 0x04, 0x04)?)?;
+        let invincibility = c.read_f32(bin.read_at(offset + 0x0C, 0x04)?)?;
+        let fall_speed = c.read_f32(bin.read_at(offset + 0x14, 0x04)?)?;
+        let name_offset = c.read_u32(bin.read_at(offset + 0x28, 0x04)?)?;
+        let aim_range = c.read_f32(bin.read_at(offset + 0x74, 0x04)?)?;
+        let damage1 = c.read_f32(bin.read_at(offset + 0x84, 0x04)?)?;
+        let damage2 = c.read_f32(bin.read_at(offset + 0x88, 0x04)?)?;
+        let damage3 = c.read_f32(bin.read_at(offset + 0x8C, 0x04)?)?;
+        let charge_effect = c.read_f32(bin.read_at(offset + 0x94, 0x04)?)?;
+        let charge = c.read_f32(bin.read_at(offset + 0x98, 0x04)?)?;
+        let multi_hit_speed = c.read_f32(bin.read_at(offset + 0xA0, 0x04)?)?;
+        let stun = c.read_f32(bin.read_at(offset + 0xA4, 0x04)?)?;
+        let shield_break_stun_time = c.read_f32(bin.read_at(offset + 0xA8, 0x04)?)?;
+        let horizontal_knockback1 = c.read_f32(bin.read_at(offset + 0xAC, 0x04)?)?;
+        let horizontal_knockback2 = c.read_f32(bin.read_at(offset + 0xB0, 0x04)?)?;
+        let horizontal_knockback3 = c.read_f32(bin.read_at(offset + 0xB4, 0x04)?)?;
+        let vertical_knockback1 = c.read_f32(bin.read_at(offset + 0xB8, 0x04)?)?;
+        let vertical_knockback2 = c.read_f32(bin.read_at(offset + 0xBC, 0x04)?)?;
+        let vertical_knockback3 = c.read_f32(bin.read_at(offset + 0xC0, 0x04)?)?;
+        let animation_speed_on_hit = c.read_f32(bin.read_at(offset + 0x138, 0x04)?)?;
+        let animation_speed_on_miss = c.read_f32(bin.read_at(offset + 0x13C, 0x04)?)?;
+
+        let unknown_008 = c.read_f32(bin.read_at(offset + 0x08, 0x04)?)?;
+        let unknown_010 = c.read_f32(bin.read_at(offset + 0x10, 0x04)?)?;
+        let unknown_018 = c.read_f32(bin.read_at(offset + 0x18, 0x04)?)?;
+        let unknown_03c = c.read_u32(bin.read_at(offset + 0x3C, 0x04)?)?;
+        let unknown_070 = c.read_f32(bin.read_at(offset + 0x70, 0x04)?)?;
+        let unknown_078 = c.read_f32(bin.read_at(offset + 0x78, 0x04)?)?;
+        let unknown_07c = c.read_f32(bin.read_at(offset + 0x7C, 0x04)?)?;
+        let unknown_090 = c.read_f32(bin.read_at(offset + 0x90, 0x04)?)?;
+        let unknown_0c4 = c.read_f32(bin.read_at(offset + 0xC4, 0x04)?)?;
+        let unknown_0c8 = c.read_f32(bin.read_at(offset + 0xC8, 0x04)?)?;
+        let unknown_0d8 = c.read_f32(bin.read_at(offset + 0xD8, 0x04)?)?;
+        let unknown_0dc = c.read_f32(bin.read_at(offset + 0xDC, 0x04)?)?;
+        let unknown_0e0 = c.read_f32(bin.read_at(offset + 0xE0, 0x04)?)?;
+        let unknown_0e4 = c.read_f32(bin.read_at(offset + 0xE4, 0x04)?)?;
+        let unknown_0e8 = c.read_f32(bin.read_at(offset + 0xE8, 0x04)?)?;
+        let unknown_0ec = c.read_f32(bin.read_at(offset + 0xEC, 0x04)?)?;
+        let unknown_120 = c.read_f32(bin.read_at(offset + 0x120, 0x04)?)?;
+        let unknown_124 = c.read_f32(bin.read_at(offset + 0x124, 0x04)?)?;
+        let unknown_128 = c.read_f32(bin.read_at(offset + 0x128, 0x04)?)?;
+        let unknown_12c = c.read_f32(bin.read_at(offset + 0x12C, 0x04)?)?;
+        let unknown_130 = c.read_f32(bin.read_at(offset + 0x130, 0x04)?)?;
+        let unknown_134 = c.read_f32(bin.read_at(offset + 0x134, 0x04)?)?;
 
         // Read boolean flag fields
-        let is_slam = raw[offset + 0x2C] != 0;
-        let shield_breaks_without_stun = raw[offset + 0x2D] != 0;
-        let shield_breaks = raw[offset + 0x2E] != 0;
-        let maintain_direction = raw[offset + 0x30] != 0;
-        let lock_position = raw[offset + 0x31] != 0;
-        let no_opponent_contact = raw[offset + 0x32] != 0;
-        let hits_otg = raw[offset + 0x33] != 0;
-        let knocks_down = raw[offset + 0x34] != 0;
-        let disabled = raw[offset + 0x35] != 0;
-        let intangible = raw[offset + 0x3A] != 0;
-        let ignore_gravity = raw[offset + 0x42] != 0;
-        let is_slam_at_any_percent = raw[offset + 0x4A] != 0;
-
-        let unknown_02f = raw[offset + 0x2F] != 0;
-        let unknown_036 = raw[offset + 0x36] != 0;
-        let unknown_037 = raw[offset + 0x37] != 0;
-        let unknown_038 = raw[offset + 0x38] != 0;
-        let unknown_039 = raw[offset + 0x39] != 0;
-        let unknown_040 = raw[offset + 0x40] != 0;
-        let unknown_041 = raw[offset + 0x41] != 0;
-        let unknown_043 = raw[offset + 0x43] != 0;
-        let unknown_044 = raw[offset + 0x44] != 0;
-        let unknown_045 = raw[offset + 0x45];
-        let unknown_046 = raw[offset + 0x46] as i8;
-        let unknown_047 = raw[offset + 0x47] != 0;
-        let unknown_049 = raw[offset + 0x49];
-        let unknown_04b = raw[offset + 0x4B] != 0;
+        let is_slam = c.read_u8(bin.read_at(offset + 0x2C, 0x01)?)? != 0;
+        let shield_breaks_without_stun = c.read_u8(bin.read_at(offset + 0x2D, 0x01)?)? != 0;
+        let shield_breaks = c.read_u8(bin.read_at(offset + 0x2E, 0x01)?)? != 0;
+        let maintain_direction = c.read_u8(bin.read_at(offset + 0x30, 0x01)?)? != 0;
+        let lock_position = c.read_u8(bin.read_at(offset + 0x31, 0x01)?)? != 0;
+        let no_opponent_contact = c.read_u8(bin.read_at(offset + 0x32, 0x01)?)? != 0;
+        let hits_otg = c.read_u8(bin.read_at(offset + 0x33, 0x01)?)? != 0;
+        let knocks_down = c.read_u8(bin.read_at(offset + 0x34, 0x01)?)? != 0;
+        let disabled = c.read_u8(bin.read_at(offset + 0x35, 0x01)?)? != 0;
+        let intangible = c.read_u8(bin.read_at(offset + 0x3A, 0x01)?)? != 0;
+        let ignore_gravity = c.read_u8(bin.read_at(offset + 0x42, 0x01)?)? != 0;
+        let is_slam_at_any_percent = c.read_u8(bin.read_at(offset + 0x4A, 0x01)?)? != 0;
+
+        let unknown_02f = c.read_u8(bin.read_at(offset + 0x2F, 0x01)?)? != 0;
+        let unknown_036 = c.read_u8(bin.read_at(offset + 0x36, 0x01)?)? != 0;
+        let unknown_037 = c.read_u8(bin.read_at(offset + 0x37, 0x01)?)? != 0;
+        let unknown_038 = c.read_u8(bin.read_at(offset + 0x38, 0x01)?)? != 0;
+        let unknown_039 = c.read_u8(bin.read_at(offset + 0x39, 0x01)?)? != 0;
+        let unknown_040 = c.read_u8(bin.read_at(offset + 0x40, 0x01)?)? != 0;
+        let unknown_041 = c.read_u8(bin.read_at(offset + 0x41, 0x01)?)? != 0;
+        let unknown_043 = c.read_u8(bin.read_at(offset + 0x43, 0x01)?)? != 0;
+        let unknown_044 = c.read_u8(bin.read_at(offset + 0x44, 0x01)?)? != 0;
+        let unknown_045 = c.read_u8(bin.read_at(offset + 0x45, 0x01)?)?;
+        let unknown_046 = c.read_u8(bin.read_at(offset + 0x46, 0x01)?)? as i8;
+        let unknown_047 = c.read_u8(bin.read_at(offset + 0x47, 0x01)?)? != 0;
+        let unknown_049 = c.read_u8(bin.read_at(offset + 0x49, 0x01)?)?;
+        let unknown_04b = c.read_u8(bin.read_at(offset + 0x4B, 0x01)?)? != 0;
 
         // Read the projectile type the attack spawns, if any
-        let projectile_offset_num = c.read_u32(&raw[offset + 0x9C..offset + 0xA0])?;
-        let projectile_offset = match projectile_offset_num {
-            0 => None,
-            _ => Some(projectile_offset_num),
-        };
-        let projectile = projectile_offset.map(|offset| {
-            bin.get_object_from_offset::<ProjectileType>(offset)
-                .unwrap()
-        });
+        let projectile = AttackMoveType::projectile_offset(bin, offset)?
+            .map(|offset| bin.get_object_from_offset::<ProjectileType>(offset))
+            .transpose()?;
 
         // Read the list of hitbox offsets, and use those to read each hitbox
-        let hitbox_offsets = AttackMoveType::hitbox_offsets(&raw, offset, c)?;
+        let hitbox_offsets = AttackMoveType::hitbox_offsets(bin, offset)?;
         let hitboxes = hitbox_offsets
             .iter()
-            .map(|o| bin.get_object_from_offset::<AttackMoveRegion>(*o).unwrap())
-            .collect();
+            .map(|o| bin.get_object_from_offset::<AttackMoveRegion>(*o))
+            .collect::<Result<Vec<AttackMoveRegion>, Error>>()?;
 
         Ok(AttackMoveType {
             aim_range,
@@ -402,8 +385,6 @@ impl SerialisedShrekSuperSlamGameObject for AttackMoveType {
             vertical_knockback1,
             vertical_knockback2,
             vertical_knockback3,
-            hitbox_offsets,
-            projectile_offset,
             unknown_008,
             unknown_010,
             unknown_018,
@@ -463,10 +444,31 @@ impl WriteableShrekSuperSlamGameObject for AttackMoveType {
     /// attack.write(&mut bin, 0x1000);
     /// ```
     fn write(&self, bin: &mut Bin, offset: usize) -> Result<(), Error> {
+        let c = bin.console;
+
+        // Write the hitboxes over those of the object we are about to replace,
+        // rather than wherever this AttackMoveType was read from, so that
+        // writing a copy of an attack never changes the original
+        let hitbox_offsets: Vec<u32> = AttackMoveType::hitbox_offsets(bin, offset)?
+            .iter()
+            .map(|o| o + Bin::header_length() as u32)
+            .collect();
+
+        // Hitboxes cannot be added or removed in place, so refuse to write
+        // anything rather than silently dropping some of them
+        if hitbox_offsets.len() != self.hitboxes.len() {
+            return Err(Error::ClassDeserialiseError(
+                classes::Error::HitboxCountMismatch {
+                    hitboxes: self.hitboxes.len(),
+                    offsets: hitbox_offsets.len(),
+                },
+            ));
+        }
+        let projectile_offset = AttackMoveType::projectile_offset(bin, offset)?;
+
         // Write back only fixed-length numeric fields to the new object - other
         // fields such as strings would modify the size of the file and
         // invalidate all offsets
-        let c = bin.console;
         bin.raw
             .splice(offset + 0x04..offset + 0x08, c.write_f32(self.endlag)?);
         bin.raw
@@ -584,32 +586,16 @@ impl WriteableShrekSuperSlamGameObject for AttackMoveType {
         bin.raw[offset + 0x4B] = self.unknown_04b as u8;
 
         // Write the attack's hitboxes back to the .bin file too
-        //
-        // If this AttackMoveType was deserialised (e.g. from a JSON version),
-        // we will not know where the hitboxes are supposed to go in the .bin
-        // file, so read out the offsets from the object that we are about to
-        // replace
-        let hitbox_offsets = if AttackMoveType::number_of_hitboxes(&bin.raw, offset, c)?
-            > self.hitbox_offsets.len() as u32
-        {
-            AttackMoveType::hitbox_offsets(&bin.raw, offset, c)?
-                .iter()
-                .map(|o| o + Bin::header_length() as u32)
-                .collect()
-        } else {
-            self.hitbox_offsets.clone()
-        };
-
         for (offset, hitbox) in hitbox_offsets.iter().zip(self.hitboxes.iter()) {
             hitbox.write(bin, *offset as usize)?;
         }
 
-        // Write the attack's projectile, if any, back to the .bin file too
-        if self.projectile.is_some() && self.projectile_offset.is_some() {
-            self.projectile
-                .as_ref()
-                .unwrap()
-                .write(bin, self.projectile_offset.unwrap() as usize)?;
+        // Write the attack's projectile, if any, over the projectile of the
+        // object we are replacing too
+        if let (Some(projectile), Some(projectile_offset)) =
+            (self.projectile.as_ref(), projectile_offset)
+        {
+            projectile.write(bin, Bin::header_length() + projectile_offset as usize)?;
         }
 
         Ok(())
@@ -617,19 +603,48 @@ impl WriteableShrekSuperSlamGameObject for AttackMoveType {
 }
 
 impl AttackMoveType {
+    /// Returns a mutable reference to the attack's hitboxes, for editing them
+    /// in place.
+    ///
+    /// # Remarks
+    ///
+    /// When the attack is written to a .bin file, each hitbox is written over
+    /// the matching hitbox of the attack being replaced, so hitboxes can be
+    /// edited but not added or removed. Changing the number of hitboxes causes
+    /// [`write`](WriteableShrekSuperSlamGameObject::write) to return an
+    /// error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use shrek_superslam::Console;
+    /// use shrek_superslam::classes::AttackMoveType;
+    /// use shrek_superslam::files::Bin;
+    ///
+    /// # let my_bin_bytes = vec![0x00, 0x01, 0x02];
+    /// let bin = Bin::new(my_bin_bytes, Console::PC).unwrap();
+    /// let mut attack = bin.get_object_from_offset::<AttackMoveType>(0x1000).unwrap();
+    /// for hitbox in attack.hitboxes_mut() {
+    ///     hitbox.radius *= 2.0;
+    /// }
+    /// ```
+    pub fn hitboxes_mut(&mut self) -> &mut Vec<AttackMoveRegion> {
+        &mut self.hitboxes
+    }
+
     /// Retrieve a list of offsets for an attack's hitboxes within the .bin file
     ///
     /// # Parameters
     ///
-    /// - `raw`: The full bytes of the .bin file
+    /// - `bin`: The .bin file containing the attack
     /// - `offset`: The offset the attack starts at within the file
-    /// - `console`: The console version the file comes from
     ///
     /// # Returns
     ///
     /// A list of offsets within the .bin file where each hitbox for the attack
     /// at the offset is located. Empty if the attack has no hitboxes.
-    fn hitbox_offsets(raw: &[u8], offset: usize, console: Console) -> Result<Vec<u32>, Error> {
+    fn hitbox_offsets(bin: &Bin, offset: usize) -> Result<Vec<u32>, Error> {
+        let console = bin.console;
         // Offset 0x20 of the AttackMoveType contains an offset within the .bin
         // file to a list of further offsets, each of which points to an
         // AttackMoveRegion object. These are the hitboxes for the attack.
@@ -639,36 +654,203 @@ impl AttackMoveType {
         //
         // We later use this information to construct a list of AttackMoveRegion
         // objects for the attack.
-        let num_hitboxes = AttackMoveType::number_of_hitboxes(&raw, offset, console)? as usize;
-        let regions_offset = console.read_u32(&raw[offset + 0x20..offset + 0x24])?;
+        let num_hitboxes = AttackMoveType::number_of_hitboxes(bin, offset)? as usize;
+        let regions_offset = console.read_u32(bin.read_at(offset + 0x20, 0x04)?)?;
         (0..num_hitboxes)
             .map(|i| {
                 let region_offset_offset = regions_offset as usize + Bin::header_length() + (i * 4);
-                console.read_u32(&raw[region_offset_offset..region_offset_offset + 4])
+                console.read_u32(bin.read_at(region_offset_offset, 4)?)
             })
             .collect()
     }
 
+    /// Retrieve the offset of an attack's projectile within the .bin file
+    ///
+    /// # Parameters
+    ///
+    /// - `bin`: The .bin file containing the attack
+    /// - `offset`: The offset the attack starts at within the file
+    ///
+    /// # Returns
+    ///
+    /// The offset of the projectile the attack at the offset spawns, excluding
+    /// the header. None if the attack has no projectile.
+    fn projectile_offset(bin: &Bin, offset: usize) -> Result<Option<u32>, Error> {
+        match bin.console.read_u32(bin.read_at(offset + 0x9C, 0x04)?)? {
+            0 => Ok(None),
+            projectile_offset => Ok(Some(projectile_offset)),
+        }
+    }
+
     /// Retrieve the number of hitboxes an attack has
     ///
     /// # Parameters
     ///
-    /// - `raw`: The full bytes of the .bin file
+    /// - `bin`: The .bin file containing the attack
     /// - `offset`: The offset the attack starts at within the file
-    /// - `console`: The console version the file comes from
     ///
     /// # Returns
     ///
     /// The number of hitboxes for the attack starting at the given offset
-    fn number_of_hitboxes(raw: &[u8], offset: usize, console: Console) -> Result<u32, Error> {
-        console.read_u32(&raw[offset + 0x24..offset + 0x28])
+    fn number_of_hitboxes(bin: &Bin, offset: usize) -> Result<u32, Error> {
+        bin.console.read_u32(bin.read_at(offset + 0x24, 0x04)?)
+    }
+}
+
+/// Builder for creating a new [`AttackMoveType`] from scratch, setting the
+/// commonly-edited fields and leaving every other field at its default.
+///
+/// # Example
+///
+/// ```
+/// use shrek_superslam::classes::{AttackMoveRegion, AttackMoveTypeBuilder};
+///
+/// let attack = AttackMoveTypeBuilder::new("my_new_atk")
+///     .damage1(12.0)
+///     .knockback(3.0, 1.5)
+///     .stun(0.5)
+///     .hitbox(AttackMoveRegion {
+///         delay: 0.1,
+///         radius: 2.0,
+///         ..Default::default()
+///     })
+///     .build();
+///
+/// assert_eq!(attack.name, "my_new_atk");
+/// assert_eq!(attack.damage1, 12.0);
+/// assert_eq!(attack.hitboxes.len(), 1);
+/// ```
+pub struct AttackMoveTypeBuilder {
+    attack: AttackMoveType,
+}
+
+impl AttackMoveTypeBuilder {
+    /// Returns a new builder for an attack with the given `name`.
+    pub fn new(name: &str) -> AttackMoveTypeBuilder {
+        AttackMoveTypeBuilder {
+            attack: AttackMoveType {
+                name: name.to_owned(),
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Sets the damage the attack deals, in the `damage1` field.
+    pub fn damage1(mut self, damage: f32) -> AttackMoveTypeBuilder {
+        self.attack.damage1 = damage;
+        self
+    }
+
+    /// Sets the `horizontal` and `vertical` knockback of the attack, in the
+    /// `horizontal_knockback1` and `vertical_knockback1` fields.
+    pub fn knockback(mut self, horizontal: f32, vertical: f32) -> AttackMoveTypeBuilder {
+        self.attack.horizontal_knockback1 = horizontal;
+        self.attack.vertical_knockback1 = vertical;
+        self
+    }
+
+    /// Sets the time (in seconds) the attack stuns for.
+    pub fn stun(mut self, stun: f32) -> AttackMoveTypeBuilder {
+        self.attack.stun = stun;
+        self
+    }
+
+    /// Adds a single hitbox to the attack.
+    ///
+    /// # Remarks
+    ///
+    /// The hitboxes of an attack are separate objects within a .bin file, and
+    /// writing an attack only writes over the hitboxes already in the file.
+    /// An attack with hitboxes therefore cannot be added to a file with
+    /// [`Bin::add_object`](crate::files::Bin::add_object), only written over
+    /// an existing attack with the same number of hitboxes.
+    pub fn hitbox(mut self, hitbox: AttackMoveRegion) -> AttackMoveTypeBuilder {
+        self.attack.hitboxes.push(hitbox);
+        self
+    }
+
+    /// Replaces every hitbox of the attack with the given `hitboxes`.
+    pub fn hitboxes(mut self, hitboxes: Vec<AttackMoveRegion>) -> AttackMoveTypeBuilder {
+        self.attack.hitboxes = hitboxes;
+        self
+    }
+
+    /// Returns the finished attack.
+    pub fn build(self) -> AttackMoveType {
+        self.attack
+    }
+}
+
+/// Returns the given `attacks` as CSV, with a row for each attack alongside
+/// the name of the character it belongs to.
+///
+/// The columns are the character, the attack's name, its damage, its
+/// horizontal and vertical knockback, its stun time, and its number of
+/// hitboxes. Attacks sharing a name each get their own row.
+///
+/// # Example
+///
+/// ```
+/// use shrek_superslam::classes::{attacks_to_csv, AttackMoveTypeBuilder};
+///
+/// let attack = AttackMoveTypeBuilder::new("fast3_atk")
+///     .damage1(4.0)
+///     .knockback(1.0, 0.5)
+///     .build();
+/// let csv = attacks_to_csv(vec![("shrek", &attack), ("shrek", &attack)]);
+///
+/// let mut lines = csv.lines();
+/// assert_eq!(
+///     lines.next(),
+///     Some("character,name,damage,horizontal_knockback,vertical_knockback,stun,hitboxes")
+/// );
+/// assert_eq!(lines.next(), Some("shrek,fast3_atk,4,1,0.5,0,0"));
+/// assert_eq!(lines.next(), Some("shrek,fast3_atk,4,1,0.5,0,0"));
+/// ```
+pub fn attacks_to_csv<'a, I>(attacks: I) -> String
+where
+    I: IntoIterator<Item = (&'a str, &'a AttackMoveType)>,
+{
+    let mut csv = String::from(
+        "character,name,damage,horizontal_knockback,vertical_knockback,stun,hitboxes\n",
+    );
+    for (character, attack) in attacks {
+        csv.push_str(&format!(
+            "{},{},{},{},{},{},{}\n",
+            csv_field(character),
+            csv_field(&attack.name),
+            attack.damage1,
+            attack.horizontal_knockback1,
+            attack.vertical_knockback1,
+            attack.stun,
+            attack.hitboxes.len()
+        ));
+    }
+
+    csv
+}
+
+/// Quote the given CSV `field` if it contains a comma, quote or newline.
+///
+/// # Parameters
+///
+/// - `field`: The text of the field
+///
+/// # Returns
+///
+/// The field, quoted and with its quotes doubled if needed.
+fn csv_field(field: &str) -> String {
+    if field.contains(&[',', '"', '\n'][..]) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
     }
 }
 
 /// Structure representing the in-game `Game::ProjectileType` object type.
 ///
 /// This type represents a projectile generated by an attack.
-#[derive(Deserialize, Serialize)]
+#[derive(Clone, Default, Deserialize, PartialEq, Serialize)]
 pub struct ProjectileType {
     /// Speed the projectile moves in the X-axis
     pub x_vector: f32,
@@ -716,12 +898,12 @@ impl SerialisedShrekSuperSlamGameObject for ProjectileType {
         let c = bin.console;
 
         Ok(ProjectileType {
-            x_vector: c.read_f32(&bin.raw[offset + 0x08..offset + 0x0C])?,
-            angle: c.read_f32(&bin.raw[offset + 0x14..offset + 0x18])?,
-            arc: c.read_f32(&bin.raw[offset + 0x18..offset + 0x1C])?,
-            homing1: c.read_f32(&bin.raw[offset + 0x44..offset + 0x48])?,
-            homing2: c.read_f32(&bin.raw[offset + 0x48..offset + 0x4C])?,
-            homing3: c.read_f32(&bin.raw[offset + 0x4C..offset + 0x50])?,
+            x_vector: c.read_f32(bin.read_at(offset + 0x08, 0x04)?)?,
+            angle: c.read_f32(bin.read_at(offset + 0x14, 0x04)?)?,
+            arc: c.read_f32(bin.read_at(offset + 0x18, 0x04)?)?,
+            homing1: c.read_f32(bin.read_at(offset + 0x44, 0x04)?)?,
+            homing2: c.read_f32(bin.read_at(offset + 0x48, 0x04)?)?,
+            homing3: c.read_f32(bin.read_at(offset + 0x4C, 0x04)?)?,
         })
     }
 }
@@ -769,7 +951,7 @@ impl WriteableShrekSuperSlamGameObject for ProjectileType {
 /// Structure representing the in-game `Game::AttackMoveRegion` object type.
 ///
 /// This type represents a single hitbox generated by an attack or projectile.
-#[derive(Deserialize, Serialize)]
+#[derive(Clone, Default, Deserialize, PartialEq, Serialize)]
 pub struct AttackMoveRegion {
     /// The delay (in seconds?) from the attack starting to the hitbox coming out.
     pub delay: f32,
@@ -815,11 +997,11 @@ impl SerialisedShrekSuperSlamGameObject for AttackMoveRegion {
         let c = bin.console;
 
         Ok(AttackMoveRegion {
-            delay: c.read_f32(&bin.raw[offset + 0x04..offset + 0x08])?,
-            arc: c.read_f32(&bin.raw[offset + 0x30..offset + 0x34])?,
-            radius: c.read_f32(&bin.raw[offset + 0x38..offset + 0x3C])?,
-            horizontal_angle: c.read_f32(&bin.raw[offset + 0x10..offset + 0x14])?,
-            unknown_024: c.read_f32(&bin.raw[offset + 0x24..offset + 0x28])?,
+            delay: c.read_f32(bin.read_at(offset + 0x04, 0x04)?)?,
+            arc: c.read_f32(bin.read_at(offset + 0x30, 0x04)?)?,
+            radius: c.read_f32(bin.read_at(offset + 0x38, 0x04)?)?,
+            horizontal_angle: c.read_f32(bin.read_at(offset + 0x10, 0x04)?)?,
+            unknown_024: c.read_f32(bin.read_at(offset + 0x24, 0x04)?)?,
         })
     }
 }
@@ -5,7 +5,7 @@ use crate::errors::Error;
 use crate::files::Bin;
 
 /// Structure representing the in-game `Game::PhysicsFighting` object type.
-#[derive(Deserialize, Serialize)]
+#[derive(Clone, Default, Deserialize, PartialEq, Serialize)]
 pub struct PhysicsFighting {
     pub m_airborn_land_dist: f32,
     pub m_airborn_no_catch_bias: f32,
@@ -115,113 +115,112 @@ impl SerialisedShrekSuperSlamGameObject for PhysicsFighting {
     /// Prefer calling [`Bin::get_object_from_offset`] rather than calling
     /// this method.
     fn new(bin: &Bin, offset: usize) -> Result<PhysicsFighting, Error> {
-        let raw = &bin.raw;
         let c = bin.console;
 
         // Read numeric fields
-        let m_airborn_land_dist = c.read_f32(&raw[offset + 0x0838..offset + 0x083C])?;
-        let m_airborn_no_catch_bias = c.read_f32(&raw[offset + 0x083C..offset + 0x0840])?;
-        let ground_leave_dist_walk = c.read_f32(&raw[offset + 0x0840..offset + 0x0844])?;
-        let ground_leave_dist_stairs = c.read_f32(&raw[offset + 0x0844..offset + 0x0848])?;
-
-        let m_jump_up_vel_influence = c.read_f32(&raw[offset + 0x084C..offset + 0x0850])?;
-        let jump_takeoff_max_time = c.read_f32(&raw[offset + 0x0850..offset + 0x0854])?;
-        let jump_delay = c.read_f32(&raw[offset + 0x0854..offset + 0x0858])?;
-        let off_ledge_vel_stop = c.read_f32(&raw[offset + 0x0858..offset + 0x085C])?;
-        let off_ledge_vel_drop = c.read_f32(&raw[offset + 0x085C..offset + 0x0860])?;
-        let off_ledge_vel_roll = c.read_f32(&raw[offset + 0x0860..offset + 0x0864])?;
-
-        let block_time_max = c.read_f32(&raw[offset + 0x0894..offset + 0x0898])?;
-        let block_time_recharge = c.read_f32(&raw[offset + 0x0898..offset + 0x089C])?;
-
-        let block_power_max = c.read_f32(&raw[offset + 0x08A0..offset + 0x08A4])?;
-        let block_power_recharge = c.read_f32(&raw[offset + 0x08A4..offset + 0x08A8])?;
-
-        let jump_peak_vel = c.read_f32(&raw[offset + 0x08AC..offset + 0x08B0])?;
-
-        let jump_takeoff_region_time = c.read_f32(&raw[offset + 0x08C4..offset + 0x08C8])?;
-        let jump_power_normal = c.read_f32(&raw[offset + 0x08C8..offset + 0x08CC])?;
-        let jump_power_double = c.read_f32(&raw[offset + 0x08CC..offset + 0x08D0])?;
-        let jump_teleport_up_dist = c.read_f32(&raw[offset + 0x08D0..offset + 0x08D4])?;
-        let jump_teleport_flat_dist = c.read_f32(&raw[offset + 0x08D4..offset + 0x08D8])?;
-        let jump_teleport_flat_up_dist = c.read_f32(&raw[offset + 0x08D8..offset + 0x08DC])?;
-        let jump_power_ledge = c.read_f32(&raw[offset + 0x08DC..offset + 0x08E0])?;
-        let jump_power_wall = c.read_f32(&raw[offset + 0x08E0..offset + 0x08E4])?;
-        let jump_power_wall_outward = c.read_f32(&raw[offset + 0x08E4..offset + 0x08E8])?;
-        let jump_power_grapple = c.read_f32(&raw[offset + 0x08E8..offset + 0x08EC])?;
-        let dump_up_vel = c.read_f32(&raw[offset + 0x08EC..offset + 0x08F0])?;
-        let dump_flat_vel = c.read_f32(&raw[offset + 0x08F0..offset + 0x08F4])?;
-
-        let accumulated_vel_air_fric = c.read_f32(&raw[offset + 0x09F4..offset + 0x09F8])?;
-        let accumulated_vel_ground_fric = c.read_f32(&raw[offset + 0x09F8..offset + 0x09FC])?;
-        let wall_bounce_low = c.read_f32(&raw[offset + 0x09FC..offset + 0x0A00])?;
-        let wall_bounce_medium = c.read_f32(&raw[offset + 0x0A00..offset + 0x0A04])?;
-        let wall_bounce_high = c.read_f32(&raw[offset + 0x0A04..offset + 0x0A08])?;
-        let hit_pause_delay = c.read_f32(&raw[offset + 0x0A08..offset + 0x0A0C])?;
-        let hit_pause_light = c.read_f32(&raw[offset + 0x0A0C..offset + 0x0A10])?;
-        let hit_pause_medium = c.read_f32(&raw[offset + 0x0A10..offset + 0x0A14])?;
-        let hit_pause_strong = c.read_f32(&raw[offset + 0x0A14..offset + 0x0A18])?;
-
-        let attack_speed_filter = c.read_f32(&raw[offset + 0x0A2C..offset + 0x0A30])?;
-
-        let move_speed_filter = c.read_f32(&raw[offset + 0x0A38..offset + 0x0A3C])?;
-
-        let min_loose_camera_radius = c.read_f32(&raw[offset + 0x0A60..offset + 0x0A64])?;
-        let max_loose_camera_radius = c.read_f32(&raw[offset + 0x0A64..offset + 0x0A68])?;
-
-        let tight_camera_radius = c.read_f32(&raw[offset + 0x0A6C..offset + 0x0A70])?;
-        let camera_actual_vel_influence = c.read_f32(&raw[offset + 0x0A70..offset + 0x0A74])?;
-        let camera_actual_vel_max = c.read_f32(&raw[offset + 0x0A74..offset + 0x0A78])?;
-        let camera_vel_influence = c.read_f32(&raw[offset + 0x0A78..offset + 0x0A7C])?;
-        let camera_vel_max = c.read_f32(&raw[offset + 0x0A7C..offset + 0x0A80])?;
-
-        let deflect_time_max = c.read_f32(&raw[offset + 0x0A84..offset + 0x0A88])?;
-
-        let deflection_delay = c.read_f32(&raw[offset + 0x0A9C..offset + 0x0AA0])?;
-
-        let deflect_reset_time = c.read_f32(&raw[offset + 0x0AC8..offset + 0x0ACC])?;
-
-        let jump_teleport_time = c.read_f32(&raw[offset + 0x0AE4..offset + 0x0AE8])?;
-        let juggle_gravity = c.read_f32(&raw[offset + 0x0AE8..offset + 0x0AEC])?;
-
-        let knockback_fric_ground = c.read_f32(&raw[offset + 0x0B2C..offset + 0x0B30])?;
-        let knockback_fric_air = c.read_f32(&raw[offset + 0x0B34..offset + 0x0B38])?;
-        let knockdown_fric = c.read_f32(&raw[offset + 0x0B38..offset + 0x0B3C])?;
-        let m_airborn_land_dist_stairs = c.read_f32(&raw[offset + 0x0B3C..offset + 0x0B40])?;
-
-        let wall_trap_hit_timer = c.read_f32(&raw[offset + 0x0B4C..offset + 0x0B50])?;
-        let wall_trap_up_vector = c.read_f32(&raw[offset + 0x0B50..offset + 0x0B54])?;
-        let wall_trap_vel_multiplier = c.read_f32(&raw[offset + 0x0B54..offset + 0x0B58])?;
-        let wall_trap_pvp_disable_timer = c.read_f32(&raw[offset + 0x0B58..offset + 0x0B5C])?;
-
-        let block_gravity = c.read_f32(&raw[offset + 0x0C48..offset + 0x0C4C])?;
-
-        let dronkey_jump_foward = c.read_f32(&raw[offset + 0x0C58..offset + 0x0C5C])?;
-        let dronkey_jump_up = c.read_f32(&raw[offset + 0x0C5C..offset + 0x0C60])?;
-        let dronkey_jump_time = c.read_f32(&raw[offset + 0x0C60..offset + 0x0C64])?;
-
-        let move_speed_up = c.read_f32(&raw[offset + 0x0C80..offset + 0x0C84])?;
-        let move_speed_up_max = c.read_f32(&raw[offset + 0x0C84..offset + 0x0C88])?;
-        let move_speed_down = c.read_f32(&raw[offset + 0x0C88..offset + 0x0C8C])?;
-        let move_speed_down_max = c.read_f32(&raw[offset + 0x0C8C..offset + 0x0C90])?;
-
-        let def_stun_1 = c.read_f32(&raw[offset + 0x0CDC..offset + 0x0CE0])?;
-        let def_kb_mult_1 = c.read_f32(&raw[offset + 0x0CE0..offset + 0x0CE4])?;
-        let def_speed_mult_1 = c.read_f32(&raw[offset + 0x0CE4..offset + 0x0CE8])?;
-        let def_speed_mult_2 = c.read_f32(&raw[offset + 0x0CE8..offset + 0x0CEC])?;
-        let def_speed_mult_3 = c.read_f32(&raw[offset + 0x0CEC..offset + 0x0CF0])?;
-        let def_speed_mult_4 = c.read_f32(&raw[offset + 0x0CF0..offset + 0x0CF4])?;
-        let def_speed_mult_5 = c.read_f32(&raw[offset + 0x0CF4..offset + 0x0CF8])?;
-        let def_speed_mult_6 = c.read_f32(&raw[offset + 0x0CF8..offset + 0x0CFC])?;
-        let def_pow_mult_1 = c.read_f32(&raw[offset + 0x0CFC..offset + 0x0D00])?;
-        let def_pow_mult_2 = c.read_f32(&raw[offset + 0x0D00..offset + 0x0D04])?;
-        let def_pow_mult_3 = c.read_f32(&raw[offset + 0x0D04..offset + 0x0D08])?;
-        let item_drop_knockup = c.read_f32(&raw[offset + 0x0D08..offset + 0x0D0C])?;
-        let item_drop_knockback = c.read_f32(&raw[offset + 0x0D0C..offset + 0x0D10])?;
-
-        let throwable_drop_knockup = c.read_f32(&raw[offset + 0x0D14..offset + 0x0D18])?;
-        let throwable_drop_knockback = c.read_f32(&raw[offset + 0x0D18..offset + 0x0D1C])?;
-        let walkup_angle_tolerance = c.read_f32(&raw[offset + 0x0D1C..offset + 0x0D20])?;
+        let m_airborn_land_dist = c.read_f32(bin.read_at(offset + 0x0838, 0x04)?)?;
+        let m_airborn_no_catch_bias = c.read_f32(bin.read_at(offset + 0x083C, 0x04)?)?;
+        let ground_leave_dist_walk = c.read_f32(bin.read_at(offset + 0x0840, 0x04)?)?;
+        let ground_leave_dist_stairs = c.read_f32(bin.read_at(offset + 0x0844, 0x04)?)?;
+
+        let m_jump_up_vel_influence = c.read_f32(bin.read_at(offset + 0x084C, 0x04)?)?;
+        let jump_takeoff_max_time = c.read_f32(bin.read_at(offset + 0x0850, 0x04)?)?;
+        let jump_delay = c.read_f32(bin.read_at(offset + 0x0854, 0x04)?)?;
+        let off_ledge_vel_stop = c.read_f32(bin.read_at(offset + 0x0858, 0x04)?)?;
+        let off_ledge_vel_drop = c.read_f32(bin.read_at(offset + 0x085C, 0x04)?)?;
+        let off_ledge_vel_roll = c.read_f32(bin.read_at(offset + 0x0860, 0x04)?)?;
+
+        let block_time_max = c.read_f32(bin.read_at(offset + 0x0894, 0x04)?)?;
+        let block_time_recharge = c.read_f32(bin.read_at(offset + 0x0898, 0x04)?)?;
+
+        let block_power_max = c.read_f32(bin.read_at(offset + 0x08A0, 0x04)?)?;
+        let block_power_recharge = c.read_f32(bin.read_at(offset + 0x08A4, 0x04)?)?;
+
+        let jump_peak_vel = c.read_f32(bin.read_at(offset + 0x08AC, 0x04)?)?;
+
+        let jump_takeoff_region_time = c.read_f32(bin.read_at(offset + 0x08C4, 0x04)?)?;
+        let jump_power_normal = c.read_f32(bin.read_at(offset + 0x08C8, 0x04)?)?;
+        let jump_power_double = c.read_f32(bin.read_at(offset + 0x08CC, 0x04)?)?;
+        let jump_teleport_up_dist = c.read_f32(bin.read_at(offset + 0x08D0, 0x04)?)?;
+        let jump_teleport_flat_dist = c.read_f32(bin.read_at(offset + 0x08D4, 0x04)?)?;
+        let jump_teleport_flat_up_dist = c.read_f32(bin.read_at(offset + 0x08D8, 0x04)?)?;
+        let jump_power_ledge = c.read_f32(bin.read_at(offset + 0x08DC, 0x04)?)?;
+        let jump_power_wall = c.read_f32(bin.read_at(offset + 0x08E0, 0x04)?)?;
+        let jump_power_wall_outward = c.read_f32(bin.read_at(offset + 0x08E4, 0x04)?)?;
+        let jump_power_grapple = c.read_f32(bin.read_at(offset + 0x08E8, 0x04)?)?;
+        let dump_up_vel = c.read_f32(bin.read_at(offset + 0x08EC, 0x04)?)?;
+        let dump_flat_vel = c.read_f32(bin.read_at(offset + 0x08F0, 0x04)?)?;
+
+        let accumulated_vel_air_fric = c.read_f32(bin.read_at(offset + 0x09F4, 0x04)?)?;
+        let accumulated_vel_ground_fric = c.read_f32(bin.read_at(offset + 0x09F8, 0x04)?)?;
+        let wall_bounce_low = c.read_f32(bin.read_at(offset + 0x09FC, 0x04)?)?;
+        let wall_bounce_medium = c.read_f32(bin.read_at(offset + 0x0A00, 0x04)?)?;
+        let wall_bounce_high = c.read_f32(bin.read_at(offset + 0x0A04, 0x04)?)?;
+        let hit_pause_delay = c.read_f32(bin.read_at(offset + 0x0A08, 0x04)?)?;
+        let hit_pause_light = c.read_f32(bin.read_at(offset + 0x0A0C, 0x04)?)?;
+        let hit_pause_medium = c.read_f32(bin.read_at(offset + 0x0A10, 0x04)?)?;
+        let hit_pause_strong = c.read_f32(bin.read_at(offset + 0x0A14, 0x04)?)?;
+
+        let attack_speed_filter = c.read_f32(bin.read_at(offset + 0x0A2C, 0x04)?)?;
+
+        let move_speed_filter = c.read_f32(bin.read_at(offset + 0x0A38, 0x04)?)?;
+
+        let min_loose_camera_radius = c.read_f32(bin.read_at(offset + 0x0A60, 0x04)?)?;
+        let max_loose_camera_radius = c.read_f32(bin.read_at(offset + 0x0A64, 0x04)?)?;
+
+        let tight_camera_radius = c.read_f32(bin.read_at(offset + 0x0A6C, 0x04)?)?;
+        let camera_actual_vel_influence = c.read_f32(bin.read_at(offset + 0x0A70, 0x04)?)?;
+        let camera_actual_vel_max = c.read_f32(bin.read_at(offset + 0x0A74, 0x04)?)?;
+        let camera_vel_influence = c.read_f32(bin.read_at(offset + 0x0A78, 0x04)?)?;
+        let camera_vel_max = c.read_f32(bin.read_at(offset + 0x0A7C, 0x04)?)?;
+
+        let deflect_time_max = c.read_f32(bin.read_at(offset + 0x0A84, 0x04)?)?;
+
+        let deflection_delay = c.read_f32(bin.read_at(offset + 0x0A9C, 0x04)?)?;
+
+        let deflect_reset_time = c.read_f32(bin.read_at(offset + 0x0AC8, 0x04)?)?;
+
+        let jump_teleport_time = c.read_f32(bin.read_at(offset + 0x0AE4, 0x04)?)?;
+        let juggle_gravity = c.read_f32(bin.read_at(offset + 0x0AE8, 0x04)?)?;
+
+        let knockback_fric_ground = c.read_f32(bin.read_at(offset + 0x0B2C, 0x04)?)?;
+        let knockback_fric_air = c.read_f32(bin.read_at(offset + 0x0B34, 0x04)?)?;
+        let knockdown_fric = c.read_f32(bin.read_at(offset + 0x0B38, 0x04)?)?;
+        let m_airborn_land_dist_stairs = c.read_f32(bin.read_at(offset + 0x0B3C, 0x04)?)?;
+
+        let wall_trap_hit_timer = c.read_f32(bin.read_at(offset + 0x0B4C, 0x04)?)?;
+        let wall_trap_up_vector = c.read_f32(bin.read_at(offset + 0x0B50, 0x04)?)?;
+        let wall_trap_vel_multiplier = c.read_f32(bin.read_at(offset + 0x0B54, 0x04)?)?;
+        let wall_trap_pvp_disable_timer = c.read_f32(bin.read_at(offset + 0x0B58, 0x04)?)?;
+
+        let block_gravity = c.read_f32(bin.read_at(offset + 0x0C48, 0x04)?)?;
+
+        let dronkey_jump_foward = c.read_f32(bin.read_at(offset + 0x0C58, 0x04)?)?;
+        let dronkey_jump_up = c.read_f32(bin.read_at(offset + 0x0C5C, 0x04)?)?;
+        let dronkey_jump_time = c.read_f32(bin.read_at(offset + 0x0C60, 0x04)?)?;
+
+        let move_speed_up = c.read_f32(bin.read_at(offset + 0x0C80, 0x04)?)?;
+        let move_speed_up_max = c.read_f32(bin.read_at(offset + 0x0C84, 0x04)?)?;
+        let move_speed_down = c.read_f32(bin.read_at(offset + 0x0C88, 0x04)?)?;
+        let move_speed_down_max = c.read_f32(bin.read_at(offset + 0x0C8C, 0x04)?)?;
+
+        let def_stun_1 = c.read_f32(bin.read_at(offset + 0x0CDC, 0x04)?)?;
+        let def_kb_mult_1 = c.read_f32(bin.read_at(offset + 0x0CE0, 0x04)?)?;
+        let def_speed_mult_1 = c.read_f32(bin.read_at(offset + 0x0CE4, 0x04)?)?;
+        let def_speed_mult_2 = c.read_f32(bin.read_at(offset + 0x0CE8, 0x04)?)?;
+        let def_speed_mult_3 = c.read_f32(bin.read_at(offset + 0x0CEC, 0x04)?)?;
+        let def_speed_mult_4 = c.read_f32(bin.read_at(offset + 0x0CF0, 0x04)?)?;
+        let def_speed_mult_5 = c.read_f32(bin.read_at(offset + 0x0CF4, 0x04)?)?;
+        let def_speed_mult_6 = c.read_f32(bin.read_at(offset + 0x0CF8, 0x04)?)?;
+        let def_pow_mult_1 = c.read_f32(bin.read_at(offset + 0x0CFC, 0x04)?)?;
+        let def_pow_mult_2 = c.read_f32(bin.read_at(offset + 0x0D00, 0x04)?)?;
+        let def_pow_mult_3 = c.read_f32(bin.read_at(offset + 0x0D04, 0x04)?)?;
+        let item_drop_knockup = c.read_f32(bin.read_at(offset + 0x0D08, 0x04)?)?;
+        let item_drop_knockback = c.read_f32(bin.read_at(offset + 0x0D0C, 0x04)?)?;
+
+        let throwable_drop_knockup = c.read_f32(bin.read_at(offset + 0x0D14, 0x04)?)?;
+        let throwable_drop_knockback = c.read_f32(bin.read_at(offset + 0x0D18, 0x04)?)?;
+        let walkup_angle_tolerance = c.read_f32(bin.read_at(offset + 0x0D1C, 0x04)?)?;
 
         Ok(PhysicsFighting {
             m_airborn_land_dist,
@@ -323,7 +322,8 @@ impl WriteableShrekSuperSlamGameObject for PhysicsFighting {
         write_to_bin(bin, offset, 0x0850, self.jump_takeoff_max_time)?;
         write_to_bin(bin, offset, 0x0854, self.jump_delay)?;
         write_to_bin(bin, offset, 0x0858, self.off_ledge_vel_stop)?;
-        write_to_bin(bin, offset, 0x085C, self.off_ledge_vel_roll)?;
+        write_to_bin(bin, offset, 0x085C, self.off_ledge_vel_drop)?;
+        write_to_bin(bin, offset, 0x0860, self.off_ledge_vel_roll)?;
 
         write_to_bin(bin, offset, 0x0894, self.block_time_max)?;
         write_to_bin(bin, offset, 0x0898, self.block_time_recharge)?;
@@ -379,9 +379,9 @@ impl WriteableShrekSuperSlamGameObject for PhysicsFighting {
         write_to_bin(bin, offset, 0x0AE8, self.juggle_gravity)?;
 
         write_to_bin(bin, offset, 0x0B2C, self.knockback_fric_ground)?;
-        write_to_bin(bin, offset, 0x0B30, self.knockback_fric_air)?;
-        write_to_bin(bin, offset, 0x0B34, self.knockdown_fric)?;
-        write_to_bin(bin, offset, 0x0B38, self.m_airborn_land_dist_stairs)?;
+        write_to_bin(bin, offset, 0x0B34, self.knockback_fric_air)?;
+        write_to_bin(bin, offset, 0x0B38, self.knockdown_fric)?;
+        write_to_bin(bin, offset, 0x0B3C, self.m_airborn_land_dist_stairs)?;
 
         write_to_bin(bin, offset, 0x0B4C, self.wall_trap_hit_timer)?;
         write_to_bin(bin, offset, 0x0B50, self.wall_trap_up_vector)?;
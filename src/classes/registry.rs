@@ -0,0 +1,68 @@
+use std::collections::HashMap;
+
+use crate::classes::hash_lookup;
+
+/// Lookup of class hashes to class names, used to name the objects within a
+/// .bin file.
+///
+/// A registry starts with every class known to be in the game, and further
+/// class names can be registered at runtime, such as when reverse-engineering
+/// a newly discovered class.
+///
+/// # Example
+///
+/// ```no_run
+/// use shrek_superslam::Console;
+/// use shrek_superslam::classes::ClassRegistry;
+/// use shrek_superslam::files::Bin;
+///
+/// let mut registry = ClassRegistry::new();
+/// registry.register(0x1F2E3D4C, "Game::MyNewClass");
+///
+/// # let my_file_bytes: Vec<u8> = vec![];
+/// let bin = Bin::with_registry(my_file_bytes, Console::PC, registry).unwrap();
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ClassRegistry {
+    /// The class names registered at runtime, by their hash
+    registered: HashMap<u32, String>,
+}
+
+impl ClassRegistry {
+    /// Returns a new `ClassRegistry` containing only the classes known to be
+    /// in the game.
+    pub fn new() -> ClassRegistry {
+        ClassRegistry::default()
+    }
+
+    /// Add the class with the given `name` to the registry, under the given
+    /// `hash`.
+    ///
+    /// # Remarks
+    ///
+    /// Class hashes are not produced by [`hash`](crate::hash), so the `hash`
+    /// must be the one found within the game's files for the class.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use shrek_superslam::classes::ClassRegistry;
+    ///
+    /// let mut registry = ClassRegistry::new();
+    /// registry.register(0x1F2E3D4C, "Game::MyNewClass");
+    /// assert_eq!(registry.lookup(0x1F2E3D4C), Some("Game::MyNewClass"));
+    /// ```
+    pub fn register(&mut self, hash: u32, name: &str) {
+        self.registered.insert(hash, name.to_owned());
+    }
+
+    /// Returns the name of the class with the given `hash`, if it is known.
+    ///
+    /// # Remarks
+    ///
+    /// The classes known to be in the game take priority over any registered
+    /// class with the same hash.
+    pub fn lookup(&self, hash: u32) -> Option<&str> {
+        hash_lookup(hash).or_else(|| self.registered.get(&hash).map(|n| n.as_str()))
+    }
+}
@@ -1,3 +1,5 @@
+use serde::{Deserialize, Serialize};
+
 use crate::classes::SerialisedShrekSuperSlamGameObject;
 use crate::errors::Error;
 use crate::files::Bin;
@@ -5,6 +7,7 @@ use crate::files::Bin;
 /// Structure representing the in-game `gf::LocalizedString` object type.
 ///
 /// This type is a thin wrapper around regular string types.
+#[derive(Clone, Deserialize, PartialEq, Serialize)]
 pub struct LocalizedString {
     /// The contents of the string
     pub string: String,
@@ -44,10 +47,10 @@ impl SerialisedShrekSuperSlamGameObject for LocalizedString {
     fn new(bin: &Bin, offset: usize) -> Result<LocalizedString, Error> {
         let x = bin
             .console
-            .read_u32(&bin.raw[offset + 0x04..offset + 0x08])?;
+            .read_u32(bin.read_at(offset + 0x04, 0x04)?)?;
         let str_offset = bin
             .console
-            .read_u32(&bin.raw[offset + 0x08..offset + 0x0C])?;
+            .read_u32(bin.read_at(offset + 0x08, 0x04)?)?;
         Ok(LocalizedString {
             string: bin.get_str_from_offset(str_offset)?,
             unknown: x,
@@ -69,6 +72,7 @@ impl LocalizedString {
 /// Structure representing the in-game `Game::EffectStringReference` object type.
 ///
 /// This type is a thin wrapper around a string that names an effect.
+#[derive(Clone, Deserialize, PartialEq, Serialize)]
 pub struct EffectStringReference {
     /// The contents of the string
     pub string: String,
@@ -105,7 +109,7 @@ impl SerialisedShrekSuperSlamGameObject for EffectStringReference {
     fn new(bin: &Bin, offset: usize) -> Result<EffectStringReference, Error> {
         let str_offset = bin
             .console
-            .read_u32(&bin.raw[offset + 0x04..offset + 0x08])?;
+            .read_u32(bin.read_at(offset + 0x04, 0x04)?)?;
         Ok(EffectStringReference {
             string: bin.get_str_from_offset(str_offset)?,
         })
@@ -1,89 +1,374 @@
 use std::cmp::Ordering;
+use std::io;
+use std::io::Read;
+
+use crate::errors::Error;
 
 const MAX_DISTANCE: usize = 0x1011D;
 
-/// Compress data to be compatible with Shrek SuperSlam
+/// The longest back-reference that can be encoded
+const MAX_LENGTH: usize = 7 + 0xFF;
+
+/// The most back-references that can follow a single run of literals
+const MAX_TOKENS: usize = 8;
+
+/// How many earlier positions are checked when searching for a match
+const MAX_CHAIN: usize = 64;
+
+/// Number of bits used to hash the three bytes starting a match
+const HASH_BITS: u32 = 16;
+
+/// Returns the given `decompressed` data compressed in the format used for
+/// files within the MASTER.DAT.
 ///
-/// # Parameters
+/// # Remarks
 ///
-/// - `decompressed`: The data to compress
+/// The data is compressed as a series of blocks, each made up of a run of
+/// literal bytes followed by up to 8 back-references into the data already
+/// decompressed. Repeated data is found greedily, so the output is not
+/// necessarily as small as the game's own compressor would make it.
 ///
-/// # Returns
+/// See [`decompress`] for a description of the format.
 ///
-/// The compressed data
+/// # Example
 ///
-/// # Notes
+/// ```
+/// use shrek_superslam::{compress, decompress};
 ///
-/// This method does not actually compress the data, but instead inserts
-/// markers throughout the file to allow the game to read the uncompressed
-/// stream. The returned data is therefore slightly larger than the input.
+/// let data = b"Shrek SuperSlam Shrek SuperSlam";
+/// let compressed = compress(data);
+/// assert_eq!(decompress(&compressed).unwrap(), data.to_vec());
+/// ```
 pub fn compress(decompressed: &[u8]) -> Vec<u8> {
-    // Adapted from code provided by zed0
-    // <https://reverseengineering.stackexchange.com/questions/16021/>
-    let mut compressed: Vec<u8> = vec![];
+    encode(decompressed, vec![])
+}
+
+/// Returns the length of the data [`compress`] would produce from the given
+/// `decompressed` data, without building the compressed data itself.
+///
+/// # Remarks
+///
+/// The compressed data is found in exactly the same way as by [`compress`],
+/// only counting its bytes rather than keeping them, so the result must
+/// always equal `compress(decompressed).len()`. This makes it no quicker
+/// than compressing the data, but the compressed data is never held in
+/// memory.
+///
+/// # Example
+///
+/// ```
+/// use shrek_superslam::{compress, estimated_compressed_size};
+///
+/// let data = b"Shrek SuperSlam Shrek SuperSlam";
+/// assert_eq!(estimated_compressed_size(data), compress(data).len());
+/// ```
+pub fn estimated_compressed_size(decompressed: &[u8]) -> usize {
+    encode(decompressed, ByteCount(0)).0
+}
+
+/// Compress the given `decompressed` data, writing the result to `output`.
+///
+/// # Parameters
+///
+/// - `decompressed`: The data to compress
+/// - `output`: Where to write the compressed data
+///
+/// # Returns
+///
+/// The `output`, once all of the compressed data has been written to it
+fn encode<O: Output>(decompressed: &[u8], output: O) -> O {
+    let mut encoder = Encoder::new(decompressed, output);
+    let mut head = vec![usize::MAX; 1 << HASH_BITS];
+    let mut prev = vec![usize::MAX; decompressed.len()];
+
     let mut index = 0;
+    while index < decompressed.len() {
+        let (length, back) = longest_match(decompressed, index, &head, &prev);
+        let step = if length >= 3 && length > token_size(length, back) + 1 {
+            encoder.back_reference(length, back);
+            length
+        } else {
+            encoder.literal(index);
+            1
+        };
+
+        // Remember every position covered, so later data can refer to it
+        for (position, previous) in prev.iter_mut().enumerate().skip(index).take(step) {
+            if let Some(hash) = hash_at(decompressed, position) {
+                *previous = head[hash];
+                head[hash] = position;
+            }
+        }
+        index += step;
+    }
+
+    encoder.finish()
+}
+
+/// Somewhere the compressed stream can be written to.
+trait Output {
+    /// Add a single `byte` to the end of the stream.
+    fn push(&mut self, byte: u8);
+
+    /// Add the given `bytes` to the end of the stream.
+    fn extend_from_slice(&mut self, bytes: &[u8]);
+}
+
+impl Output for Vec<u8> {
+    fn push(&mut self, byte: u8) {
+        Vec::push(self, byte);
+    }
+
+    fn extend_from_slice(&mut self, bytes: &[u8]) {
+        Vec::extend_from_slice(self, bytes);
+    }
+}
+
+/// An [`Output`] that only counts the bytes written to it.
+struct ByteCount(usize);
+
+impl Output for ByteCount {
+    fn push(&mut self, _byte: u8) {
+        self.0 += 1;
+    }
+
+    fn extend_from_slice(&mut self, bytes: &[u8]) {
+        self.0 += bytes.len();
+    }
+}
+
+/// Builds up the compressed stream, grouping literals and back-references
+/// into blocks.
+struct Encoder<'a, O: Output> {
+    /// The data being compressed
+    data: &'a [u8],
+
+    /// The compressed stream so far
+    compressed: O,
+
+    /// Index within `data` of the current run of literals
+    literals_start: usize,
+
+    /// Length of the current run of literals
+    literals: usize,
+
+    /// The encoded back-references following the current run of literals
+    tokens: Vec<u8>,
+
+    /// The number of back-references in `tokens`
+    token_count: usize,
+}
+
+impl<'a, O: Output> Encoder<'a, O> {
+    fn new(data: &'a [u8], compressed: O) -> Encoder<'a, O> {
+        Encoder {
+            data,
+            compressed,
+            literals_start: 0,
+            literals: 0,
+            tokens: vec![],
+            token_count: 0,
+        }
+    }
+
+    /// Add the byte at `index` of the data as a literal.
+    fn literal(&mut self, index: usize) {
+        // Literals can only come before the back-references of a block, so
+        // any pending back-references end the current block
+        if self.token_count > 0 {
+            self.flush();
+        }
+        if self.literals == 0 {
+            self.literals_start = index;
+        }
+        self.literals += 1;
+
+        // A block of the maximum length is allowed to have no back-references
+        if self.literals == MAX_DISTANCE {
+            self.compressed.extend_from_slice(&[0xF8, 0xFF, 0xFF]);
+            self.compressed.extend_from_slice(
+                &self.data[self.literals_start..self.literals_start + MAX_DISTANCE],
+            );
+            self.literals = 0;
+        }
+    }
 
-    // Repeat as many max-length blocks as we can
-    let mut remaining = decompressed.len() - index;
-    while remaining > MAX_DISTANCE {
-        compressed.extend(&[0xF8, 0xFF, 0xFF]);
-        compressed.extend(&decompressed[index..index + MAX_DISTANCE]);
-        index += MAX_DISTANCE;
-        remaining = decompressed.len() - index;
+    /// Add a back-reference copying `length` bytes starting `back` bytes
+    /// before the current position.
+    fn back_reference(&mut self, length: usize, back: usize) {
+        let distance = back - 1;
+        let (distance_code, distance_extra) = if distance < 0x1E {
+            (distance, vec![])
+        } else if distance < 0x11E {
+            (0x1E, vec![(distance - 0x1E) as u8])
+        } else {
+            let extra = distance - 0x11E;
+            (0x1F, vec![extra as u8, (extra >> 8) as u8])
+        };
+
+        if length <= 7 {
+            self.tokens.push(((distance_code << 3) | length) as u8);
+        } else {
+            self.tokens.push((distance_code << 3) as u8);
+            self.tokens.push((length - 7) as u8);
+        }
+        self.tokens.extend(distance_extra);
+
+        self.token_count += 1;
+        if self.token_count == MAX_TOKENS {
+            self.flush();
+        }
     }
 
-    // Add a final block with the remaining data
-    if remaining > 0x11D {
-        compressed.push(0xF8);
-        let header = remaining - 0x11E;
-        compressed.push((header & 0x000000FF) as u8);
-        compressed.push((header >> 8) as u8);
-    } else if remaining > 0x1D {
-        compressed.push(0xF0);
-        let header = remaining - 0x1E;
-        compressed.push(header as u8);
-    } else {
-        let header = remaining << 3;
-        compressed.push(header as u8);
+    /// Write out the current block of literals and back-references.
+    fn flush(&mut self) {
+        let count = (self.token_count - 1) as u8;
+        let literals = self.literals;
+        if literals < 0x1E {
+            self.compressed.push(((literals as u8) << 3) | count);
+        } else if literals < 0x11E {
+            self.compressed.push(0xF0 | count);
+            self.compressed.push((literals - 0x1E) as u8);
+        } else {
+            let extra = literals - 0x11E;
+            self.compressed.push(0xF8 | count);
+            self.compressed.push(extra as u8);
+            self.compressed.push((extra >> 8) as u8);
+        }
+        self.compressed
+            .extend_from_slice(&self.data[self.literals_start..self.literals_start + literals]);
+        self.compressed.extend_from_slice(&self.tokens);
+        self.tokens.clear();
+
+        self.literals = 0;
+        self.token_count = 0;
     }
-    compressed.extend(&decompressed[index..]);
 
-    // Add the special case 0 length back reference to end the stream
-    compressed.extend(&[0x00, 0x00]);
+    /// Returns the compressed stream, ended with the special case zero-length
+    /// back-reference.
+    fn finish(mut self) -> O {
+        self.tokens.extend(&[0x00, 0x00]);
+        self.token_count += 1;
+        self.flush();
 
-    compressed
+        self.compressed
+    }
 }
 
-/// Decompress compressed Shrek SuperSlam data
+/// Find the longest earlier match for the data starting at `index`.
 ///
 /// # Parameters
 ///
-/// - `compressed`: The compressed data to extract
+/// - `data`: The data being compressed
+/// - `index`: The position to find a match for
+/// - `head`: The most recent position with each hash
+/// - `prev`: The previous position with the same hash as each position
 ///
 /// # Returns
 ///
-/// The extracted data
-pub fn decompress(compressed: &[u8]) -> Vec<u8> {
+/// The length of the match and how many bytes back it starts, or a length
+/// of 0 if there is no match
+fn longest_match(data: &[u8], index: usize, head: &[usize], prev: &[usize]) -> (usize, usize) {
+    let hash = match hash_at(data, index) {
+        Some(h) => h,
+        None => return (0, 0),
+    };
+    let limit = MAX_LENGTH.min(data.len() - index);
+
+    let mut best = (0, 0);
+    let mut candidate = head[hash];
+    for _ in 0..MAX_CHAIN {
+        if candidate == usize::MAX || index - candidate > MAX_DISTANCE + 1 {
+            break;
+        }
+        let length = data[candidate..]
+            .iter()
+            .zip(&data[index..index + limit])
+            .take_while(|(a, b)| a == b)
+            .count();
+        if length > best.0 {
+            best = (length, index - candidate);
+            if length == limit {
+                break;
+            }
+        }
+        candidate = prev[candidate];
+    }
+
+    best
+}
+
+/// Returns the hash of the three bytes starting at `index`, if there are
+/// enough bytes left.
+fn hash_at(data: &[u8], index: usize) -> Option<usize> {
+    data.get(index..index + 3).map(|b| {
+        let value = (b[0] as u32) << 16 | (b[1] as u32) << 8 | b[2] as u32;
+        (value.wrapping_mul(2_654_435_761) >> (32 - HASH_BITS)) as usize
+    })
+}
+
+/// Returns the number of bytes needed to encode a back-reference of the
+/// given `length`, starting `back` bytes before the current position.
+fn token_size(length: usize, back: usize) -> usize {
+    let length_size = if length > 7 { 2 } else { 1 };
+    let distance_size = match back - 1 {
+        d if d < 0x1E => 0,
+        d if d < 0x11E => 1,
+        _ => 2,
+    };
+    length_size + distance_size
+}
+
+/// Returns the given `compressed` data, as stored within the MASTER.DAT,
+/// decompressed.
+///
+/// # Errors
+///
+/// Returns an error if the data is truncated, or a back-reference refers to
+/// before the start of the decompressed data.
+///
+/// # Remarks
+///
+/// The compressed data is a series of blocks. Each block begins with a header
+/// byte, whose top 5 bits give the number of literal bytes that follow it and
+/// whose bottom 3 bits give one less than the number of back-references
+/// after those literals. A count of 30 means the count is 30 plus the next
+/// byte, and a count of 31 means it is 286 plus the next two bytes, stored
+/// little-endian on every console. A block with the maximum 0x1011D literals
+/// has one fewer back-reference, allowing it to have none.
+///
+/// Each back-reference byte has the distance back, less 1, in its top 5 bits,
+/// extended in the same way as the literal count, and the length to copy in
+/// its bottom 3 bits. A length of 0 means the length is 7 plus the following
+/// byte, which comes before any extra distance bytes. A length of 0 followed
+/// by a 0 byte ends the data.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::fs;
+/// use shrek_superslam::decompress;
+///
+/// let compressed = fs::read("player.db.bin").unwrap();
+/// let decompressed = decompress(&compressed).unwrap();
+/// ```
+pub fn decompress(compressed: &[u8]) -> Result<Vec<u8>, Error> {
     let mut decompressed: Vec<u8> = vec![];
     let mut index: usize = 0;
 
     loop {
-        let mut current = compressed[index] as usize;
-        index += 1;
+        let mut current = next_byte(compressed, &mut index)?;
         let mut length = (current & 7) + 1;
         let mut distance = current >> 3;
 
         match distance.cmp(&0x1E) {
             Ordering::Equal => {
-                current = compressed[index] as usize;
-                index += 1;
+                current = next_byte(compressed, &mut index)?;
                 distance = current + 0x1E;
             }
             Ordering::Greater => {
-                distance += compressed[index] as usize;
-                index += 1;
-                current = compressed[index] as usize;
-                index += 1;
+                distance += next_byte(compressed, &mut index)?;
+                current = next_byte(compressed, &mut index)?;
                 distance += (current << 8) + 0xFF;
                 if distance == MAX_DISTANCE {
                     length -= 1;
@@ -93,43 +378,46 @@ pub fn decompress(compressed: &[u8]) -> Vec<u8> {
         };
 
         if distance != 0 {
-            decompressed.extend(&compressed[index..(index + distance)]);
+            let literals = compressed
+                .get(index..(index + distance))
+                .ok_or(Error::DecompressionError { offset: index })?;
+            decompressed.extend(literals);
             index += distance;
         }
 
         let bound = length;
         for _ in 0..bound {
-            current = compressed[index] as usize;
-            index += 1;
+            let token = index;
+            current = next_byte(compressed, &mut index)?;
             length = current & 7;
             distance = current >> 3;
 
             if length == 0 {
-                length = compressed[index] as usize;
-                index += 1;
+                length = next_byte(compressed, &mut index)?;
                 if length == 0 {
-                    return decompressed;
+                    return Ok(decompressed);
                 }
                 length += 7;
             }
 
             match distance.cmp(&0x1E) {
                 Ordering::Equal => {
-                    current = compressed[index] as usize;
-                    index += 1;
+                    current = next_byte(compressed, &mut index)?;
                     distance = current + 0x1E;
                 }
                 Ordering::Greater => {
-                    current = compressed[index] as usize;
+                    current = next_byte(compressed, &mut index)?;
                     distance += current;
-                    index += 1;
-                    current = compressed[index] as usize;
+                    current = next_byte(compressed, &mut index)?;
                     distance += (current << 8) + 0xFF;
-                    index += 1;
                 }
                 _ => (),
             };
 
+            // Back-references cannot reach before the start of the data
+            if distance >= decompressed.len() {
+                return Err(Error::DecompressionError { offset: token });
+            }
             for _ in 0..length {
                 let backwards = decompressed[decompressed.len() - 1 - distance];
                 decompressed.push(backwards);
@@ -138,6 +426,269 @@ pub fn decompress(compressed: &[u8]) -> Vec<u8> {
     }
 }
 
+/// Read the byte at `index` of the `compressed` data, moving `index` on to
+/// the next byte.
+///
+/// # Parameters
+///
+/// - `compressed`: The compressed data being extracted
+/// - `index`: The position of the byte to read
+///
+/// # Returns
+///
+/// The byte, or an error if `index` is past the end of the data
+fn next_byte(compressed: &[u8], index: &mut usize) -> Result<usize, Error> {
+    let byte = compressed
+        .get(*index)
+        .ok_or(Error::DecompressionError { offset: *index })?;
+    *index += 1;
+
+    Ok(*byte as usize)
+}
+
+/// Returns true if compressing the given `data` and then decompressing it
+/// gives back the same data.
+///
+/// # Example
+///
+/// ```
+/// use shrek_superslam::verify_roundtrip;
+///
+/// assert!(verify_roundtrip(b"Shrek SuperSlam"));
+/// ```
+pub fn verify_roundtrip(data: &[u8]) -> bool {
+    match decompress(&compress(data)) {
+        Ok(decompressed) => decompressed == data,
+        Err(_) => false,
+    }
+}
+
+/// The number of previously decompressed bytes a back-reference can reach
+const WINDOW_SIZE: usize = MAX_DISTANCE + 1;
+
+/// Where a [`Decompressor`] is within the compressed stream
+#[derive(Clone, Copy)]
+enum State {
+    /// At the header of a block
+    Block,
+
+    /// Copying the literals of a block, followed by its back-references
+    Literals { remaining: usize, tokens: usize },
+
+    /// At the next of the block's remaining back-references
+    Token { tokens: usize },
+
+    /// Copying the bytes of a back-reference
+    Copy {
+        remaining: usize,
+        distance: usize,
+        tokens: usize,
+    },
+
+    /// At the end of the stream
+    Finished,
+}
+
+/// Decompresses compressed Shrek SuperSlam data as it is read from the
+/// underlying reader, without extracting the whole file at once.
+///
+/// Only the most recent decompressed bytes that back-references may still
+/// refer to are kept.
+///
+/// # Remarks
+///
+/// The compressed data is read a single byte at a time, so readers such as
+/// files should be wrapped in a [`std::io::BufReader`].
+///
+/// # Example
+///
+/// ```no_run
+/// use std::fs::File;
+/// use std::io::{BufReader, Read};
+/// use shrek_superslam::Decompressor;
+///
+/// let file = BufReader::new(File::open("player.db.bin").unwrap());
+/// let mut decompressor = Decompressor::new(file);
+/// let mut header = [0; 0x40];
+/// decompressor.read_exact(&mut header).unwrap();
+/// ```
+pub struct Decompressor<R: Read> {
+    /// The compressed data
+    reader: R,
+
+    /// The most recent decompressed bytes, indexed by position modulo the
+    /// window size
+    window: Vec<u8>,
+
+    /// The number of bytes decompressed so far
+    position: usize,
+
+    /// Where the decompressor is within the compressed stream
+    state: State,
+
+    /// An error reached part way through a read, which is returned by the
+    /// next read so that the bytes before it are not lost
+    error: Option<io::Error>,
+}
+
+impl<R: Read> Decompressor<R> {
+    /// Returns a new `Decompressor` reading compressed data from `reader`.
+    pub fn new(reader: R) -> Decompressor<R> {
+        Decompressor {
+            reader,
+            window: vec![0; WINDOW_SIZE],
+            position: 0,
+            state: State::Block,
+            error: None,
+        }
+    }
+
+    /// Read the next byte of compressed data.
+    fn next_byte(&mut self) -> io::Result<usize> {
+        let mut byte = [0];
+        self.reader.read_exact(&mut byte)?;
+
+        Ok(byte[0] as usize)
+    }
+
+    /// Read the extra bytes of a length or distance with the given `code`.
+    fn extended(&mut self, code: usize) -> io::Result<usize> {
+        match code.cmp(&0x1E) {
+            Ordering::Equal => Ok(self.next_byte()? + 0x1E),
+            Ordering::Greater => {
+                let low = self.next_byte()?;
+                let high = self.next_byte()?;
+                Ok(code + low + (high << 8) + 0xFF)
+            }
+            Ordering::Less => Ok(code),
+        }
+    }
+
+    /// Read the header of a block, returning the state for its literals.
+    fn block(&mut self) -> io::Result<State> {
+        let current = self.next_byte()?;
+        let mut tokens = (current & 7) + 1;
+        let literals = self.extended(current >> 3)?;
+        if literals == MAX_DISTANCE {
+            tokens -= 1;
+        }
+
+        Ok(State::Literals {
+            remaining: literals,
+            tokens,
+        })
+    }
+
+    /// Read a back-reference, returning the state for copying its bytes.
+    fn token(&mut self, tokens: usize) -> io::Result<State> {
+        let current = self.next_byte()?;
+        let mut length = current & 7;
+        if length == 0 {
+            length = self.next_byte()?;
+            if length == 0 {
+                return Ok(State::Finished);
+            }
+            length += 7;
+        }
+
+        // Back-references cannot reach before the start of the data
+        let distance = self.extended(current >> 3)?;
+        if distance >= self.position {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "Back-reference before the start of the data",
+            ));
+        }
+
+        Ok(State::Copy {
+            remaining: length,
+            distance,
+            tokens: tokens - 1,
+        })
+    }
+
+    /// Add a decompressed `byte` to the window, returning it.
+    fn emit(&mut self, byte: u8) -> u8 {
+        self.window[self.position % WINDOW_SIZE] = byte;
+        self.position += 1;
+
+        byte
+    }
+
+    /// Move on to the next state of the compressed stream, writing any byte
+    /// decompressed along the way to `buf` at `written`.
+    fn step(&mut self, buf: &mut [u8], written: &mut usize) -> io::Result<State> {
+        let state = match self.state {
+            State::Block => self.block()?,
+            State::Literals {
+                remaining: 0,
+                tokens,
+            } => State::Token { tokens },
+            State::Literals { remaining, tokens } => {
+                let byte = self.next_byte()? as u8;
+                buf[*written] = self.emit(byte);
+                *written += 1;
+                State::Literals {
+                    remaining: remaining - 1,
+                    tokens,
+                }
+            }
+            State::Token { tokens: 0 } => State::Block,
+            State::Token { tokens } => self.token(tokens)?,
+            State::Copy {
+                remaining: 0,
+                tokens,
+                ..
+            } => State::Token { tokens },
+            State::Copy {
+                remaining,
+                distance,
+                tokens,
+            } => {
+                let byte = self.window[(self.position - 1 - distance) % WINDOW_SIZE];
+                buf[*written] = self.emit(byte);
+                *written += 1;
+                State::Copy {
+                    remaining: remaining - 1,
+                    distance,
+                    tokens,
+                }
+            }
+            State::Finished => State::Finished,
+        };
+
+        Ok(state)
+    }
+}
+
+impl<R: Read> Read for Decompressor<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if let Some(error) = self.error.take() {
+            return Err(error);
+        }
+
+        let mut written = 0;
+        while written < buf.len() {
+            if let State::Finished = self.state {
+                break;
+            }
+            match self.step(buf, &mut written) {
+                Ok(state) => self.state = state,
+
+                // Return the bytes decompressed so far, and report the error
+                // on the next read
+                Err(error) if written > 0 => {
+                    self.error = Some(error);
+                    break;
+                }
+                Err(error) => return Err(error),
+            }
+        }
+
+        Ok(written)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -145,8 +696,170 @@ mod test {
     #[test]
     fn compress_then_decompress() {
         let data = "The quick brown fox jumped over the lazy dog";
-        let compressed = compress(&data.as_bytes());
-        let decompressed = decompress(&compressed);
+        let compressed = compress(data.as_bytes());
+        let decompressed = decompress(&compressed).unwrap();
         assert_eq!(String::from_utf8(decompressed).unwrap(), data);
     }
+
+    #[test]
+    fn decompress_back_references() {
+        // Three literals, then a short back-reference and a longer one using
+        // an extra length byte, which overlap the bytes they produce
+        let compressed = [0x1A, b'a', b'b', b'c', 0x16, 0x40, 0x03, 0x00, 0x00];
+        assert_eq!(
+            decompress(&compressed).unwrap(),
+            b"abcabcabcabcabcabca".to_vec()
+        );
+    }
+
+    #[test]
+    fn decompress_malformed() {
+        let compressed = compress(b"The quick brown fox jumped over the lazy dog");
+        for end in 0..compressed.len() {
+            assert!(decompress(&compressed[..end]).is_err());
+        }
+
+        // A back-reference before the start of the data
+        assert!(decompress(&[0x08, b'a', 0x11, 0x00, 0x00]).is_err());
+    }
+
+    /// Returns `length` bytes of repeatable pseudo-random data.
+    fn noise(length: usize) -> Vec<u8> {
+        let mut state: u32 = 0x1234_5678;
+        (0..length)
+            .map(|_| {
+                state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+                (state >> 16) as u8
+            })
+            .collect()
+    }
+
+    #[test]
+    fn compress_empty() {
+        assert_eq!(decompress(&compress(&[])).unwrap(), Vec::<u8>::new());
+    }
+
+    #[test]
+    fn compress_repeated() {
+        let data = b"SuperSlam ".repeat(500);
+        let compressed = compress(&data);
+        assert!(compressed.len() < data.len() / 10);
+        assert_eq!(decompress(&compressed).unwrap(), data);
+
+        let data = vec![0x00; 0x20000];
+        let compressed = compress(&data);
+        assert!(compressed.len() < data.len() / 100);
+        assert_eq!(decompress(&compressed).unwrap(), data);
+    }
+
+    #[test]
+    fn compress_noise() {
+        // Long runs of literals need the extended and maximum length headers
+        for length in &[0x1D, 0x1E, 0x11D, 0x11E, 0x1011C, 0x1011D, 0x1011E, 0x30000] {
+            let data = noise(*length);
+            assert_eq!(decompress(&compress(&data)).unwrap(), data);
+        }
+    }
+
+    #[test]
+    fn compress_distant_matches() {
+        // Repeats at every range of distance, mixed in with literals
+        let mut data = noise(0x20000);
+        for (start, back, length) in &[
+            (0x100, 0x10, 5),
+            (0x200, 0x100, 40),
+            (0x2000, 0x1000, 300),
+            (0x18000, 0x1011E, 100),
+            (0x1C000, 0x10200, 100),
+        ] {
+            for i in *start..start + length {
+                data[i] = data[i - back];
+            }
+        }
+        assert_eq!(decompress(&compress(&data)).unwrap(), data);
+    }
+
+    #[test]
+    fn compress_smaller_than_input() {
+        let data = b"The quick brown fox jumped over the lazy dog. ".repeat(40);
+        let mut mixed = noise(0x1000);
+        mixed.extend(&data);
+        mixed.extend(noise(0x100));
+        mixed.extend(&data);
+
+        let compressed = compress(&mixed);
+        assert!(compressed.len() < mixed.len());
+        assert_eq!(decompress(&compressed).unwrap(), mixed);
+    }
+
+    #[test]
+    fn estimated_size_matches_compress() {
+        let mut inputs = vec![
+            vec![],
+            b"SuperSlam ".repeat(500),
+            b"The quick brown fox jumped over the lazy dog".to_vec(),
+        ];
+        for length in &[0x1D, 0x11E, 0x1011D, 0x30000] {
+            inputs.push(noise(*length));
+        }
+        let mut mixed = noise(0x1000);
+        mixed.extend(b"Shrek SuperSlam ".repeat(100));
+        inputs.push(mixed);
+
+        for data in &inputs {
+            assert_eq!(estimated_compressed_size(data), compress(data).len());
+        }
+    }
+
+    #[test]
+    fn decompressor() {
+        let mut data = noise(0x30000);
+        data.extend(b"SuperSlam ".repeat(500));
+        data.extend(vec![0x00; 0x20000]);
+        let compressed = compress(&data);
+
+        let mut decompressed = vec![];
+        Decompressor::new(&compressed[..])
+            .read_to_end(&mut decompressed)
+            .unwrap();
+        assert_eq!(decompressed, data);
+
+        // Reading in small pieces gives the same data
+        let mut decompressor = Decompressor::new(&compressed[..]);
+        let mut piece = [0; 7];
+        let mut decompressed: Vec<u8> = vec![];
+        loop {
+            let read = decompressor.read(&mut piece).unwrap();
+            if read == 0 {
+                break;
+            }
+            decompressed.extend(&piece[..read]);
+        }
+        assert_eq!(decompressed, data);
+
+        let mut decompressed = vec![];
+        assert!(Decompressor::new(&compressed[..compressed.len() - 1])
+            .read_to_end(&mut decompressed)
+            .is_err());
+        assert!(Decompressor::new(&[0x08, b'a', 0x11, 0x00, 0x00][..])
+            .read_to_end(&mut decompressed)
+            .is_err());
+
+        // The bytes before an error are returned first, then the error
+        let mut decompressor = Decompressor::new(&[0x08, b'a', 0x11, 0x00, 0x00][..]);
+        let mut buf = [0; 0x10];
+        assert_eq!(decompressor.read(&mut buf).unwrap(), 1);
+        assert_eq!(buf[0], b'a');
+        assert_eq!(
+            decompressor.read(&mut buf).unwrap_err().kind(),
+            io::ErrorKind::InvalidData
+        );
+    }
+
+    #[test]
+    fn roundtrip() {
+        assert!(verify_roundtrip(&[]));
+        assert!(verify_roundtrip(&noise(0x20000)));
+        assert!(verify_roundtrip(&b"SuperSlam ".repeat(500)));
+    }
 }
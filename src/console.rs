@@ -1,13 +1,30 @@
+use std::fmt;
 use std::io;
+use std::str::FromStr;
 
 use byteorder::{BigEndian, LittleEndian, ReadBytesExt, WriteBytesExt};
+use encoding::all::ISO_8859_1;
+use encoding::{DecoderTrap, Encoding};
+use serde::{Deserialize, Serialize};
 
 use crate::errors::Error;
 
+/// The byte order a console stores numbers in
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Endianness {
+    Big,
+    Little,
+}
+
 /// The different console versions of the game, used to determine which
 /// endianness to use when reading numbers from files
-#[derive(Copy, Clone, PartialEq)]
+///
+/// When serialised, each console is given by the same name as its
+/// [`Display`](std::fmt::Display) representation.
+#[derive(Copy, Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum Console {
+    #[serde(alias = "gc")]
     Gamecube,
     PC,
     PS2,
@@ -15,6 +32,29 @@ pub enum Console {
 }
 
 impl Console {
+    /// Returns the byte order the console stores numbers in.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use shrek_superslam::{Console, Endianness};
+    ///
+    /// assert_eq!(Console::Gamecube.endianness(), Endianness::Big);
+    /// assert_eq!(Console::PC.endianness(), Endianness::Little);
+    /// ```
+    pub fn endianness(&self) -> Endianness {
+        match self {
+            Console::Gamecube => Endianness::Big,
+            Console::PC | Console::PS2 | Console::Xbox => Endianness::Little,
+        }
+    }
+
+    /// Returns true if the console stores numbers as big-endian, which is only
+    /// the case for the Gamecube.
+    pub fn is_big_endian(&self) -> bool {
+        self.endianness() == Endianness::Big
+    }
+
     /// Read a 32-bit unsigned integer from the given bytes from the given console
     ///
     /// # Parameters
@@ -32,9 +72,9 @@ impl Console {
             )));
         }
 
-        match self {
-            Console::Gamecube => e((&bytes[0..4]).read_u32::<BigEndian>()),
-            _ => e((&bytes[0..4]).read_u32::<LittleEndian>()),
+        match self.endianness() {
+            Endianness::Big => e((&bytes[0..4]).read_u32::<BigEndian>()),
+            Endianness::Little => e((&bytes[0..4]).read_u32::<LittleEndian>()),
         }
     }
 
@@ -51,9 +91,9 @@ impl Console {
     /// the value
     pub fn write_u32(&self, n: u32) -> Result<Vec<u8>, Error> {
         let mut wtr = Vec::new();
-        match self {
-            Console::Gamecube => e(wtr.write_u32::<BigEndian>(n))?,
-            _ => e(wtr.write_u32::<LittleEndian>(n))?,
+        match self.endianness() {
+            Endianness::Big => e(wtr.write_u32::<BigEndian>(n))?,
+            Endianness::Little => e(wtr.write_u32::<LittleEndian>(n))?,
         };
 
         Ok(wtr)
@@ -76,9 +116,9 @@ impl Console {
             )));
         }
 
-        match self {
-            Console::Gamecube => e((&bytes[0..4]).read_f32::<BigEndian>()),
-            _ => e((&bytes[0..4]).read_f32::<LittleEndian>()),
+        match self.endianness() {
+            Endianness::Big => e((&bytes[0..4]).read_f32::<BigEndian>()),
+            Endianness::Little => e((&bytes[0..4]).read_f32::<LittleEndian>()),
         }
     }
 
@@ -95,15 +135,272 @@ impl Console {
     /// the value
     pub fn write_f32(&self, n: f32) -> Result<Vec<u8>, Error> {
         let mut wtr = Vec::new();
-        match self {
-            Console::Gamecube => e(wtr.write_f32::<BigEndian>(n))?,
-            _ => e(wtr.write_f32::<LittleEndian>(n))?,
+        match self.endianness() {
+            Endianness::Big => e(wtr.write_f32::<BigEndian>(n))?,
+            Endianness::Little => e(wtr.write_f32::<LittleEndian>(n))?,
+        };
+
+        Ok(wtr)
+    }
+
+    /// Read a vector of three consecutive 32-bit floating-points, such as an
+    /// x, y, z position, from the given bytes from the given console
+    ///
+    /// # Parameters
+    ///
+    /// - `bytes`: The bytes to read as a vector
+    ///
+    /// # Returns
+    ///
+    /// The three 32-bit floating points from the bytes
+    pub fn read_vec3(&self, bytes: &[u8]) -> Result<[f32; 3], Error> {
+        if bytes.len() < 12 {
+            return Err(Error::ConsoleNumberError(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "Too few bytes to read",
+            )));
+        }
+
+        Ok([
+            self.read_f32(&bytes[0..4])?,
+            self.read_f32(&bytes[4..8])?,
+            self.read_f32(&bytes[8..12])?,
+        ])
+    }
+
+    /// Given a vector of three 32-bit floating-points, returns the console's
+    /// representation as an array of bytes
+    ///
+    /// # Parameters
+    ///
+    /// - `v`: The vector to convert
+    ///
+    /// # Returns
+    ///
+    /// The vector as an array of 12 bytes, as the console represents the
+    /// values
+    pub fn write_vec3(&self, v: [f32; 3]) -> Result<Vec<u8>, Error> {
+        let mut wtr = Vec::new();
+        for n in &v {
+            wtr.extend(self.write_f32(*n)?);
+        }
+
+        Ok(wtr)
+    }
+
+    /// Read a null-terminated string from the given bytes from the given
+    /// console
+    ///
+    /// # Parameters
+    ///
+    /// - `bytes`: The bytes to read as a string
+    ///
+    /// # Returns
+    ///
+    /// The string up to the first null byte, or up to the end of the bytes if
+    /// there is no null byte
+    ///
+    /// # Remarks
+    ///
+    /// Text within the game is stored using the single-byte ISO 8859-1
+    /// encoding on every console. Specifically, $AE = ®.
+    pub fn read_str(&self, bytes: &[u8]) -> Result<String, Error> {
+        let size = bytes.iter().position(|&b| b == 0x00).unwrap_or(bytes.len());
+        Ok(ISO_8859_1.decode(&bytes[..size], DecoderTrap::Strict)?)
+    }
+
+    /// Read an 8-bit unsigned integer from the given bytes from the given console
+    ///
+    /// # Parameters
+    ///
+    /// - `bytes`: The bytes to read as an integer
+    ///
+    /// # Returns
+    ///
+    /// The 8-bit unsigned integer from the bytes
+    ///
+    /// # Remarks
+    ///
+    /// Single bytes are the same on every console, and this is provided for
+    /// consistency with the other methods.
+    pub fn read_u8(&self, bytes: &[u8]) -> Result<u8, Error> {
+        e((&bytes[..]).read_u8())
+    }
+
+    /// Given an 8-bit unsigned integer, returns the console's representation
+    /// as an array of bytes
+    ///
+    /// # Parameters
+    ///
+    /// - `n`: The number to convert
+    ///
+    /// # Returns
+    ///
+    /// The 8-bit unsigned integer as an array of 1 byte
+    pub fn write_u8(&self, n: u8) -> Result<Vec<u8>, Error> {
+        Ok(vec![n])
+    }
+
+    /// Read a 16-bit unsigned integer from the given bytes from the given console
+    ///
+    /// # Parameters
+    ///
+    /// - `bytes`: The bytes to read as an integer
+    ///
+    /// # Returns
+    ///
+    /// The 16-bit unsigned integer from the bytes
+    pub fn read_u16(&self, bytes: &[u8]) -> Result<u16, Error> {
+        if bytes.len() < 2 {
+            return Err(Error::ConsoleNumberError(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "Too few bytes to read",
+            )));
+        }
+
+        match self.endianness() {
+            Endianness::Big => e((&bytes[0..2]).read_u16::<BigEndian>()),
+            Endianness::Little => e((&bytes[0..2]).read_u16::<LittleEndian>()),
+        }
+    }
+
+    /// Given a 16-bit unsigned integer, returns the console's representation
+    /// as an array of bytes
+    ///
+    /// # Parameters
+    ///
+    /// - `n`: The number to convert
+    ///
+    /// # Returns
+    ///
+    /// The 16-bit unsigned integer as an array of 2 bytes, as the console represents the
+    /// value
+    pub fn write_u16(&self, n: u16) -> Result<Vec<u8>, Error> {
+        let mut wtr = Vec::new();
+        match self.endianness() {
+            Endianness::Big => e(wtr.write_u16::<BigEndian>(n))?,
+            Endianness::Little => e(wtr.write_u16::<LittleEndian>(n))?,
+        };
+
+        Ok(wtr)
+    }
+
+    /// Read a 32-bit signed integer from the given bytes from the given console
+    ///
+    /// # Parameters
+    ///
+    /// - `bytes`: The bytes to read as an integer
+    ///
+    /// # Returns
+    ///
+    /// The 32-bit signed integer from the bytes
+    pub fn read_i32(&self, bytes: &[u8]) -> Result<i32, Error> {
+        if bytes.len() < 4 {
+            return Err(Error::ConsoleNumberError(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "Too few bytes to read",
+            )));
+        }
+
+        match self.endianness() {
+            Endianness::Big => e((&bytes[0..4]).read_i32::<BigEndian>()),
+            Endianness::Little => e((&bytes[0..4]).read_i32::<LittleEndian>()),
+        }
+    }
+
+    /// Given a 32-bit signed integer, returns the console's representation
+    /// as an array of bytes
+    ///
+    /// # Parameters
+    ///
+    /// - `n`: The number to convert
+    ///
+    /// # Returns
+    ///
+    /// The 32-bit signed integer as an array of 4 bytes, as the console represents the
+    /// value
+    pub fn write_i32(&self, n: i32) -> Result<Vec<u8>, Error> {
+        let mut wtr = Vec::new();
+        match self.endianness() {
+            Endianness::Big => e(wtr.write_i32::<BigEndian>(n))?,
+            Endianness::Little => e(wtr.write_i32::<LittleEndian>(n))?,
+        };
+
+        Ok(wtr)
+    }
+
+    /// Read a 64-bit unsigned integer from the given bytes from the given console
+    ///
+    /// # Parameters
+    ///
+    /// - `bytes`: The bytes to read as an integer
+    ///
+    /// # Returns
+    ///
+    /// The 64-bit unsigned integer from the bytes
+    pub fn read_u64(&self, bytes: &[u8]) -> Result<u64, Error> {
+        if bytes.len() < 8 {
+            return Err(Error::ConsoleNumberError(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "Too few bytes to read",
+            )));
+        }
+
+        match self.endianness() {
+            Endianness::Big => e((&bytes[0..8]).read_u64::<BigEndian>()),
+            Endianness::Little => e((&bytes[0..8]).read_u64::<LittleEndian>()),
+        }
+    }
+
+    /// Given a 64-bit unsigned integer, returns the console's representation
+    /// as an array of bytes
+    ///
+    /// # Parameters
+    ///
+    /// - `n`: The number to convert
+    ///
+    /// # Returns
+    ///
+    /// The 64-bit unsigned integer as an array of 8 bytes, as the console represents the
+    /// value
+    pub fn write_u64(&self, n: u64) -> Result<Vec<u8>, Error> {
+        let mut wtr = Vec::new();
+        match self.endianness() {
+            Endianness::Big => e(wtr.write_u64::<BigEndian>(n))?,
+            Endianness::Little => e(wtr.write_u64::<LittleEndian>(n))?,
         };
 
         Ok(wtr)
     }
 }
 
+impl fmt::Display for Console {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Console::Gamecube => write!(f, "gamecube"),
+            Console::PC => write!(f, "pc"),
+            Console::PS2 => write!(f, "ps2"),
+            Console::Xbox => write!(f, "xbox"),
+        }
+    }
+}
+
+impl FromStr for Console {
+    type Err = Error;
+
+    /// Parse a console from its name, ignoring case. The Gamecube may also be
+    /// given as "gc".
+    fn from_str(s: &str) -> Result<Console, Error> {
+        match s.to_lowercase().as_str() {
+            "gamecube" | "gc" => Ok(Console::Gamecube),
+            "pc" => Ok(Console::PC),
+            "ps2" => Ok(Console::PS2),
+            "xbox" => Ok(Console::Xbox),
+            _ => Err(Error::ConsoleNameError(s.to_string())),
+        }
+    }
+}
+
 /// Converts an error from the [`byteorder`] crate to an error from the library.
 fn e<T>(result: Result<T, io::Error>) -> Result<T, Error> {
     match result {
@@ -118,9 +415,9 @@ mod test {
 
     #[test]
     fn read_u32_pc() {
-        let data1 = vec![0x00, 0x00, 0x00, 0x00];
-        let data2 = vec![0xFF, 0xFF, 0xFF, 0xFF];
-        let data3 = vec![0x01, 0x02, 0x03, 0x04];
+        let data1 = [0x00, 0x00, 0x00, 0x00];
+        let data2 = [0xFF, 0xFF, 0xFF, 0xFF];
+        let data3 = [0x01, 0x02, 0x03, 0x04];
         let too_short = vec![0x00];
         let too_long = vec![0x01, 0x02, 0x03, 0x04, 0x05];
 
@@ -133,9 +430,9 @@ mod test {
 
     #[test]
     fn read_u32_gcn() {
-        let data1 = vec![0x00, 0x00, 0x00, 0x00];
-        let data2 = vec![0xFF, 0xFF, 0xFF, 0xFF];
-        let data3 = vec![0x01, 0x02, 0x03, 0x04];
+        let data1 = [0x00, 0x00, 0x00, 0x00];
+        let data2 = [0xFF, 0xFF, 0xFF, 0xFF];
+        let data3 = [0x01, 0x02, 0x03, 0x04];
 
         assert_eq!(Console::Gamecube.read_u32(&data1[0..4]).unwrap(), 0);
         assert_eq!(Console::Gamecube.read_u32(&data2[0..4]).unwrap(), u32::MAX);
@@ -179,9 +476,9 @@ mod test {
 
     #[test]
     fn read_f32_pc() {
-        let data1 = vec![0x00, 0x00, 0x00, 0x00];
-        let data2 = vec![0x00, 0x00, 0x80, 0x3F];
-        let data3 = vec![0x00, 0x00, 0x80, 0xBF];
+        let data1 = [0x00, 0x00, 0x00, 0x00];
+        let data2 = [0x00, 0x00, 0x80, 0x3F];
+        let data3 = [0x00, 0x00, 0x80, 0xBF];
 
         assert_eq!(Console::PC.read_f32(&data1[0..4]).unwrap(), 0.0);
         assert_eq!(Console::PC.read_f32(&data2[0..4]).unwrap(), 1.0);
@@ -190,9 +487,9 @@ mod test {
 
     #[test]
     fn read_f32_gcn() {
-        let data1 = vec![0x00, 0x00, 0x00, 0x00];
-        let data2 = vec![0x3F, 0x80, 0x00, 0x00];
-        let data3 = vec![0xBF, 0x80, 0x00, 0x00];
+        let data1 = [0x00, 0x00, 0x00, 0x00];
+        let data2 = [0x3F, 0x80, 0x00, 0x00];
+        let data3 = [0xBF, 0x80, 0x00, 0x00];
 
         assert_eq!(Console::Gamecube.read_f32(&data1[0..4]).unwrap(), 0.0);
         assert_eq!(Console::Gamecube.read_f32(&data2[0..4]).unwrap(), 1.0);
@@ -230,4 +527,171 @@ mod test {
             vec![0xBF, 0x80, 0x00, 0x00]
         );
     }
+
+    #[test]
+    fn read_u8() {
+        assert_eq!(Console::PC.read_u8(&[0x01, 0x02]).unwrap(), 0x01);
+        assert_eq!(Console::Gamecube.read_u8(&[0xFF]).unwrap(), 0xFF);
+        assert!(Console::PC.read_u8(&[]).is_err());
+    }
+
+    #[test]
+    fn write_u8() {
+        assert_eq!(Console::PC.write_u8(0x01).unwrap(), vec![0x01]);
+        assert_eq!(Console::Gamecube.write_u8(0xFF).unwrap(), vec![0xFF]);
+    }
+
+    #[test]
+    fn read_u16_pc() {
+        let data = [0x01, 0x02, 0x03];
+
+        assert_eq!(Console::PC.read_u16(&[0x00, 0x00]).unwrap(), 0);
+        assert_eq!(Console::PC.read_u16(&[0xFF, 0xFF]).unwrap(), u16::MAX);
+        assert_eq!(Console::PC.read_u16(&data).unwrap(), 0x0201);
+        assert!(Console::PC.read_u16(&data[0..1]).is_err());
+    }
+
+    #[test]
+    fn read_u16_gcn() {
+        assert_eq!(Console::Gamecube.read_u16(&[0x01, 0x02]).unwrap(), 0x0102);
+        assert!(Console::Gamecube.read_u16(&[0x01]).is_err());
+    }
+
+    #[test]
+    fn write_u16() {
+        assert_eq!(Console::PC.write_u16(0x0201).unwrap(), vec![0x01, 0x02]);
+        assert_eq!(
+            Console::Gamecube.write_u16(0x0201).unwrap(),
+            vec![0x02, 0x01]
+        );
+    }
+
+    #[test]
+    fn read_i32_pc() {
+        let data1 = [0xFF, 0xFF, 0xFF, 0xFF];
+        let data2 = [0x00, 0x00, 0x00, 0x80];
+        let data3 = [0x01, 0x02, 0x03, 0x04];
+
+        assert_eq!(Console::PC.read_i32(&data1).unwrap(), -1);
+        assert_eq!(Console::PC.read_i32(&data2).unwrap(), i32::MIN);
+        assert_eq!(Console::PC.read_i32(&data3).unwrap(), 0x04030201);
+        assert!(Console::PC.read_i32(&data3[0..3]).is_err());
+    }
+
+    #[test]
+    fn read_i32_gcn() {
+        let data1 = [0xFF, 0xFF, 0xFF, 0xFF];
+        let data2 = [0x80, 0x00, 0x00, 0x00];
+
+        assert_eq!(Console::Gamecube.read_i32(&data1).unwrap(), -1);
+        assert_eq!(Console::Gamecube.read_i32(&data2).unwrap(), i32::MIN);
+    }
+
+    #[test]
+    fn write_i32() {
+        assert_eq!(
+            Console::PC.write_i32(-2).unwrap(),
+            vec![0xFE, 0xFF, 0xFF, 0xFF]
+        );
+        assert_eq!(
+            Console::Gamecube.write_i32(-2).unwrap(),
+            vec![0xFF, 0xFF, 0xFF, 0xFE]
+        );
+    }
+
+    #[test]
+    fn read_u64_pc() {
+        let data = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08];
+
+        assert_eq!(Console::PC.read_u64(&data).unwrap(), 0x0807060504030201);
+        assert!(Console::PC.read_u64(&data[0..7]).is_err());
+    }
+
+    #[test]
+    fn read_u64_gcn() {
+        let data = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08];
+
+        assert_eq!(
+            Console::Gamecube.read_u64(&data).unwrap(),
+            0x0102030405060708
+        );
+    }
+
+    #[test]
+    fn write_u64() {
+        assert_eq!(
+            Console::PC.write_u64(0x0807060504030201).unwrap(),
+            vec![0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08]
+        );
+        assert_eq!(
+            Console::Gamecube.write_u64(0x0807060504030201).unwrap(),
+            vec![0x08, 0x07, 0x06, 0x05, 0x04, 0x03, 0x02, 0x01]
+        );
+    }
+
+    #[test]
+    fn read_vec3() {
+        let pc = [
+            0x00, 0x00, 0x80, 0x3F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0xBF,
+        ];
+        let gcn = [
+            0x3F, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xBF, 0x80, 0x00, 0x00,
+        ];
+
+        assert_eq!(Console::PC.read_vec3(&pc).unwrap(), [1.0, 0.0, -1.0]);
+        assert_eq!(Console::Gamecube.read_vec3(&gcn).unwrap(), [1.0, 0.0, -1.0]);
+        assert!(Console::PC.read_vec3(&pc[0..8]).is_err());
+    }
+
+    #[test]
+    fn write_vec3() {
+        assert_eq!(
+            Console::PC.write_vec3([1.0, 0.0, -1.0]).unwrap(),
+            vec![0x00, 0x00, 0x80, 0x3F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0xBF]
+        );
+        assert_eq!(
+            Console::Gamecube.write_vec3([1.0, 0.0, -1.0]).unwrap(),
+            vec![0x3F, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xBF, 0x80, 0x00, 0x00]
+        );
+    }
+
+    #[test]
+    fn read_str() {
+        assert_eq!(Console::PC.read_str(b"abc\0def").unwrap(), "abc");
+        assert_eq!(Console::Gamecube.read_str(b"abc\0\0\0").unwrap(), "abc");
+        assert_eq!(Console::PC.read_str(b"abc").unwrap(), "abc");
+        assert_eq!(Console::PC.read_str(b"\0abc").unwrap(), "");
+        assert_eq!(Console::PC.read_str(&[0x41, 0xAE, 0x00]).unwrap(), "A®");
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Console::Gamecube.to_string(), "gamecube");
+        assert_eq!(Console::PC.to_string(), "pc");
+        assert_eq!(Console::PS2.to_string(), "ps2");
+        assert_eq!(Console::Xbox.to_string(), "xbox");
+    }
+
+    #[test]
+    fn from_str() {
+        for console in &[Console::Gamecube, Console::PC, Console::PS2, Console::Xbox] {
+            assert_eq!(console.to_string().parse::<Console>().unwrap(), *console);
+        }
+        assert_eq!("GC".parse::<Console>().unwrap(), Console::Gamecube);
+        assert_eq!("GameCube".parse::<Console>().unwrap(), Console::Gamecube);
+        assert_eq!("Xbox".parse::<Console>().unwrap(), Console::Xbox);
+
+        assert!("".parse::<Console>().is_err());
+        assert!("wii".parse::<Console>().is_err());
+        assert!("pc ".parse::<Console>().is_err());
+    }
+
+    #[test]
+    fn endianness() {
+        assert!(Console::Gamecube.is_big_endian());
+        assert!(!Console::PC.is_big_endian());
+        assert!(!Console::PS2.is_big_endian());
+        assert!(!Console::Xbox.is_big_endian());
+        assert_eq!(Console::Xbox.endianness(), Endianness::Little);
+    }
 }
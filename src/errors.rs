@@ -13,16 +13,69 @@ pub enum Error {
     /// An error generated when trying to deserialise a class from a .bin file.
     ClassDeserialiseError(classes::Error),
 
+    /// An error generated when parsing the name of a console that does not
+    /// exist. Contains the name given.
+    ConsoleNameError(String),
+
     /// An error generated when trying to read or write a number value for the
     /// given console. Contains the error generated by the `byteorder` crate.
     ConsoleNumberError(io::Error),
 
+    /// An error generated when compressed data is truncated or malformed.
+    /// Contains the offset within the compressed data of the problem.
+    DecompressionError { offset: usize },
+
+    /// An error generated when a file being added to the MASTER.DAT already
+    /// exists. Contains the path of the file.
+    FileConflictError(String),
+
     /// An error generated if there is an error reading or writing to a file.
     FileError(io::Error),
 
+    /// An error generated when the header of a .bin file describes regions
+    /// that do not fit within the file, such as when the file is not a .bin
+    /// file at all. Contains the offset within the header of the bad value.
+    InvalidBinHeaderError { offset: usize },
+
+    /// An error generated when a texpack does not begin with the magic bytes
+    /// expected for the console. Contains the bytes found instead.
+    InvalidTexpackMagicError { magic: Vec<u8> },
+
+    /// An error generated when reading or writing the JSON form of a file.
+    /// Contains the error generated by the `serde_json` crate.
+    JsonError(serde_json::Error),
+
+    /// An error generated when a file does not exist within the MASTER.DAT.
+    /// Contains the path of the requested file.
+    MissingFileError(String),
+
+    /// An error generated when a .bin file does not contain a section with the
+    /// given number.
+    MissingSectionError { number: u32 },
+
     /// An error generated when a string cannot be decoded from an in-game file.
     /// Contains the error generated by the `ISO_8859_1` crate.
     StringDeserialiseError(Cow<'static, str>),
+
+    /// An error generated when a string at the given `offset` within an
+    /// in-game file cannot be decoded, or cannot be encoded to be written to
+    /// that offset. Contains the error generated by the `ISO_8859_1` crate.
+    StringError {
+        offset: usize,
+        source: Cow<'static, str>,
+    },
+
+    /// An error generated when the file at `path` within the MASTER.DAT would
+    /// end at `end`, which is past the end of the MASTER.DAT.
+    TruncatedFileError {
+        path: String,
+        end: usize,
+        file_size: usize,
+    },
+
+    /// An error generated when a texpack entry has a file type that is not
+    /// recognised. Contains the value of the file type field.
+    UnknownTexpackEntryTypeError { value: u32 },
 }
 
 impl From<classes::Error> for Error {
@@ -37,21 +90,81 @@ impl From<std::io::Error> for Error {
     }
 }
 
+impl From<serde_json::Error> for Error {
+    fn from(error: serde_json::Error) -> Self {
+        Error::JsonError(error)
+    }
+}
+
 impl From<Cow<'static, str>> for Error {
     fn from(error: Cow<'static, str>) -> Self {
         Error::StringDeserialiseError(error)
     }
 }
 
+impl Error {
+    /// Returns the error with the `offset` of the string being read or
+    /// written attached, if it is an error decoding or encoding a string.
+    /// Other errors are returned unchanged.
+    pub(crate) fn at_offset(self, offset: usize) -> Error {
+        match self {
+            Error::StringDeserialiseError(source) => Error::StringError { offset, source },
+            e => e,
+        }
+    }
+}
+
 impl error::Error for Error {}
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             Error::ClassDeserialiseError(e) => e.fmt(f),
+            Error::ConsoleNameError(name) => write!(
+                f,
+                "Unrecognised console '{}': must be one of 'gamecube', 'gc', 'pc', 'ps2' or 'xbox'",
+                name
+            ),
             Error::ConsoleNumberError(e) => e.fmt(f),
+            Error::DecompressionError { offset } => {
+                write!(f, "Malformed compressed data at offset 0x{:X}", offset)
+            }
+            Error::FileConflictError(path) => {
+                write!(f, "File '{}' already exists in the MASTER.DAT", path)
+            }
             Error::FileError(e) => e.fmt(f),
+            Error::InvalidBinHeaderError { offset } => write!(
+                f,
+                "Not a .bin file - invalid header value at offset 0x{:X}",
+                offset
+            ),
+            Error::InvalidTexpackMagicError { magic } => {
+                write!(f, "Not a texpack - begins with {:02X?}", magic)
+            }
+            Error::JsonError(e) => e.fmt(f),
+            Error::MissingFileError(path) => write!(f, "No file '{}' in the MASTER.DAT", path),
+            Error::MissingSectionError { number } => {
+                write!(f, "No section with number {} in the .bin file", number)
+            }
             Error::StringDeserialiseError(s) => write!(f, "{}", s.deref()),
+            Error::StringError { offset, source } => write!(
+                f,
+                "Invalid string at offset 0x{:X}: {}",
+                offset,
+                source.deref()
+            ),
+            Error::TruncatedFileError {
+                path,
+                end,
+                file_size,
+            } => write!(
+                f,
+                "File '{}' ends at {}, past the end of the MASTER.DAT of size {}",
+                path, end, file_size
+            ),
+            Error::UnknownTexpackEntryTypeError { value } => {
+                write!(f, "Unknown texpack entry type 0x{:X}", value)
+            }
         }
     }
 }
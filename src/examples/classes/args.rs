@@ -41,13 +41,7 @@ impl Config {
         let dat = PathBuf::from(matches.opt_str("a").unwrap());
         let dir = PathBuf::from(matches.opt_str("i").unwrap());
         let console = match matches.opt_str("console") {
-            Some(c) => match c.as_ref() {
-                "gc" => Console::Gamecube,
-                "pc" => Console::PC,
-                "ps2" => Console::PS2,
-                "xbox" => Console::Xbox,
-                _ => return Err(String::from("unrecognised console")),
-            },
+            Some(c) => c.parse::<Console>().map_err(|e| e.to_string())?,
             _ => Console::PC,
         };
 
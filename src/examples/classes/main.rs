@@ -12,7 +12,7 @@ use args::Config;
 
 /// Function for printing additional info on classes of types we can decode.
 fn print_class_additional_info(bin: &Bin, object: &BinObject) {
-    match object.name {
+    match object.name.as_str() {
         "gf::LocalizedString" => {
             let localized_string = bin
                 .get_object_from_offset::<LocalizedString>(object.offset)
@@ -74,9 +74,16 @@ fn main() {
 
                 if !bin.objects().is_empty() {
                     println!("{} ({} objects)", filepath, bin.objects().len());
-                    for object in bin.objects() {
-                        print!("\t+{:04x}: {}", object.offset + 0x40, object.name);
-                        print_class_additional_info(&bin, &object);
+                    for (name, object) in bin.named_objects() {
+                        print!(
+                            "\t+{:04x}: {}",
+                            Bin::file_offset(object.offset),
+                            object.name
+                        );
+                        if !name.is_empty() {
+                            print!(" '{}'", name);
+                        }
+                        print_class_additional_info(&bin, object);
                         println!();
                     }
                 }
@@ -0,0 +1,56 @@
+use std::env;
+use std::fs;
+use std::process;
+
+extern crate shrek_superslam;
+use shrek_superslam::classes::{attacks_to_csv, AttackMoveType};
+use shrek_superslam::files::Bin;
+use shrek_superslam::{MasterDat, MasterDir};
+
+mod args;
+use args::Config;
+
+fn main() {
+    let config = Config::new(env::args()).unwrap_or_else(|err| {
+        println!("Unable to parse args: {}", err);
+        process::exit(1);
+    });
+
+    // Read the MASTER.DIR and MASTER.DAT files
+    let master_dir = match MasterDir::from_file(&config.master_dir_path, config.console) {
+        Ok(m) => m,
+        Err(e) => panic!("failed to read {:?}: {}", &config.master_dir_path, e),
+    };
+    let master_dat = match MasterDat::from_file(&config.master_dat_path, master_dir) {
+        Ok(m) => m,
+        Err(e) => panic!("failed to read {:?}: {}", &config.master_dat_path, e),
+    };
+
+    // Collect every Game::AttackMoveType object from every player.db.bin
+    // file, alongside the name of the character it belongs to
+    let mut attacks = Vec::<(String, AttackMoveType)>::new();
+    for filepath in master_dat.files() {
+        let mut iter = filepath.rsplit('\\').take(2);
+        if iter.next() != Some("player.db.bin") {
+            continue;
+        }
+
+        // Get the character name from the directory containing the file
+        let character = iter.next().unwrap().to_owned();
+        let bin = Bin::new(
+            master_dat.decompressed_file(&filepath).unwrap(),
+            config.console,
+        )
+        .unwrap_or_else(|e| panic!("Error reading '{}': {:?}", &filepath, e));
+        for (_, attack) in bin.get_all_objects_of_type::<AttackMoveType>() {
+            attacks.push((character.clone(), attack));
+        }
+    }
+
+    // Sort by character, keeping the order of the attacks within each file
+    attacks.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let csv = attacks_to_csv(attacks.iter().map(|(c, a)| (c.as_str(), a)));
+    fs::write(&config.csv, csv)
+        .unwrap_or_else(|e| panic!("failed to write {:?}: {}", &config.csv, e));
+}
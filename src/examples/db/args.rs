@@ -43,13 +43,7 @@ impl Config {
 
         let db = PathBuf::from(matches.opt_str("d").unwrap());
         let console = match matches.opt_str("console") {
-            Some(c) => match c.as_ref() {
-                "gc" => Console::Gamecube,
-                "pc" => Console::PC,
-                "ps2" => Console::PS2,
-                "xbox" => Console::Xbox,
-                _ => return Err(String::from("unrecognised console")),
-            },
+            Some(c) => c.parse::<Console>().map_err(|e| e.to_string())?,
             _ => Console::PC,
         };
 
@@ -22,6 +22,11 @@ fn main() {
     // Dump the contents of the object list
     println!("offset,name,type");
     for entry in &db.entries {
-        println!("{:08X},{},{}", entry.1.offset + 0x40, entry.0, entry.1.name);
+        println!(
+            "{:08X},{},{}",
+            Bin::file_offset(entry.1.offset),
+            entry.0,
+            entry.1.name
+        );
     }
 }
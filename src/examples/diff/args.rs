@@ -0,0 +1,92 @@
+use std::path::PathBuf;
+
+use getopts::Options;
+
+use shrek_superslam::Console;
+
+/// Possible arguments to the program
+pub struct Config {
+    /// The path to the original MASTER.DAT file
+    pub old_master_dat_path: PathBuf,
+
+    /// The path to the original MASTER.DIR file
+    pub old_master_dir_path: PathBuf,
+
+    /// The path to the modified MASTER.DAT file
+    pub new_master_dat_path: PathBuf,
+
+    /// The path to the modified MASTER.DIR file
+    pub new_master_dir_path: PathBuf,
+
+    /// True to print the differences as JSON
+    pub json: bool,
+
+    /// The console version of the files
+    pub console: Console,
+}
+
+impl Config {
+    /// Parse the commandline arguments and return them as a new Config
+    ///
+    /// # Parameters
+    ///
+    /// - `args`: The commandline arguments passed to the program
+    ///
+    /// # Returns
+    ///
+    /// An `Ok(Config)` populated with the passed commandline arguments, or an
+    /// `Err(str)` containing an error message if the arguments could not be
+    /// parsed.
+    pub fn new(args: std::env::Args) -> Result<Config, String> {
+        if args.len() < 2 {
+            return Err(String::from("not enough arguments"));
+        }
+
+        let mut opts = Options::new();
+        opts.reqopt(
+            "",
+            "old-dat",
+            "path to the original MASTER.DAT",
+            "MASTER.DAT",
+        );
+        opts.reqopt(
+            "",
+            "old-dir",
+            "path to the original MASTER.DIR",
+            "MASTER.DIR",
+        );
+        opts.reqopt(
+            "",
+            "new-dat",
+            "path to the modified MASTER.DAT",
+            "MASTER.DAT",
+        );
+        opts.reqopt(
+            "",
+            "new-dir",
+            "path to the modified MASTER.DIR",
+            "MASTER.DIR",
+        );
+        opts.optopt("c", "console", "target console", "gc|pc|ps2|xbox");
+        opts.optflag("", "json", "print the differences as JSON");
+        let args: Vec<String> = args.collect();
+        let matches = match opts.parse(&args[1..]) {
+            Ok(m) => m,
+            Err(f) => return Err(f.to_string()),
+        };
+
+        let console = match matches.opt_str("console") {
+            Some(c) => c.parse::<Console>().map_err(|e| e.to_string())?,
+            _ => Console::PC,
+        };
+
+        Ok(Config {
+            old_master_dat_path: PathBuf::from(matches.opt_str("old-dat").unwrap()),
+            old_master_dir_path: PathBuf::from(matches.opt_str("old-dir").unwrap()),
+            new_master_dat_path: PathBuf::from(matches.opt_str("new-dat").unwrap()),
+            new_master_dir_path: PathBuf::from(matches.opt_str("new-dir").unwrap()),
+            json: matches.opt_present("json"),
+            console,
+        })
+    }
+}
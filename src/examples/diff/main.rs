@@ -0,0 +1,201 @@
+use std::collections::BTreeMap;
+use std::env;
+use std::path::Path;
+use std::process;
+
+use serde::Serialize;
+
+extern crate shrek_superslam;
+use shrek_superslam::classes::ShrekSuperSlamObject;
+use shrek_superslam::files::Bin;
+use shrek_superslam::{Console, MasterDat, MasterDir};
+
+mod args;
+use args::Config;
+
+/// The differences between two MASTER.DAT files
+#[derive(Default, Serialize)]
+struct Diff {
+    /// The paths of the files only in the modified MASTER.DAT
+    added: Vec<String>,
+
+    /// The paths of the files only in the original MASTER.DAT
+    removed: Vec<String>,
+
+    /// The files in both MASTER.DATs whose contents differ
+    changed: Vec<ChangedFile>,
+}
+
+/// A file whose contents differ between two MASTER.DAT files
+#[derive(Serialize)]
+struct ChangedFile {
+    /// The path of the file
+    path: String,
+
+    /// The names of the `gf::DB` objects that differ, if the file is a
+    /// .db.bin file
+    objects: Vec<String>,
+}
+
+/// Read the MASTER.DAT at `dat_path`, using the MASTER.DIR at `dir_path`
+///
+/// # Parameters
+///
+/// - `dat_path`: The path to the MASTER.DAT file
+/// - `dir_path`: The path to the MASTER.DIR file
+/// - `console`: The console the files come from
+///
+/// # Returns
+///
+/// The parsed MASTER.DAT. Panics if either file cannot be read.
+fn read_master_dat(dat_path: &Path, dir_path: &Path, console: Console) -> MasterDat {
+    let master_dir = match MasterDir::from_file(dir_path, console) {
+        Ok(m) => m,
+        Err(e) => panic!("failed to read {:?}: {}", dir_path, e),
+    };
+    match MasterDat::from_file(dat_path, master_dir) {
+        Ok(m) => m,
+        Err(e) => panic!("failed to read {:?}: {}", dat_path, e),
+    }
+}
+
+/// Get the supported objects within the given .db.bin file, grouped by their
+/// name in the file's `gf::DB`
+///
+/// # Parameters
+///
+/// - `path`: The path of the file, for error messages
+/// - `data`: The decompressed bytes of the file
+/// - `console`: The console the file comes from
+///
+/// # Returns
+///
+/// Every object of each name, in the order of the `gf::DB`.
+fn named_objects(
+    path: &str,
+    data: Vec<u8>,
+    console: Console,
+) -> BTreeMap<String, Vec<ShrekSuperSlamObject>> {
+    let bin =
+        Bin::new(data, console).unwrap_or_else(|e| panic!("Error reading '{}': {:?}", path, e));
+    let mut objects = BTreeMap::<String, Vec<ShrekSuperSlamObject>>::new();
+    for (name, object) in bin
+        .parse_ordered()
+        .unwrap_or_else(|e| panic!("Error parsing '{}': {:?}", path, e))
+    {
+        objects.entry(name).or_default().push(object);
+    }
+
+    objects
+}
+
+/// Compare the given original and modified .db.bin files
+///
+/// # Parameters
+///
+/// - `path`: The path of the file, for error messages
+/// - `old`: The decompressed bytes of the original file
+/// - `new`: The decompressed bytes of the modified file
+/// - `console`: The console the files come from
+///
+/// # Returns
+///
+/// The names of the `gf::DB` objects that were added, removed or changed.
+fn changed_objects(path: &str, old: Vec<u8>, new: Vec<u8>, console: Console) -> Vec<String> {
+    let old = named_objects(path, old, console);
+    let mut new = named_objects(path, new, console);
+
+    let mut changed = vec![];
+    for (name, objects) in old {
+        if new.remove(&name).as_ref() != Some(&objects) {
+            changed.push(name);
+        }
+    }
+    changed.extend(new.into_keys());
+    changed.sort();
+
+    changed
+}
+
+/// Compare the given original and modified MASTER.DAT files
+///
+/// # Parameters
+///
+/// - `old`: The original MASTER.DAT
+/// - `new`: The modified MASTER.DAT
+/// - `console`: The console the files come from
+///
+/// # Returns
+///
+/// The files that were added, removed or changed.
+fn diff(old: &MasterDat, new: &MasterDat, console: Console) -> Diff {
+    let mut diff = Diff::default();
+    for (path, old_data) in old.iter() {
+        if !new.contains(path) {
+            diff.removed.push(path.to_owned());
+            continue;
+        }
+
+        let old_data = old_data.unwrap_or_else(|e| panic!("Error reading '{}': {}", path, e));
+        let new_data = new
+            .decompressed_file(path)
+            .unwrap_or_else(|e| panic!("Error reading '{}': {}", path, e));
+        if old_data != new_data {
+            let objects = if path.ends_with(".db.bin") {
+                changed_objects(path, old_data, new_data, console)
+            } else {
+                vec![]
+            };
+            diff.changed.push(ChangedFile {
+                path: path.to_owned(),
+                objects,
+            });
+        }
+    }
+
+    diff.added = new
+        .iter_compressed()
+        .map(|(path, _)| path)
+        .filter(|path| !old.contains(path))
+        .map(|path| path.to_owned())
+        .collect();
+
+    diff
+}
+
+fn main() {
+    let config = Config::new(env::args()).unwrap_or_else(|err| {
+        println!("Unable to parse args: {}", err);
+        process::exit(1);
+    });
+
+    let old = read_master_dat(
+        &config.old_master_dat_path,
+        &config.old_master_dir_path,
+        config.console,
+    );
+    let new = read_master_dat(
+        &config.new_master_dat_path,
+        &config.new_master_dir_path,
+        config.console,
+    );
+    let diff = diff(&old, &new, config.console);
+
+    if config.json {
+        println!("{}", serde_json::to_string_pretty(&diff).unwrap());
+        return;
+    }
+
+    for path in &diff.added {
+        println!("added: {}", path);
+    }
+    for path in &diff.removed {
+        println!("removed: {}", path);
+    }
+    for file in &diff.changed {
+        println!("changed: {}", file.path);
+        for name in &file.objects {
+            println!("    {}", name);
+        }
+    }
+}
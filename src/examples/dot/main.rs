@@ -0,0 +1,21 @@
+use std::env;
+use std::fs;
+use std::process;
+
+extern crate shrek_superslam;
+use shrek_superslam::files::Bin;
+
+mod args;
+use args::Config;
+
+fn main() {
+    let config = Config::new(env::args()).unwrap_or_else(|err| {
+        println!("Unable to parse args: {}", err);
+        process::exit(1);
+    });
+
+    // Read the .bin file and write the graph of its objects, which can be
+    // rendered with Graphviz, e.g. `dot -Tsvg player.dot -o player.svg`
+    let bin = Bin::new(fs::read(&config.db_path).unwrap(), config.console).unwrap();
+    fs::write(&config.dot_path, bin.to_dot()).unwrap();
+}
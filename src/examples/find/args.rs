@@ -0,0 +1,82 @@
+use std::path::PathBuf;
+
+use getopts::Options;
+
+use shrek_superslam::classes::class_hash_for_name;
+use shrek_superslam::Console;
+
+/// Possible arguments to the program
+pub struct Config {
+    /// The path to the MASTER.DAT file
+    pub master_dat_path: PathBuf,
+
+    /// The path to the MASTER.DIR file
+    pub master_dir_path: PathBuf,
+
+    /// The hash of the class to search for
+    pub hash: u32,
+
+    /// The console version of the files
+    pub console: Console,
+}
+
+impl Config {
+    /// Parse the commandline arguments and return them as a new Config
+    ///
+    /// # Parameters
+    ///
+    /// - `args`: The commandline arguments passed to the program
+    ///
+    /// # Returns
+    ///
+    /// An `Ok(Config)` populated with the passed commandline arguments, or an
+    /// `Err(str)` containing an error message if the arguments could not be
+    /// parsed.
+    pub fn new(args: std::env::Args) -> Result<Config, String> {
+        if args.len() < 2 {
+            return Err(String::from("not enough arguments"));
+        }
+
+        let mut opts = Options::new();
+        opts.reqopt("a", "dat", "path to MASTER.DAT", "MASTER.DAT");
+        opts.reqopt("i", "dir", "path to MASTER.DIR", "MASTER.DIR");
+        opts.optopt(
+            "t",
+            "type",
+            "name of the class to find",
+            "Game::AttackMoveType",
+        );
+        opts.optopt("h", "hash", "hash of the class to find", "0xEBF07BB5");
+        opts.optopt("c", "console", "target console", "gc|pc|ps2|xbox");
+        let args: Vec<String> = args.collect();
+        let matches = match opts.parse(&args[1..]) {
+            Ok(m) => m,
+            Err(f) => return Err(f.to_string()),
+        };
+
+        let dat = PathBuf::from(matches.opt_str("a").unwrap());
+        let dir = PathBuf::from(matches.opt_str("i").unwrap());
+        let hash = match (matches.opt_str("t"), matches.opt_str("h")) {
+            (Some(name), None) => class_hash_for_name(&name)
+                .ok_or_else(|| format!("unrecognised class name '{}'", name))?,
+            (None, Some(hash)) => u32::from_str_radix(hash.trim_start_matches("0x"), 16)
+                .map_err(|_| format!("invalid hash '{}': must be hexadecimal", hash))?,
+            _ => {
+                return Err(String::from(
+                    "exactly one of --type or --hash must be given",
+                ))
+            }
+        };
+        let console = match matches.opt_str("console") {
+            Some(c) => c.parse::<Console>().map_err(|e| e.to_string())?,
+            _ => Console::PC,
+        };
+
+        Ok(Config {
+            master_dat_path: dat,
+            master_dir_path: dir,
+            hash,
+            console,
+        })
+    }
+}
@@ -0,0 +1,54 @@
+use std::env;
+use std::process;
+
+extern crate shrek_superslam;
+use shrek_superslam::files::Bin;
+use shrek_superslam::{MasterDat, MasterDir};
+
+mod args;
+use args::Config;
+
+fn main() {
+    let config = Config::new(env::args()).unwrap_or_else(|err| {
+        println!("Unable to parse args: {}", err);
+        process::exit(1);
+    });
+
+    // Read the MASTER.DIR and MASTER.DAT files
+    let master_dir = match MasterDir::from_file(&config.master_dir_path, config.console) {
+        Ok(m) => m,
+        Err(e) => panic!("failed to read {:?}: {}", &config.master_dir_path, e),
+    };
+    let master_dat = match MasterDat::from_file(&config.master_dat_path, master_dir) {
+        Ok(m) => m,
+        Err(e) => panic!("failed to read {:?}: {}", &config.master_dat_path, e),
+    };
+
+    // Print the file, offset and gf::DB name of every object of the class
+    // within every .db.bin file
+    println!("file,offset,name");
+    for (filepath, data) in master_dat.iter() {
+        if !filepath.ends_with(".db.bin") {
+            continue;
+        }
+
+        let data = data.unwrap_or_else(|e| panic!("Error reading '{}': {}", filepath, e));
+        let bin = match Bin::new(data, config.console) {
+            Ok(b) => b,
+            Err(e) => {
+                eprintln!("skipping '{}': {}", filepath, e);
+                continue;
+            }
+        };
+        for (name, object) in bin.named_objects() {
+            if object.hash == config.hash {
+                println!(
+                    "{},{:08X},{}",
+                    filepath,
+                    Bin::file_offset(object.offset),
+                    name
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use std::env;
+use std::process;
+
+extern crate shrek_superslam;
+use shrek_superslam::{MasterDat, MasterDir};
+
+mod args;
+use args::Config;
+
+fn main() {
+    let config = Config::new(env::args()).unwrap_or_else(|err| {
+        println!("Unable to parse args: {}", err);
+        process::exit(1);
+    });
+
+    // Read the MASTER.DIR and MASTER.DAT files
+    let master_dir = match MasterDir::from_file(&config.master_dir_path, config.console) {
+        Ok(m) => m,
+        Err(e) => panic!("failed to read {:?}: {}", &config.master_dir_path, e),
+    };
+    let master_dat = match MasterDat::from_file(&config.master_dat_path, master_dir) {
+        Ok(m) => m,
+        Err(e) => panic!("failed to read {:?}: {}", &config.master_dat_path, e),
+    };
+
+    // Print the description of every file as JSON
+    let manifest = master_dat
+        .manifest()
+        .unwrap_or_else(|e| panic!("failed to read files: {}", e));
+    println!("{}", serde_json::to_string_pretty(&manifest).unwrap());
+}
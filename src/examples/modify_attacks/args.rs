@@ -13,6 +13,15 @@ pub enum Mode {
     Write,
 }
 
+/// The format of the attacks file
+pub enum Format {
+    /// The attacks file is JSON
+    Json,
+
+    /// The attacks file is YAML. Requires the `yaml` feature.
+    Yaml,
+}
+
 /// Possible arguments to the program
 pub struct Config {
     /// The path to the MASTER.DAT file
@@ -21,12 +30,15 @@ pub struct Config {
     /// The path to the MASTER.DIR file
     pub master_dir_path: PathBuf,
 
-    /// The path to the attacks JSON to read or write
+    /// The path to the attacks file to read or write
     pub json: PathBuf,
 
     /// The mode of the program
     pub mode: Mode,
 
+    /// The format of the attacks file
+    pub format: Format,
+
     /// The console version of the files
     pub console: Console,
 }
@@ -54,10 +66,11 @@ impl Config {
         opts.reqopt(
             "j",
             "json",
-            "path to the JSON file to read or write to",
+            "path to the JSON or YAML file to read or write to",
             "shreksuperslam-character-attacks.json",
         );
         opts.reqopt("m", "mode", "read or write mode", "read|write");
+        opts.optopt("f", "format", "format of the attacks file", "json|yaml");
         opts.optopt("c", "console", "target console", "gc|pc|ps2|xbox");
         let args: Vec<String> = args.collect();
         let matches = match opts.parse(&args[1..]) {
@@ -81,19 +94,26 @@ impl Config {
             },
             _ => return Err(String::from("no mode given - must be 'read' or 'write'")),
         };
-        let console = match matches.opt_str("console") {
-            Some(c) => match c.as_ref() {
-                "gc" => Console::Gamecube,
-                "pc" => Console::PC,
-                "ps2" => Console::PS2,
-                "xbox" => Console::Xbox,
+        let format = match matches.opt_str("f") {
+            Some(f) => match f.to_ascii_lowercase().as_ref() {
+                "json" => Format::Json,
+                "yaml" if cfg!(feature = "yaml") => Format::Yaml,
+                "yaml" => {
+                    return Err(String::from(
+                        "YAML support requires building with the 'yaml' feature",
+                    ))
+                }
                 _ => {
                     return Err(format!(
-                        "unrecognised console '{}': must be one of 'gc', 'pc', 'ps2' or 'xbox'",
-                        c
+                        "unrecognised format '{}': must be 'json' or 'yaml'",
+                        f
                     ))
                 }
             },
+            _ => Format::Json,
+        };
+        let console = match matches.opt_str("console") {
+            Some(c) => c.parse::<Console>().map_err(|e| e.to_string())?,
             _ => Console::PC,
         };
 
@@ -102,6 +122,7 @@ impl Config {
             master_dir_path: dir,
             json,
             mode,
+            format,
             console,
         })
     }
@@ -12,12 +12,13 @@ use shrek_superslam::Console;
 use shrek_superslam::{MasterDat, MasterDir};
 
 mod args;
-use args::{Config, Mode};
+use args::{Config, Format, Mode};
 
-/// Get all character Game::AttackMoveType objects and put them in a JSON
-/// structure, then write that JSON to a new file
+/// Get all character Game::AttackMoveType objects and put them in a JSON or
+/// YAML structure, then write that structure to a new file
 ///
-/// The resultant JSON looks like:
+/// The resultant JSON looks like the following, with the YAML having the same
+/// structure:
 ///
 /// ```
 /// {
@@ -49,8 +50,9 @@ use args::{Config, Mode};
 ///
 /// - `master_dat`: The game's MASTER.DAT file
 /// - `console`: The console the MASTER.DAT comes from
-/// - `json_path`: The path to write the JSON representation to
-fn attacks_to_json(master_dat: &MasterDat, console: Console, json_path: &Path) {
+/// - `path`: The path to write the JSON or YAML representation to
+/// - `format`: Whether to write JSON or YAML
+fn attacks_to_file(master_dat: &MasterDat, console: Console, path: &Path, format: &Format) {
     // A BTreeMap is used so that the output values are sorted by key
     let mut attacks = BTreeMap::<String, Vec<AttackMoveType>>::new();
 
@@ -78,13 +80,19 @@ fn attacks_to_json(master_dat: &MasterDat, console: Console, json_path: &Path) {
         }
     }
 
-    // Write the object to a JSON file
-    let file = File::create(&json_path).unwrap();
+    // Write the object to a JSON or YAML file
+    let file = File::create(path).unwrap();
     let writer = BufWriter::new(file);
-    serde_json::to_writer_pretty(writer, &attacks).unwrap();
+    match format {
+        Format::Json => serde_json::to_writer_pretty(writer, &attacks).unwrap(),
+        #[cfg(feature = "yaml")]
+        Format::Yaml => serde_yaml::to_writer(writer, &attacks).unwrap(),
+        #[cfg(not(feature = "yaml"))]
+        Format::Yaml => unreachable!("YAML support requires the 'yaml' feature"),
+    }
 }
 
-/// Writes the character attack values from the given JSON file to the
+/// Writes the character attack values from the given JSON or YAML file to the
 /// MASTER.DAT, then writes out a new MASTER.DAT and MASTER.DIR pair containing
 /// the changes
 ///
@@ -92,13 +100,25 @@ fn attacks_to_json(master_dat: &MasterDat, console: Console, json_path: &Path) {
 ///
 /// - `master_dat`: The game's MASTER.DAT file
 /// - `console`: The console the MASTER.DAT comes from
-/// - `json_path`: The path to read the updated attack values from
-fn write_new_attack_data(master_dat: &mut MasterDat, console: Console, json_path: &Path) {
-    // Load and deserialise the JSON file
-    let file = File::open(&json_path).unwrap();
+/// - `path`: The path to read the updated attack values from
+/// - `format`: Whether the file is JSON or YAML
+fn write_new_attack_data(
+    master_dat: &mut MasterDat,
+    console: Console,
+    path: &Path,
+    format: &Format,
+) {
+    // Load and deserialise the JSON or YAML file
+    let file = File::open(path).unwrap();
     let reader = BufReader::new(file);
 
-    let attacks: HashMap<String, Vec<AttackMoveType>> = serde_json::from_reader(reader).unwrap();
+    let attacks: HashMap<String, Vec<AttackMoveType>> = match format {
+        Format::Json => serde_json::from_reader(reader).unwrap(),
+        #[cfg(feature = "yaml")]
+        Format::Yaml => serde_yaml::from_reader(reader).unwrap(),
+        #[cfg(not(feature = "yaml"))]
+        Format::Yaml => unreachable!("YAML support requires the 'yaml' feature"),
+    };
     for (character, attacks) in &attacks {
         // Read the player.db.bin file for this character
         let filename = format!("data\\players\\{}\\player.db.bin", character);
@@ -133,12 +153,8 @@ fn write_new_attack_data(master_dat: &mut MasterDat, console: Console, json_path
         }
 
         // Write the updated .bin file to the MASTER.DAT
-        if let Err(i) = master_dat.update_file(&filename, bin.raw()) {
-            panic!(
-                "updated file had wrong size: {} instead of {}",
-                bin.raw().len(),
-                i
-            );
+        if let Err(e) = master_dat.update_file(&filename, bin.raw()) {
+            panic!("error updating '{}': {}", filename, e);
         }
     }
 
@@ -165,11 +181,18 @@ fn main() {
     };
 
     match config.mode {
-        // Read the attacks from the MASTER.DAT, and write them to a JSON file
-        Mode::Read => attacks_to_json(&master_dat, config.console, &config.json),
-
-        // Read in the JSON file, and write the new values to the MASTER.DAT,
-        // then write a new MASTER.DAT and MASTER.DIR pair with the changes
-        Mode::Write => write_new_attack_data(&mut master_dat, config.console, &config.json),
+        // Read the attacks from the MASTER.DAT, and write them to a JSON or
+        // YAML file
+        Mode::Read => attacks_to_file(&master_dat, config.console, &config.json, &config.format),
+
+        // Read in the JSON or YAML file, and write the new values to the
+        // MASTER.DAT, then write a new MASTER.DAT and MASTER.DIR pair with the
+        // changes
+        Mode::Write => write_new_attack_data(
+            &mut master_dat,
+            config.console,
+            &config.json,
+            &config.format,
+        ),
     };
 }
@@ -8,6 +8,7 @@ use shrek_superslam::Console;
 pub struct Config {
     pub data_path: PathBuf, // The path to the extracted data/ directory
     pub console: Console,   // The console version of the files
+    pub verify: bool,       // Whether to check each file survives compression
 }
 
 impl Config {
@@ -35,6 +36,11 @@ impl Config {
             "data/",
         );
         opts.optopt("c", "console", "target console", "gc|pc|ps2|xbox");
+        opts.optflag(
+            "v",
+            "verify",
+            "check each file decompresses back to the original",
+        );
         let args: Vec<String> = args.collect();
         let matches = match opts.parse(&args[1..]) {
             Ok(m) => m,
@@ -43,19 +49,14 @@ impl Config {
 
         let data = PathBuf::from(matches.opt_str("d").unwrap());
         let console = match matches.opt_str("console") {
-            Some(c) => match c.as_ref() {
-                "gc" => Console::Gamecube,
-                "pc" => Console::PC,
-                "ps2" => Console::PS2,
-                "xbox" => Console::Xbox,
-                _ => return Err(String::from("unrecognised console")),
-            },
+            Some(c) => c.parse::<Console>().map_err(|e| e.to_string())?,
             _ => Console::PC,
         };
 
         Ok(Config {
             data_path: data,
             console,
+            verify: matches.opt_present("verify"),
         })
     }
 }
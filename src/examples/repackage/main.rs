@@ -10,7 +10,7 @@ use walkdir::WalkDir;
 
 extern crate shrek_superslam;
 use shrek_superslam::files::Texpack;
-use shrek_superslam::{Console, MasterDat};
+use shrek_superslam::{verify_roundtrip, Console, MasterDat};
 
 mod args;
 use args::Config;
@@ -18,25 +18,8 @@ use args::Config;
 /// Repackage an extracted texpack located at the given `extracted_dir_path`
 /// for the given `console`.
 fn repackage_texpack(extracted_dir_path: &Path, texpack_path: &Path, console: Console) {
-    // Create a new empty texpack.
-    let mut texpack = Texpack::new(console);
-
-    // Iterate over the extracted directory and add each file to the new texpack.
-    for file in WalkDir::new(&extracted_dir_path)
-        .into_iter()
-        .filter_map(|e| e.ok())
-        .filter(|d| d.file_type().is_file())
-    {
-        let name = file
-            .path()
-            .file_stem()
-            .unwrap()
-            .to_str()
-            .unwrap()
-            .to_owned();
-        let file_contents = fs::read(&file.path()).unwrap();
-        texpack.add_file(name, &file_contents);
-    }
+    // Create a new texpack from every file in the extracted directory.
+    let texpack = Texpack::from_directory(extracted_dir_path, console).unwrap();
 
     // Write the texpack to a file, overwriting the original
     let mut texpack_outfile = File::create(texpack_path).unwrap();
@@ -47,25 +30,22 @@ fn repackage_texpack(extracted_dir_path: &Path, texpack_path: &Path, console: Co
 
 /// Perform additional operations on a file if required.
 fn additonal_file_operations(path: &Path, console: Console) {
-    match path.extension().unwrap_or(OsStr::new("")).to_str().unwrap() {
-        // If the file is a .texpack, check if an extracted directory exists
-        // for it, and repackage that directory into the .texpack file before
-        // then adding the new file to the MASTER.DAT.
-        "texpack" => {
-            // The extract program creates directories next to the texpack
-            // file with the same name as the texpack, with "-extract" appended.
-            let extracted_texpack_path = path.with_file_name(format!(
-                "{}-extracted",
-                path.file_name().unwrap().to_str().unwrap()
-            ));
-
-            // If that directory exists, turn it back into the texpack.
-            if extracted_texpack_path.exists() {
-                repackage_texpack(&extracted_texpack_path, path, console);
-            }
+    // If the file is a .texpack, check if an extracted directory exists
+    // for it, and repackage that directory into the .texpack file before
+    // then adding the new file to the MASTER.DAT.
+    if path.extension().unwrap_or(OsStr::new("")) == "texpack" {
+        // The extract program creates directories next to the texpack
+        // file with the same name as the texpack, with "-extract" appended.
+        let extracted_texpack_path = path.with_file_name(format!(
+            "{}-extracted",
+            path.file_name().unwrap().to_str().unwrap()
+        ));
+
+        // If that directory exists, turn it back into the texpack.
+        if extracted_texpack_path.exists() {
+            repackage_texpack(&extracted_texpack_path, path, console);
         }
-        _ => {}
-    };
+    }
 }
 
 /// Determine if the given `path` should be excluded from being repackaged or not.
@@ -74,16 +54,15 @@ fn additonal_file_operations(path: &Path, console: Console) {
 /// as the contents of extracted texpack files, that are not present in the original
 /// MASTER.DAT and MASTER.DIR.
 fn excluded(path: &Path) -> bool {
-    // Check the extension the parent directory. The current extractor ends
-    // extracted file directories with the "-extracted" suffix, and the old
-    // one made directories that ended with ".d". Both of these are excluded.
-    let parent = path
-        .parent()
-        .unwrap_or(Path::new(""))
-        .as_os_str()
-        .to_str()
-        .unwrap();
-    parent.ends_with("-extracted") || parent.ends_with(".d")
+    // Check the extension of each parent directory. The current extractor
+    // ends extracted file directories with the "-extracted" suffix, and the
+    // old one made directories that ended with ".d". Both of these are
+    // excluded, along with any directories within them, such as the metadata
+    // written by the texpack program.
+    path.ancestors().skip(1).any(|parent| {
+        let parent = parent.as_os_str().to_str().unwrap();
+        parent.ends_with("-extracted") || parent.ends_with(".d")
+    })
 }
 
 fn main() {
@@ -95,17 +74,18 @@ fn main() {
     // Create a new MASTER.DAT file
     let mut master_dat = MasterDat::new(config.console);
 
-    // Add each file in the data/ directory to the MASTER.DAT
+    // Read each file in the data/ directory, to be added to the MASTER.DAT
+    let mut files = vec![];
     for file in WalkDir::new(&config.data_path)
         .into_iter()
         .filter_map(|e| e.ok())
         .filter(|d| d.file_type().is_file())
-        .filter(|d| !excluded(&d.path()))
+        .filter(|d| !excluded(d.path()))
     {
         // Some files may require additional operations, such as repackaging
         // extracted files, before they are read and compressed into the
         // MASTER.DAT.
-        additonal_file_operations(&file.path(), config.console);
+        additonal_file_operations(file.path(), config.console);
 
         // Read the file
         let contents = fs::read(file.path()).expect("could not open file");
@@ -127,10 +107,21 @@ fn main() {
             .unwrap()
             .replace('/', "\\");
 
-        // Add the file to the MASTER.DAT
-        master_dat.add_file(relative_path, &contents);
+        // Optionally check the file will decompress correctly in the game
+        if config.verify && !verify_roundtrip(&contents) {
+            eprintln!(
+                "warning: '{}' does not decompress back to the original",
+                relative_path
+            );
+        }
+
+        files.push((relative_path, contents));
     }
 
+    // Compress and add every file to the MASTER.DAT, in the order they were
+    // read
+    master_dat.add_files_parallel(files);
+
     // Write the new MASTER.DAT and MASTER.DIR to files
     if let Err(e) = master_dat.write(&PathBuf::from("MASTER.DAT"), &PathBuf::from("MASTER.DIR")) {
         panic!("failed to write \"MASTER.DAT\" or \"MASTER.DIR\": {}", e);
@@ -0,0 +1,48 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process;
+
+extern crate shrek_superslam;
+use shrek_superslam::{MasterDat, MasterDir};
+
+mod args;
+use args::Config;
+
+fn main() {
+    let config = Config::new(env::args()).unwrap_or_else(|err| {
+        println!("Unable to parse args: {}", err);
+        process::exit(1);
+    });
+
+    // Read the MASTER.DIR and MASTER.DAT files
+    let master_dir = match MasterDir::from_file(&config.master_dir_path, config.console) {
+        Ok(m) => m,
+        Err(e) => panic!("failed to read {:?}: {}", &config.master_dir_path, e),
+    };
+    let mut master_dat = match MasterDat::from_file(&config.master_dat_path, master_dir) {
+        Ok(m) => m,
+        Err(e) => panic!("failed to read {:?}: {}", &config.master_dat_path, e),
+    };
+
+    // Replace the file, either compressing it or storing it exactly as it is.
+    // Every other file keeps its compressed bytes, only moving to make room
+    // if the new file is a different size.
+    let replacement = match fs::read(&config.replacement_path) {
+        Ok(r) => r,
+        Err(e) => panic!("failed to read {:?}: {}", &config.replacement_path, e),
+    };
+    let result = if config.compressed {
+        master_dat.update_compressed_file(&config.target, &replacement)
+    } else {
+        master_dat.update_file(&config.target, &replacement)
+    };
+    if let Err(e) = result {
+        panic!("failed to replace '{}': {}", &config.target, e);
+    }
+
+    // Write the updated MASTER.DAT and MASTER.DIR to new files
+    master_dat
+        .write(Path::new("MASTER.DAT"), Path::new("MASTER.DIR"))
+        .expect("could not write out new files");
+}
@@ -0,0 +1,91 @@
+use std::path::PathBuf;
+
+use getopts::Options;
+
+use shrek_superslam::Console;
+
+/// The mode of the program
+pub enum Mode {
+    /// Extracts the texpack to the directory
+    Extract,
+
+    /// Packs the directory into the texpack
+    Pack,
+}
+
+/// Possible arguments to the program
+pub struct Config {
+    /// The path to the texpack file to read or write
+    pub texpack_path: PathBuf,
+
+    /// The path to the directory of textures to write or read
+    pub dir_path: PathBuf,
+
+    /// The mode of the program
+    pub mode: Mode,
+
+    /// The console version of the files
+    pub console: Console,
+}
+
+impl Config {
+    /// Parse the commandline arguments and return them as a new Config
+    ///
+    /// # Parameters
+    ///
+    /// - `args`: The commandline arguments passed to the program
+    ///
+    /// # Returns
+    ///
+    /// An `Ok(Config)` populated with the passed commandline arguments, or an
+    /// `Err(str)` containing an error message if the arguments could not be
+    /// parsed.
+    pub fn new(args: std::env::Args) -> Result<Config, String> {
+        if args.len() < 2 {
+            return Err(String::from("not enough arguments"));
+        }
+
+        let mut opts = Options::new();
+        opts.reqopt("t", "texpack", "path to the .texpack", "object.texpack");
+        opts.reqopt(
+            "d",
+            "dir",
+            "path to the directory of textures",
+            "object.texpack-extracted",
+        );
+        opts.reqopt("m", "mode", "extract or pack mode", "extract|pack");
+        opts.optopt("c", "console", "target console", "gc|pc|ps2|xbox");
+        let args: Vec<String> = args.collect();
+        let matches = match opts.parse(&args[1..]) {
+            Ok(m) => m,
+            Err(f) => return Err(f.to_string()),
+        };
+
+        let texpack = PathBuf::from(matches.opt_str("t").unwrap());
+        let dir = PathBuf::from(matches.opt_str("d").unwrap());
+        let mode = match matches.opt_str("m") {
+            Some(m) => match m.to_ascii_lowercase().as_ref() {
+                "extract" => Mode::Extract,
+                "pack" => Mode::Pack,
+                _ => {
+                    return Err(format!(
+                        "unrecognised mode '{}': must be 'extract' or 'pack'",
+                        m
+                    ))
+                }
+            },
+            _ => return Err(String::from("no mode given - must be 'extract' or 'pack'")),
+        };
+        let console = match matches.opt_str("console") {
+            Some(c) => c.parse::<Console>().map_err(|e| e.to_string())?,
+            _ => Console::PC,
+        };
+
+        Ok(Config {
+            texpack_path: texpack,
+            dir_path: dir,
+            mode,
+            console,
+        })
+    }
+}
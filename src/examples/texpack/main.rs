@@ -0,0 +1,113 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process;
+
+use serde::Serialize;
+
+extern crate shrek_superslam;
+use shrek_superslam::files::Texpack;
+use shrek_superslam::Console;
+
+mod args;
+use args::{Config, Mode};
+
+/// The name of the directory within the extracted directory that holds the
+/// description of each texture. Being a directory, it is skipped when the
+/// textures are packed again.
+const METADATA_DIR: &str = "metadata";
+
+/// Description of a single file extracted from a texpack, written alongside
+/// the file as JSON
+#[derive(Serialize)]
+struct TextureMetadata {
+    /// The filename of the file, including extension
+    filename: String,
+
+    /// The hash of the full filename
+    hash: u32,
+
+    /// The type of the file within the texpack
+    filetype: String,
+
+    /// The size of the file in bytes
+    size: u32,
+
+    /// The width of the texture in pixels, if known
+    width: Option<u32>,
+
+    /// The height of the texture in pixels, if known
+    height: Option<u32>,
+
+    /// The number of mipmaps in the texture, if known
+    mip_count: Option<u32>,
+
+    /// The compression format of the texture, if known and compressed
+    format: Option<String>,
+}
+
+/// Extract each file within the texpack to the given directory, alongside a
+/// JSON description of each file
+///
+/// # Parameters
+///
+/// - `texpack_path`: The path to the texpack to extract
+/// - `dir_path`: The directory to extract the files to
+/// - `console`: The console the texpack comes from
+fn extract(texpack_path: &Path, dir_path: &Path, console: Console) {
+    let texpack = Texpack::from_file(texpack_path, console)
+        .unwrap_or_else(|e| panic!("failed to read {:?}: {}", texpack_path, e));
+    texpack.to_directory(dir_path).expect("Unable to write file");
+    let metadata_path = dir_path.join(METADATA_DIR);
+    fs::create_dir_all(&metadata_path).unwrap();
+
+    for entry in texpack.entries() {
+        let file = texpack.get_file(&entry.filename).unwrap();
+
+        // Only the DDS textures of the PC and Xbox versions can currently be
+        // described any further
+        let info = file.dds_info();
+        let metadata = TextureMetadata {
+            filename: entry.filename.clone(),
+            hash: entry.hash,
+            filetype: format!("{:?}", entry.filetype),
+            size: entry.size,
+            width: info.map(|i| i.width),
+            height: info.map(|i| i.height),
+            mip_count: info.map(|i| i.mip_count),
+            format: info
+                .and_then(|i| i.four_cc)
+                .map(|f| String::from_utf8_lossy(&f).into_owned()),
+        };
+        fs::write(
+            metadata_path.join(format!("{}.json", entry.filename)),
+            serde_json::to_string_pretty(&metadata).unwrap(),
+        )
+        .expect("Unable to write file");
+    }
+}
+
+/// Pack each file within the given directory into a new texpack
+///
+/// # Parameters
+///
+/// - `texpack_path`: The path to write the texpack to
+/// - `dir_path`: The directory containing the files to pack
+/// - `console`: The console to create the texpack for
+fn pack(texpack_path: &Path, dir_path: &Path, console: Console) {
+    let texpack = Texpack::from_directory(dir_path, console)
+        .unwrap_or_else(|e| panic!("failed to read {:?}: {}", dir_path, e));
+    fs::write(texpack_path, texpack.to_bytes().unwrap()).expect("Unable to write file");
+}
+
+fn main() {
+    let config = Config::new(env::args()).unwrap_or_else(|err| {
+        println!("Unable to parse args: {}", err);
+        process::exit(1);
+    });
+
+    match config.mode {
+        Mode::Extract => extract(&config.texpack_path, &config.dir_path, config.console),
+        Mode::Pack => pack(&config.texpack_path, &config.dir_path, config.console),
+    }
+}
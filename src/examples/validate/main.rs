@@ -0,0 +1,60 @@
+use std::env;
+use std::process;
+
+extern crate shrek_superslam;
+use shrek_superslam::files::Bin;
+use shrek_superslam::{MasterDat, MasterDir};
+
+mod args;
+use args::Config;
+
+fn main() {
+    let config = Config::new(env::args()).unwrap_or_else(|err| {
+        println!("Unable to parse args: {}", err);
+        process::exit(1);
+    });
+
+    // Read the MASTER.DIR and MASTER.DAT files, which fails if the MASTER.DAT
+    // does not contain every file the MASTER.DIR describes
+    let master_dir =
+        MasterDir::from_file(&config.master_dir_path, config.console).unwrap_or_else(|e| {
+            println!("failed to read {:?}: {}", &config.master_dir_path, e);
+            process::exit(1);
+        });
+    let master_dat =
+        MasterDat::from_file(&config.master_dat_path, master_dir).unwrap_or_else(|e| {
+            println!("failed to read {:?}: {}", &config.master_dat_path, e);
+            process::exit(1);
+        });
+
+    // Decompress every file, and check the structure of every .db.bin file,
+    // printing the result for each file
+    let mut files = 0;
+    let mut failures = 0;
+    for (path, data) in master_dat.iter() {
+        files += 1;
+        let problems: Vec<String> = match data {
+            Err(e) => vec![format!("failed to decompress: {}", e)],
+            Ok(data) if path.ends_with(".db.bin") => match Bin::new(data, config.console) {
+                Ok(bin) => bin.validate().iter().map(|i| format!("{:?}", i)).collect(),
+                Err(e) => vec![format!("failed to read: {}", e)],
+            },
+            Ok(_) => vec![],
+        };
+
+        if problems.is_empty() {
+            println!("ok: {}", path);
+        } else {
+            failures += 1;
+            println!("FAILED: {}", path);
+            for problem in &problems {
+                println!("    {}", problem);
+            }
+        }
+    }
+
+    println!("{} of {} files failed", failures, files);
+    if failures > 0 {
+        process::exit(1);
+    }
+}
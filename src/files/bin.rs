@@ -1,17 +1,24 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+
 use encoding::all::ISO_8859_1;
-use encoding::{DecoderTrap, Encoding};
+use encoding::{EncoderTrap, Encoding};
+use serde::{Deserialize, Serialize};
 
 use crate::classes;
 use crate::classes::{
-    hash_lookup, SerialisedShrekSuperSlamGameObject, WriteableShrekSuperSlamGameObject,
+    resolve_object, size_lookup, AttackMoveType, ClassRegistry, EffectStringReference, EventSequence, GfDb,
+    LocalizedString, SerialisedShrekSuperSlamGameObject, ShrekSuperSlamObject, Spitter,
+    SpitterKeyframe, WriteableShrekSuperSlamGameObject,
 };
 use crate::console::Console;
 use crate::errors::Error;
 
 /// Structure representing the header (the first 40 bytes) of a .bin file
+#[derive(Clone, Copy)]
 struct BinHeader {
     pub offset1: u32,
     pub sections: u32,
+    #[allow(dead_code)]
     pub offset2: u32,
     pub dependencies: u32,
     pub offset4: u32,
@@ -31,6 +38,55 @@ impl BinHeader {
     }
 }
 
+/// Structure describing the header of a .bin file, as returned by
+/// [`Bin::header`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BinHeaderInfo {
+    /// The size of the region of the file holding the objects, which begins
+    /// with the `gf::DB`
+    pub gf_db_size: u32,
+
+    /// The number of 'sections', each describing a list of offsets
+    pub sections: u32,
+
+    /// The number of dependencies on other files
+    pub dependencies: u32,
+
+    /// The number of 64-byte entries following the dependencies, whose
+    /// purpose is unknown
+    pub offset4: u32,
+}
+
+/// A structural problem found within a .bin file by [`Bin::validate`].
+///
+/// All offsets exclude the header, in the same way as the offsets stored
+/// within the objects themselves.
+#[derive(Clone, Debug, PartialEq)]
+pub enum BinValidationIssue {
+    /// None of the file's sections list the objects within the file
+    MissingObjectSection,
+
+    /// The lists pointed to by the file's sections run past the end of the
+    /// file
+    SectionsOutOfBounds { end: usize, file_size: usize },
+
+    /// A value at `referenced_from` points to an `offset` that does not begin
+    /// with the hash of a known class
+    UnknownObject { referenced_from: u32, offset: u32 },
+
+    /// A value at `referenced_from` points to an `offset` that does not
+    /// contain a complete string within the file
+    StringOutOfBounds { referenced_from: u32, offset: u32 },
+
+    /// A value at `referenced_from` points to a list of `count` offsets at
+    /// `offset` that runs past the end of the file's objects
+    ListOutOfBounds {
+        referenced_from: u32,
+        offset: u32,
+        count: u32,
+    },
+}
+
 /// Poorly-named struct that represents the description a 'section' within a
 /// .bin - a small 16-byte area that describes and points to a big list of
 /// offsets to entries of a certain type within the file
@@ -64,14 +120,20 @@ impl BinSection {
 ///
 /// Use `offset` field as a parameter to the [`Bin::get_object_from_offset`]
 /// function to get the full deserialised object from the .bin file.
+///
+/// The `offset` is stored as it is within the file, excluding the 0x40 byte
+/// header. The tools in this repository print [`Bin::file_offset`] of it
+/// instead, which gives the location of the object within the file on disk.
+/// Serialising a `BinObject` writes the offset unchanged.
+#[derive(Clone, Debug, PartialEq, Serialize)]
 pub struct BinObject {
     /// The hash of the object
     pub hash: u32,
 
     /// The name of the object
-    pub name: &'static str,
+    pub name: String,
 
-    /// The offset of the object within the file
+    /// The offset of the object within the file, excluding the header
     pub offset: u32,
 }
 
@@ -79,10 +141,37 @@ impl BinObject {
     /// Create a new BinObject structure from the given `offset` in the `raw`
     /// bytes of the entire .bin file from the given `console` version.
     pub fn new(raw: &[u8], offset: u32, console: Console) -> Result<BinObject, Error> {
-        let hash = console.read_u32(&raw[Bin::header_length() + offset as usize..Bin::header_length() + offset as usize + 0x04])?;
+        BinObject::with_registry(raw, offset, console, &ClassRegistry::new())
+    }
+
+    /// Create a new BinObject structure from the given `offset` in the `raw`
+    /// bytes of the entire .bin file from the given `console` version, naming
+    /// it using the given `registry`.
+    pub fn with_registry(
+        raw: &[u8],
+        offset: u32,
+        console: Console,
+        registry: &ClassRegistry,
+    ) -> Result<BinObject, Error> {
+        let object_begin = Bin::header_length() + offset as usize;
+        let hash = match raw.get(object_begin..object_begin + 0x04) {
+            Some(bytes) => console.read_u32(bytes)?,
+            None => {
+                return Err(classes::Error::NotEnoughBytes {
+                    requested: 0x04,
+                    file_size: raw.len(),
+                    offset: object_begin,
+                }
+                .into())
+            }
+        };
 
-        if let Some(name) = hash_lookup(hash) {
-            Ok(BinObject { hash, name, offset })
+        if let Some(name) = registry.lookup(hash) {
+            Ok(BinObject {
+                hash,
+                name: name.to_owned(),
+                offset,
+            })
         } else {
             Err(Error::ClassDeserialiseError(
                 classes::Error::IncorrectType { hash },
@@ -91,6 +180,32 @@ impl BinObject {
     }
 }
 
+/// A single object within the JSON form of a .bin file, as produced by
+/// [`Bin::to_json`].
+#[derive(Deserialize, Serialize)]
+struct JsonEntry {
+    /// The name of the object in the file's `gf::DB`
+    name: String,
+
+    /// The offset of the object within the file
+    offset: u32,
+
+    /// The contents of the object
+    object: JsonObject,
+}
+
+/// The contents of an object within the JSON form of a .bin file.
+#[derive(Deserialize, Serialize)]
+#[serde(untagged)]
+enum JsonObject {
+    /// An object of a class supported by this library, with its fields
+    Supported(Box<ShrekSuperSlamObject>),
+
+    /// An object of an unsupported class, kept as its class hash and the raw
+    /// bytes up to the start of the next object
+    Unsupported { hash: u32, bytes: Vec<u8> },
+}
+
 /// Structure for reading and modifying a .bin file from the extracted Shrek
 /// SuperSlam game files.
 ///
@@ -104,8 +219,20 @@ impl BinObject {
 /// For more information about the available classes, see the [classes](../classes/index.html)
 /// module, which contains structures representing the classes found within these
 /// .bin files.
+///
+/// # Offsets
+///
+/// The offsets stored within the objects of a .bin file exclude the 0x40 byte
+/// header, so that an offset of 0x00 is the `gf::DB` at 0x40 in the file.
+/// Every method of this structure that takes or returns a `u32` offset, such
+/// as [`Bin::get_object_from_offset`] and [`BinObject::offset`], uses these
+/// stored offsets. Only [`Bin::raw`] is indexed by the offset within the file
+/// itself. Use [`Bin::file_offset`] and [`Bin::stored_offset`] to convert
+/// between the two.
 pub struct Bin {
+    header: BinHeader,
     objects: Vec<BinObject>,
+    pub(crate) registry: ClassRegistry,
     pub(crate) console: Console,
     pub(crate) raw: Vec<u8>,
 }
@@ -116,9 +243,55 @@ impl Bin {
         0x40
     }
 
+    /// Returns the offset within the file of the given `stored` offset, which
+    /// excludes the header.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use shrek_superslam::files::Bin;
+    ///
+    /// // The gf::DB is stored at 0x00, after the header
+    /// assert_eq!(Bin::file_offset(0x00), 0x40);
+    /// ```
+    pub fn file_offset(stored: u32) -> usize {
+        stored as usize + Bin::header_length()
+    }
+
+    /// Returns the offset stored within the file's objects for the given
+    /// `file` offset, which includes the header.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `file` offset is within the header, as nothing stored
+    /// within the file can refer to it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use shrek_superslam::files::Bin;
+    ///
+    /// assert_eq!(Bin::stored_offset(0x1040), 0x1000);
+    /// assert_eq!(Bin::stored_offset(Bin::file_offset(0x1000)), 0x1000);
+    /// ```
+    pub fn stored_offset(file: usize) -> u32 {
+        assert!(
+            file >= Bin::header_length(),
+            "offset 0x{:X} is within the .bin header",
+            file
+        );
+        (file - Bin::header_length()) as u32
+    }
+
     /// Construct a new `Bin` object from the given `raw` bytes of a
     /// decompressed .bin file, from the given `console` version.
     ///
+    /// # Errors
+    ///
+    /// Returns an `Err(Error::InvalidBinHeaderError)` if the header describes
+    /// regions that do not fit within the file, which is usually because
+    /// `raw` is not a .bin file.
+    ///
     /// # Example
     ///
     /// ```no_run
@@ -132,13 +305,131 @@ impl Bin {
     /// let bin = Bin::new(my_file_bytes, Console::PC).unwrap();
     /// ```
     pub fn new(raw: Vec<u8>, console: Console) -> Result<Bin, Error> {
-        // Read the header
-        let header = BinHeader::new(&raw[0x00..Bin::header_length()], console)?;
+        Bin::with_registry(raw, console, ClassRegistry::new())
+    }
+
+    /// Construct a new `Bin` object from the given `raw` bytes of a
+    /// decompressed .bin file, from the given `console` version, naming its
+    /// objects using the given `registry`.
+    ///
+    /// # Remarks
+    ///
+    /// Use this rather than [`Bin::new`] for files containing classes that
+    /// are not yet known to the library, after registering their names.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use shrek_superslam::Console;
+    /// use shrek_superslam::classes::ClassRegistry;
+    /// use shrek_superslam::files::Bin;
+    ///
+    /// let mut registry = ClassRegistry::new();
+    /// registry.register(0x1F2E3D4C, "Game::MyNewClass");
+    ///
+    /// # let my_file_bytes: Vec<u8> = vec![];
+    /// let bin = Bin::with_registry(my_file_bytes, Console::PC, registry).unwrap();
+    /// ```
+    pub fn with_registry(
+        raw: Vec<u8>,
+        console: Console,
+        registry: ClassRegistry,
+    ) -> Result<Bin, Error> {
+        // Read the header, and use it to find each of the 'sections'
+        let header_raw = match raw.get(0x00..Bin::header_length()) {
+            Some(bytes) => bytes,
+            None => {
+                return Err(classes::Error::NotEnoughBytes {
+                    requested: Bin::header_length(),
+                    file_size: raw.len(),
+                    offset: 0x00,
+                }
+                .into())
+            }
+        };
+        let header = BinHeader::new(header_raw, console)?;
+        Bin::check_header(header, raw.len())?;
+        let sections = Bin::sections(&raw, header, console)?;
+
+        // Create an object for each serialised game object in the .bin
+        let mut objects: Vec<BinObject> = vec![];
+        for section in sections {
+            // The 'section' with a value of 1 in its first field details the
+            // number of objects within the .bin file
+            if section.number == 1 {
+                // This region contains a list of offsets within the file to
+                // each object contained within it
+                for j in 0..section.size {
+                    let object_ptr_offset = section.offset as usize + (j as usize * 0x04);
+                    let object_ptr = match raw.get(object_ptr_offset..(object_ptr_offset + 0x04)) {
+                        Some(bytes) => bytes,
+                        None => {
+                            return Err(classes::Error::NotEnoughBytes {
+                                requested: 0x04,
+                                file_size: raw.len(),
+                                offset: object_ptr_offset,
+                            }
+                            .into())
+                        }
+                    };
+                    let object_offset = console.read_u32(object_ptr)?;
+                    let obj = BinObject::with_registry(&raw, object_offset, console, &registry)?;
+                    objects.push(obj);
+                }
+            }
+        }
+
+        Ok(Bin {
+            header,
+            objects,
+            registry,
+            console,
+            raw,
+        })
+    }
+
+    /// Checks that the regions described by the given `header` fit within a
+    /// file of `file_size` bytes.
+    ///
+    /// # Parameters
+    ///
+    /// - `header`: The already-parsed header of the file
+    /// - `file_size`: The length of the entire file, including the header
+    ///
+    /// # Returns
+    ///
+    /// An `Err(Error::InvalidBinHeaderError)` containing the offset of the first
+    /// header value that cannot be correct.
+    fn check_header(header: BinHeader, file_size: usize) -> Result<(), Error> {
+        // The objects region must at least hold the gf::DB, and each region
+        // that follows it must fit within the file. The sums are done in 64
+        // bits so that nonsense values cannot overflow.
+        let file_size = file_size as u64;
+        let mut end = Bin::header_length() as u64 + header.offset1 as u64;
+        if (header.offset1 as usize) < GfDb::size() || end > file_size {
+            return Err(Error::InvalidBinHeaderError { offset: 0x10 });
+        }
+        let regions = [
+            (0x18, header.sections, 0x10),
+            (0x24, header.dependencies, 0x80),
+            (0x2C, header.offset4, Bin::header_length() as u64),
+        ];
+        for (offset, count, entry_size) in regions.iter() {
+            end += *count as u64 * entry_size;
+            if end > file_size {
+                return Err(Error::InvalidBinHeaderError { offset: *offset });
+            }
+        }
+
+        Ok(())
+    }
 
+    /// Returns the list of 'sections' described within the given `raw` bytes
+    /// of a .bin file, using the counts within its already-parsed `header`.
+    fn sections(raw: &[u8], header: BinHeader, console: Console) -> Result<Vec<BinSection>, Error> {
         // The offsets and counts within the header are used to calculate
         // various offsets to the different sections within the .bin file
-        let file_begin_offset = Bin::header_length() as u32;
-        let section_begin_offset = file_begin_offset + header.offset1;
+        let section_begin_offset = Bin::section_descriptions_offset(header);
         let dependencies_begin_offset = section_begin_offset + (header.sections * 0x10);
         let ptr4_begin_offset = dependencies_begin_offset + (header.dependencies * 0x80);
 
@@ -157,32 +448,84 @@ impl Bin {
             let section_size = section.size;
             sections.push(section);
 
-            section_dst_offset += section_size * 4;
+            section_dst_offset = section_dst_offset.saturating_add(section_size.saturating_mul(4));
         }
 
-        // Create an object for each serialised game object in the .bin
-        let mut objects: Vec<BinObject> = vec![];
-        for section in sections {
-            // The 'section' with a value of 1 in its first field details the
-            // number of objects within the .bin file
-            if section.number == 1 {
-                // This region contains a list of offsets within the file to
-                // each object contained within it
-                for j in 0..section.size {
-                    let object_ptr_offset = (section.offset + (j * 0x04)) as usize;
-                    let object_offset =
-                        console.read_u32(&raw[object_ptr_offset..(object_ptr_offset + 0x04)])?;
-                    let obj = BinObject::new(&raw, object_offset, console)?;
-                    objects.push(obj);
-                }
-            }
-        }
+        Ok(sections)
+    }
 
-        Ok(Bin {
-            objects,
-            console,
-            raw,
-        })
+    /// Returns the offset within the file of the first section description,
+    /// which immediately follows the region of the file holding the objects.
+    fn section_descriptions_offset(header: BinHeader) -> u32 {
+        Bin::header_length() as u32 + header.offset1
+    }
+
+    /// Inserts the given `bytes` at the end of the region of the file holding
+    /// the objects, moving the sections and the lists they point to further
+    /// into the file to make room.
+    ///
+    /// The inserted bytes are padded to a multiple of 16 bytes so that the
+    /// alignment of everything after them is preserved.
+    ///
+    /// Returns the offset of the inserted bytes, excluding the header.
+    fn append_data(&mut self, bytes: &[u8]) -> Result<u32, Error> {
+        let header = self.header;
+        let mut padded = bytes.to_vec();
+        padded.resize((bytes.len() + 0x0F) & !0x0F, 0x00);
+
+        let insert_offset = Bin::section_descriptions_offset(header) as usize;
+        self.raw
+            .splice(insert_offset..insert_offset, padded.iter().cloned());
+        self.header.offset1 += padded.len() as u32;
+        self.raw
+            .splice(0x10..0x14, self.console.write_u32(self.header.offset1)?);
+
+        Ok(header.offset1)
+    }
+
+    /// Appends the given object `offset` to the end of the list of objects
+    /// pointed to by the section with a number of 1.
+    fn append_object_offset(&mut self, offset: u32) -> Result<(), Error> {
+        let header = self.header;
+        let sections = Bin::sections(&self.raw, header, self.console)?;
+        let (index, section) = sections
+            .iter()
+            .enumerate()
+            .find(|(_, s)| s.number == 1)
+            .ok_or(Error::MissingSectionError { number: 1 })?;
+
+        // Insert the new offset at the end of the list, then increment the
+        // count within the section description to match
+        let list_end = (section.offset + section.size * 4) as usize;
+        self.raw
+            .splice(list_end..list_end, self.console.write_u32(offset)?);
+        let size_offset = Bin::section_descriptions_offset(header) as usize + (index * 0x10) + 0x04;
+        self.raw.splice(
+            size_offset..size_offset + 0x04,
+            self.console.write_u32(section.size + 1)?,
+        );
+
+        Ok(())
+    }
+
+    /// Returns the offset of every 4-byte word within the given `data` region
+    /// of a .bin file whose value satisfies the given `predicate`, reading
+    /// values for the given `console`.
+    ///
+    /// The objects within a .bin file do not describe which of their fields
+    /// are offsets, so this treats every aligned word as a potential offset.
+    fn find_words<F>(data: &[u8], console: Console, predicate: F) -> Vec<u32>
+    where
+        F: Fn(u32, u32) -> bool,
+    {
+        (0..data.len().saturating_sub(3))
+            .step_by(4)
+            .filter(|p| match console.read_u32(&data[*p..*p + 4]) {
+                Ok(value) => predicate(*p as u32, value),
+                Err(_) => false,
+            })
+            .map(|p| p as u32)
+            .collect()
     }
 
     /// Get all objects of a requested type `T` contained within the .bin file.
@@ -228,6 +571,11 @@ impl Bin {
     /// Returns a deserialised object of type `T` contained at given `offset`
     /// within the .bin file.
     ///
+    /// The `offset` excludes the header, as in [`BinObject::offset`]. Passing
+    /// an offset within the file instead usually gives an
+    /// [`IncorrectType`](crate::classes::Error::IncorrectType) error; convert
+    /// it with [`Bin::stored_offset`] first.
+    ///
     /// # Errors
     ///
     /// If the given `offset` does not contain the start of the requested type,
@@ -257,110 +605,2119 @@ impl Bin {
     {
         // Ensure there are enough bytes for the requested type to fit before
         // we try and make a slice for it
-        if offset as usize + T::size() > self.raw.len() {
-            return Err(classes::Error::NotEnoughBytes {
-                requested: T::size(),
-                file_size: self.raw.len(),
-                offset: offset as usize,
-            }
-            .into());
-        }
+        let object_begin = offset as usize + Bin::header_length();
+        self.read_at(object_begin, T::size())?;
 
         // Ensure the requested type exists at the given offset by checking the
         // hash at the offset matches the expected hash of the type
-        let object_begin = offset as usize + Bin::header_length();
-        let hash = self
-            .console
-            .read_u32(&self.raw[object_begin..object_begin + 4])?;
+        let hash = self.console.read_u32(self.read_at(object_begin, 4)?)?;
         if hash != T::hash() {
             return Err(classes::Error::IncorrectType { hash }.into());
         }
 
         // Pass the offset to the game object's own constructor
-        T::new(&self, object_begin)
+        T::new(self, object_begin)
     }
 
-    /// Returns a string from the given `offset` within the .bin file.
+    /// Deserialise every object listed in the file's `gf::DB` whose class is
+    /// supported by this library.
+    ///
+    /// Returns a map from the name of each object in the `gf::DB` to the
+    /// deserialised object. Objects of unsupported classes are skipped. Use
+    /// [`Bin::parse_ordered`] if the order of the objects is important.
     ///
     /// # Errors
     ///
-    /// If the bytes at the given `offset` fail to decode as an ISO 8859-1
-    /// string, then an error is returned.
+    /// If the `gf::DB` or any supported object within it fails to deserialise,
+    /// then an error is returned.
     ///
     /// # Example
     ///
     /// ```no_run
     /// use shrek_superslam::Console;
+    /// use shrek_superslam::classes::ShrekSuperSlamObject;
     /// use shrek_superslam::files::Bin;
     ///
-    /// // Get a specific string located in the .bin file
+    /// // Print the name of every attack in the file
     /// # let my_file_bytes: Vec<u8> = vec![];
     /// let bin = Bin::new(my_file_bytes, Console::PC).unwrap();
-    /// let my_string = bin.get_str_from_offset(0x500).unwrap();
-    /// println!("At offset {}, there is the string '{}'", 0x500, my_string);
+    /// for (name, object) in bin.parse().unwrap() {
+    ///     if let ShrekSuperSlamObject::AttackMoveType(attack) = object {
+    ///         println!("{} is {}", name, attack.name);
+    ///     }
+    /// }
     /// ```
-    pub fn get_str_from_offset(&self, offset: u32) -> Result<String, Error> {
-        let str_begin = offset as usize + Bin::header_length();
+    pub fn parse(&self) -> Result<HashMap<String, ShrekSuperSlamObject>, Error> {
+        Ok(self.parse_ordered()?.into_iter().collect())
+    }
 
-        // Find the first NULL byte, which ends the string. If not found,
-        // default to the end of the slice, which will more than likely give us
-        // an Error later
-        let slice = &self.raw[str_begin..];
-        let size = slice.iter().position(|&b| b == 0x00).unwrap_or(0);
+    /// Deserialise every object listed in the file's `gf::DB` whose class is
+    /// supported by this library, keeping the order of the `gf::DB`.
+    ///
+    /// Returns a list of the name of each object in the `gf::DB` alongside the
+    /// deserialised object. Objects of unsupported classes are skipped.
+    ///
+    /// # Errors
+    ///
+    /// If the `gf::DB` or any supported object within it fails to deserialise,
+    /// then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use shrek_superslam::Console;
+    /// use shrek_superslam::files::Bin;
+    ///
+    /// // Print the name of every supported object, in order
+    /// # let my_file_bytes: Vec<u8> = vec![];
+    /// let bin = Bin::new(my_file_bytes, Console::PC).unwrap();
+    /// for (name, _) in bin.parse_ordered().unwrap() {
+    ///     println!("{}", name);
+    /// }
+    /// ```
+    pub fn parse_ordered(&self) -> Result<Vec<(String, ShrekSuperSlamObject)>, Error> {
+        let db = self.get_object_from_offset::<GfDb>(0x00)?;
+        let mut objects = vec![];
+        for (name, object) in db.entries {
+            if let Some(resolved) = resolve_object(self, &object)? {
+                objects.push((name, resolved));
+            }
+        }
 
-        // Text within the game is stored using the single-byte ISO 8859-1
-        // encoding. Specifically, $AE = ®. We therefore need to decode it
-        Ok(ISO_8859_1.decode(&self.raw[str_begin..str_begin + size], DecoderTrap::Strict)?)
+        Ok(objects)
     }
 
-    /// Overwrite an existing object at the given `offset` with the new object
-    /// given in the `object` parameter.
+    /// Returns the JSON form of every object listed in the file's `gf::DB`,
+    /// keeping the order of the `gf::DB`.
+    ///
+    /// Each object is written with its name and offset. Objects of classes
+    /// supported by this library are written with their fields, as described
+    /// by [`ShrekSuperSlamObject`]. Objects of unsupported classes are written
+    /// as their class hash and raw bytes instead.
+    ///
+    /// The returned JSON can be edited and passed back to [`Bin::apply_json`].
     ///
     /// # Errors
     ///
-    /// If the given `offset` does not contain the beginning of an object of
-    /// the given type, then an error is returned.
+    /// If the `gf::DB` or any supported object within it fails to deserialise,
+    /// or the objects cannot be written as JSON, then an error is returned.
     ///
     /// # Example
     ///
     /// ```no_run
+    /// use std::fs;
     /// use shrek_superslam::Console;
     /// use shrek_superslam::files::Bin;
-    /// use shrek_superslam::classes::AttackMoveType;
     ///
-    /// // Overwrite the damage of a specific Game::AttackMoveType object
+    /// // Dump every object in the file to a JSON file
     /// # let my_file_bytes: Vec<u8> = vec![];
-    /// let mut bin = Bin::new(my_file_bytes, Console::PC).unwrap();
-    /// let mut attack = bin.get_object_from_offset::<AttackMoveType>(0x1000).unwrap();
-    /// attack.damage1 = 100.0;
-    /// bin.overwrite_object(0x1000, &attack);
+    /// let bin = Bin::new(my_file_bytes, Console::PC).unwrap();
+    /// fs::write("objects.json", bin.to_json().unwrap()).unwrap();
     /// ```
-    pub fn overwrite_object<T>(&mut self, offset: u32, object: &T) -> Result<(), Error>
-    where
-        T: SerialisedShrekSuperSlamGameObject + WriteableShrekSuperSlamGameObject,
-    {
-        // Check that the given offset actually contains an object of the type
-        // given as a parameter before we overwrite it
-        let object_begin = offset as usize + Bin::header_length();
-        let hash = self
-            .console
-            .read_u32(&self.raw[object_begin..object_begin + 4])?;
-        if hash != T::hash() {
-            return Err(classes::Error::IncorrectType { hash }.into());
+    pub fn to_json(&self) -> Result<String, Error> {
+        let db = self.get_object_from_offset::<GfDb>(0x00)?;
+        let mut entries = vec![];
+        for (name, object) in db.entries {
+            let contents = match resolve_object(self, &object)? {
+                Some(resolved) => JsonObject::Supported(Box::new(resolved)),
+                None => JsonObject::Unsupported {
+                    hash: object.hash,
+                    bytes: self.object_region(object.offset).to_vec(),
+                },
+            };
+            entries.push(JsonEntry {
+                name,
+                offset: object.offset,
+                object: contents,
+            });
         }
 
-        object.write(self, object_begin)?;
+        Ok(serde_json::to_string_pretty(&entries)?)
+    }
+
+    /// Overwrite the objects within the file with the objects in the given
+    /// `json`, in the form produced by [`Bin::to_json`].
+    ///
+    /// Each object is written over the object at its offset, in the same way
+    /// as [`Bin::overwrite_object`].
+    ///
+    /// # Remarks
+    ///
+    /// Only the fields written by each object's `write()` method are changed,
+    /// so edits to other fields are ignored. Objects of classes that cannot be
+    /// written by this library, including every object given as raw bytes,
+    /// are skipped.
+    ///
+    /// # Errors
+    ///
+    /// If the `json` is not in the form produced by [`Bin::to_json`], or any
+    /// object cannot be written over the object at its offset, then an error
+    /// is returned and the file is left unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::fs;
+    /// use shrek_superslam::Console;
+    /// use shrek_superslam::files::Bin;
+    ///
+    /// // Apply the changes made to a JSON file created by Bin::to_json()
+    /// # let my_file_bytes: Vec<u8> = vec![];
+    /// let mut bin = Bin::new(my_file_bytes, Console::PC).unwrap();
+    /// bin.apply_json(&fs::read_to_string("objects.json").unwrap()).unwrap();
+    /// ```
+    pub fn apply_json(&mut self, json: &str) -> Result<(), Error> {
+        let entries: Vec<JsonEntry> = serde_json::from_str(json)?;
+
+        // Write every object to a copy of the file first, so that the file is
+        // left unchanged if any of them cannot be written
+        let mut scratch = Bin {
+            header: self.header,
+            objects: self.objects.clone(),
+            registry: self.registry.clone(),
+            console: self.console,
+            raw: self.raw.clone(),
+        };
+        for entry in entries {
+            let offset = entry.offset;
+            let object = match entry.object {
+                JsonObject::Supported(object) => *object,
+                JsonObject::Unsupported { .. } => continue,
+            };
+            match object {
+                ShrekSuperSlamObject::AttackMoveRegion(o) => scratch.overwrite_object(offset, &o)?,
+                ShrekSuperSlamObject::AttackMoveType(o) => scratch.overwrite_object(offset, &o)?,
+                ShrekSuperSlamObject::GameWorld(o) => scratch.overwrite_object(offset, &o)?,
+                ShrekSuperSlamObject::PhysicsFighting(o) => scratch.overwrite_object(offset, &o)?,
+                ShrekSuperSlamObject::ProjectileType(o) => scratch.overwrite_object(offset, &o)?,
+                ShrekSuperSlamObject::Spitter(o) => scratch.overwrite_object(offset, &o)?,
+                ShrekSuperSlamObject::SpitterKeyframe(o) => scratch.overwrite_object(offset, &o)?,
+                _ => {}
+            }
+        }
 
+        *self = scratch;
         Ok(())
     }
 
-    /// Returns the raw bytes of the .bin file.
-    pub fn raw(&self) -> &[u8] {
-        &self.raw
+    /// Returns the raw bytes of the object at the given `offset`.
+    ///
+    /// The `offset` excludes the header, in the same way as the offsets in
+    /// [`Bin::objects`](#method.objects).
+    ///
+    /// # Remarks
+    ///
+    /// If the object's class is supported by this library, the returned slice
+    /// is exactly as long as the class's serialised size. Otherwise the size
+    /// of the object is not known, and the slice instead runs up to the start
+    /// of the next object in the file, or the end of the region of the file
+    /// holding the objects for the last object. As objects are followed by the
+    /// data they own, such as strings and lists, this slice will usually also
+    /// include that data.
+    ///
+    /// # Errors
+    ///
+    /// Returns an Err if no object starts at the given `offset`, or if the
+    /// file is too short to hold an object of the class's size.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use shrek_superslam::Console;
+    /// use shrek_superslam::files::Bin;
+    ///
+    /// // Dump the bytes of the first object in the .bin file
+    /// # let my_file_bytes: Vec<u8> = vec![];
+    /// let bin = Bin::new(my_file_bytes, Console::PC).unwrap();
+    /// let object = &bin.objects()[0];
+    /// println!("{:02X?}", bin.object_bytes(object.offset).unwrap());
+    /// ```
+    pub fn object_bytes(&self, offset: u32) -> Result<&[u8], Error> {
+        let object = self
+            .objects
+            .iter()
+            .find(|o| o.offset == offset)
+            .ok_or(classes::Error::ObjectNotFound { offset })?;
+
+        match classes::size_lookup(object.hash) {
+            Some(size) => {
+                let start = offset as usize + Bin::header_length();
+                if start + size > self.raw.len() {
+                    return Err(classes::Error::NotEnoughBytes {
+                        requested: size,
+                        file_size: self.raw.len(),
+                        offset: offset as usize,
+                    }
+                    .into());
+                }
+                Ok(&self.raw[start..start + size])
+            }
+            None => Ok(self.object_region(offset)),
+        }
     }
 
-    /// Returns a list of objects within the .bin file.
-    pub fn objects(&self) -> &Vec<BinObject> {
-        &self.objects
+    /// Get the raw bytes of the object at the given `offset`, along with any
+    /// data following it that belongs to no other object.
+    ///
+    /// # Parameters
+    ///
+    /// - `offset`: The offset of the object, excluding the header
+    ///
+    /// # Returns
+    ///
+    /// The bytes from the start of the object up to the start of the next
+    /// object, or the end of the region of the file holding the objects.
+    fn object_region(&self, offset: u32) -> &[u8] {
+        let end = self
+            .objects
+            .iter()
+            .map(|o| o.offset)
+            .filter(|&o| o > offset)
+            .min()
+            .map(|o| o as usize + Bin::header_length())
+            .unwrap_or_else(|| Bin::section_descriptions_offset(self.header) as usize);
+        &self.raw[offset as usize + Bin::header_length()..end]
+    }
+
+    /// Returns the offset of every value within the file's objects that
+    /// refers to the given `offset`.
+    ///
+    /// Both the given `offset` and the returned offsets exclude the header, in
+    /// the same way as the offsets stored within the objects themselves.
+    ///
+    /// # Remarks
+    ///
+    /// The .bin format does not describe which fields of an object are
+    /// offsets, so every aligned 4-byte value within the objects is compared
+    /// against the given `offset`. A number that happens to be equal to the
+    /// offset will therefore also be returned.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use shrek_superslam::Console;
+    /// use shrek_superslam::files::Bin;
+    ///
+    /// // Find everything pointing to a specific object
+    /// # let my_file_bytes: Vec<u8> = vec![];
+    /// let bin = Bin::new(my_file_bytes, Console::PC).unwrap();
+    /// for reference in bin.references_to(0x1000) {
+    ///     println!("Offset {} refers to offset {}", reference, 0x1000);
+    /// }
+    /// ```
+    pub fn references_to(&self, offset: u32) -> Vec<u32> {
+        let data_end = Bin::section_descriptions_offset(self.header) as usize;
+        let data = &self.raw[Bin::header_length()..data_end];
+        Bin::find_words(data, self.console, |_, v| v == offset)
+    }
+
+    /// Returns a [Graphviz](https://graphviz.org/) graph of the objects
+    /// within the file and the references between them, in the DOT language.
+    ///
+    /// Each object is a node, labelled with its name in the file's `gf::DB`
+    /// and its class. Each edge is a reference from within one object to the
+    /// offset of another, such as from a `Game::AttackMoveType` to its
+    /// hitboxes.
+    ///
+    /// # Remarks
+    ///
+    /// The references are found in the same way as [`Bin::references_to`],
+    /// so a number within an object that happens to be equal to the offset of
+    /// another object also gives an edge. As zero is far more often a number
+    /// or an empty value than a reference, nothing is given an edge to the
+    /// `gf::DB` at offset zero. Each pair of objects has at most one edge
+    /// between them.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::fs;
+    /// use shrek_superslam::Console;
+    /// use shrek_superslam::files::Bin;
+    ///
+    /// // Write the graph to a file, to be rendered by Graphviz
+    /// # let my_file_bytes: Vec<u8> = vec![];
+    /// let bin = Bin::new(my_file_bytes, Console::PC).unwrap();
+    /// fs::write("player.dot", bin.to_dot()).unwrap();
+    /// ```
+    pub fn to_dot(&self) -> String {
+        let objects: Vec<(String, &BinObject)> = self.named_objects().collect();
+        let offsets: HashSet<u32> = objects.iter().map(|(_, o)| o.offset).collect();
+
+        let mut dot = String::from("digraph {\n");
+        for (name, object) in &objects {
+            let label = if name.is_empty() {
+                dot_escape(&object.name)
+            } else {
+                format!("{}\\n{}", dot_escape(name), dot_escape(&object.name))
+            };
+            dot.push_str(&format!("    \"{:08X}\" [label=\"{}\"];\n", object.offset, label));
+        }
+
+        // Each object is only scanned once, so references that form a cycle
+        // are simply written as edges in both directions
+        for (_, object) in &objects {
+            let bytes = self.object_region(object.offset);
+            let targets: BTreeSet<u32> = Bin::find_words(bytes, self.console, |_, v| {
+                v != 0x00 && v != object.offset && offsets.contains(&v)
+            })
+            .into_iter()
+            .filter_map(|p| self.console.read_u32(&bytes[p as usize..p as usize + 4]).ok())
+            .collect();
+            for target in targets {
+                dot.push_str(&format!("    \"{:08X}\" -> \"{:08X}\";\n", object.offset, target));
+            }
+        }
+        dot.push_str("}\n");
+
+        dot
+    }
+
+    /// Returns every object referred to from within the object at the given
+    /// `offset`, in the order they are first referred to.
+    ///
+    /// Only the object's own bytes are searched if its class is supported.
+    /// The size of any other class is not known, so the bytes searched run
+    /// up to the start of the next object. Each returned object can be
+    /// deserialised with [`Bin::get_object_from_offset`] if its class is
+    /// supported.
+    ///
+    /// # Remarks
+    ///
+    /// The references are found in the same way as [`Bin::to_dot`], so a
+    /// number within the object that happens to be equal to the offset of
+    /// another object is also returned. Zero is never treated as a reference,
+    /// and the object is not counted as referring to itself.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no object begins at the given `offset`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use shrek_superslam::Console;
+    /// use shrek_superslam::classes::GameWorld;
+    /// use shrek_superslam::files::Bin;
+    ///
+    /// // Print the class of every object the level appears to refer to
+    /// # let my_file_bytes: Vec<u8> = vec![];
+    /// let bin = Bin::new(my_file_bytes, Console::PC).unwrap();
+    /// for (offset, _) in bin.get_all_objects_of_type::<GameWorld>() {
+    ///     for object in bin.objects_referenced_from(offset).unwrap() {
+    ///         println!("{} at offset {}", object.name, object.offset);
+    ///     }
+    /// }
+    /// ```
+    pub fn objects_referenced_from(&self, offset: u32) -> Result<Vec<&BinObject>, Error> {
+        let object = self
+            .objects
+            .iter()
+            .find(|o| o.offset == offset)
+            .ok_or(classes::Error::ObjectNotFound { offset })?;
+
+        let data = self.object_region(offset);
+        let data = match size_lookup(object.hash) {
+            Some(size) => &data[..size.min(data.len())],
+            None => data,
+        };
+        let mut seen = HashSet::new();
+        Ok(
+            Bin::find_words(data, self.console, |_, v| v != 0x00 && v != offset)
+                .into_iter()
+                .filter_map(|p| self.console.read_u32(&data[p as usize..p as usize + 4]).ok())
+                .filter_map(|v| self.objects.iter().find(|o| o.offset == v))
+                .filter(|o| seen.insert(o.offset))
+                .collect(),
+        )
+    }
+
+    /// Returns an iterator over the objects within the .bin file, alongside
+    /// the name each object has in the file's `gf::DB`.
+    ///
+    /// Objects that are not listed in the `gf::DB`, such as objects that only
+    /// exist as part of other objects, are given an empty name.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use shrek_superslam::Console;
+    /// use shrek_superslam::files::Bin;
+    ///
+    /// // Print the name and class of every object in the file
+    /// # let my_file_bytes: Vec<u8> = vec![];
+    /// let bin = Bin::new(my_file_bytes, Console::PC).unwrap();
+    /// for (name, object) in bin.named_objects() {
+    ///     println!("{} is a {}", name, object.name);
+    /// }
+    /// ```
+    pub fn named_objects(&self) -> impl Iterator<Item = (String, &BinObject)> {
+        let mut names: HashMap<u32, String> = match self.get_object_from_offset::<GfDb>(0x00) {
+            Ok(db) => db
+                .entries
+                .into_iter()
+                .map(|(name, object)| (object.offset, name))
+                .collect(),
+            Err(_) => HashMap::new(),
+        };
+
+        self.objects
+            .iter()
+            .map(move |o| (names.remove(&o.offset).unwrap_or_default(), o))
+    }
+
+    /// Returns a string from the given `offset` within the .bin file, which
+    /// excludes the header.
+    ///
+    /// # Errors
+    ///
+    /// If the bytes at the given `offset` fail to decode as an ISO 8859-1
+    /// string, then an [`Error::StringError`] is returned.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use shrek_superslam::Console;
+    /// use shrek_superslam::files::Bin;
+    ///
+    /// // Get a specific string located in the .bin file
+    /// # let my_file_bytes: Vec<u8> = vec![];
+    /// let bin = Bin::new(my_file_bytes, Console::PC).unwrap();
+    /// let my_string = bin.get_str_from_offset(0x500).unwrap();
+    /// println!("At offset {}, there is the string '{}'", 0x500, my_string);
+    /// ```
+    pub fn get_str_from_offset(&self, offset: u32) -> Result<String, Error> {
+        let str_begin = offset as usize + Bin::header_length();
+        let bytes = self.read_at(str_begin, self.raw.len().saturating_sub(str_begin))?;
+        self.console
+            .read_str(bytes)
+            .map_err(|e| e.at_offset(str_begin))
+    }
+
+    /// Returns the `length` bytes of the file starting at the given `offset`.
+    ///
+    /// Unlike the offsets stored within the objects themselves, the `offset`
+    /// includes the header, in the same way as the offset passed to
+    /// [`SerialisedShrekSuperSlamGameObject::new`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`classes::Error::NotEnoughBytes`] if the file ends before
+    /// all of the requested bytes, rather than panicking.
+    pub(crate) fn read_at(&self, offset: usize, length: usize) -> Result<&[u8], Error> {
+        match offset.checked_add(length) {
+            Some(end) if end <= self.raw.len() => Ok(&self.raw[offset..end]),
+            _ => Err(classes::Error::NotEnoughBytes {
+                requested: length,
+                file_size: self.raw.len(),
+                offset,
+            }
+            .into()),
+        }
+    }
+
+    /// Overwrite an existing object at the given `offset` with the new object
+    /// given in the `object` parameter.
+    ///
+    /// # Errors
+    ///
+    /// If the given `offset` does not contain the beginning of an object of
+    /// the given type, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use shrek_superslam::Console;
+    /// use shrek_superslam::files::Bin;
+    /// use shrek_superslam::classes::AttackMoveType;
+    ///
+    /// // Overwrite the damage of a specific Game::AttackMoveType object
+    /// # let my_file_bytes: Vec<u8> = vec![];
+    /// let mut bin = Bin::new(my_file_bytes, Console::PC).unwrap();
+    /// let mut attack = bin.get_object_from_offset::<AttackMoveType>(0x1000).unwrap();
+    /// attack.damage1 = 100.0;
+    /// bin.overwrite_object(0x1000, &attack);
+    /// ```
+    pub fn overwrite_object<T>(&mut self, offset: u32, object: &T) -> Result<(), Error>
+    where
+        T: SerialisedShrekSuperSlamGameObject + WriteableShrekSuperSlamGameObject,
+    {
+        // Check that the given offset actually contains an object of the type
+        // given as a parameter, and that all of it is within the file, before
+        // we overwrite it
+        let object_begin = offset as usize + Bin::header_length();
+        let hash = self.console.read_u32(self.read_at(object_begin, 0x04)?)?;
+        if hash != T::hash() {
+            return Err(classes::Error::IncorrectType { hash }.into());
+        }
+        self.read_at(object_begin, T::size())?;
+
+        object.write(self, object_begin)?;
+
+        Ok(())
+    }
+
+    /// Change the text of the `gf::LocalizedString` at the given `offset` to
+    /// `new_text`.
+    ///
+    /// # Remarks
+    ///
+    /// If the new text fits within the bytes of the old text, and nothing
+    /// else in the file refers to the old text, it is written in place.
+    /// Otherwise, the new text is appended to the end of the region of the
+    /// file holding the objects and the string is updated to point to it. The
+    /// old text is then blanked, unless something else still refers to it.
+    /// Either way, no other offsets within the file need to change.
+    ///
+    /// The value at +04 of the string, which is zero for strings the game
+    /// treats as empty, is left unchanged. An empty string therefore remains
+    /// flagged as empty whatever text it is given.
+    ///
+    /// # Errors
+    ///
+    /// If the given `offset` does not contain the beginning of a
+    /// `gf::LocalizedString`, or the `new_text` cannot be encoded as an
+    /// ISO 8859-1 string, then an error is returned and the file is left
+    /// unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use shrek_superslam::Console;
+    /// use shrek_superslam::files::Bin;
+    ///
+    /// // Translate a specific string within the .bin file
+    /// # let my_file_bytes: Vec<u8> = vec![];
+    /// let mut bin = Bin::new(my_file_bytes, Console::PC).unwrap();
+    /// bin.set_localized_string(0x1000, "Appuyez sur Start").unwrap();
+    /// ```
+    pub fn set_localized_string(&mut self, offset: u32, new_text: &str) -> Result<(), Error> {
+        let c = self.console;
+        self.get_object_from_offset::<LocalizedString>(offset)?;
+        let pointer = Bin::header_length() + offset as usize + 0x08;
+        let str_offset = c.read_u32(self.read_at(pointer, 0x04)?)?;
+        let str_begin = Bin::header_length() + str_offset as usize;
+
+        // The old text has already been read successfully, so is known to be
+        // null-terminated
+        let old_len = self.raw[str_begin..]
+            .iter()
+            .position(|b| *b == 0x00)
+            .map(|n| n + 1)
+            .unwrap_or(self.raw.len() - str_begin);
+        let mut new_bytes = ISO_8859_1
+            .encode(new_text, EncoderTrap::Strict)
+            .map_err(|e| Error::from(e).at_offset(str_begin))?;
+        new_bytes.push(0x00);
+
+        let shared = self.references_to(str_offset).len() > 1;
+        if !shared && new_bytes.len() <= old_len {
+            new_bytes.resize(old_len, 0x00);
+            self.raw.splice(str_begin..str_begin + old_len, new_bytes);
+            return Ok(());
+        }
+
+        let new_offset = self.append_data(&new_bytes)?;
+        self.raw.splice(pointer..pointer + 0x04, c.write_u32(new_offset)?);
+        if !shared {
+            self.raw
+                .splice(str_begin..str_begin + old_len, vec![0x00; old_len]);
+        }
+
+        Ok(())
+    }
+
+    /// Add a new `object` to the .bin file, with an entry in the file's
+    /// `gf::DB` under the given `name`.
+    ///
+    /// Returns the offset of the new object within the file, which can then
+    /// be passed to methods such as [`Bin::get_object_from_offset`].
+    ///
+    /// # Remarks
+    ///
+    /// The object, its name and a new copy of the `gf::DB` entry list are
+    /// appended to the end of the region of the file holding the objects, and
+    /// the `gf::DB` is updated to point to the new list. The old list is left
+    /// in place as blank bytes, so that no existing offsets within the file
+    /// need to change.
+    ///
+    /// Only the fields written by the object's `write()` method are stored.
+    /// Anything the object points to elsewhere in the file, such as the
+    /// hitboxes and projectile of a `Game::AttackMoveType` or the keyframes
+    /// of a `Game::Spitter`, is not created. The new object points to none of
+    /// these, so an attack with any hitboxes or a spitter with any keyframes
+    /// cannot be added, and an error is returned instead.
+    ///
+    /// # Errors
+    ///
+    /// If the file does not begin with a `gf::DB` object, the `name` cannot be
+    /// encoded as an ISO 8859-1 string, or the object cannot be written, then
+    /// an error is returned and the file is left unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use shrek_superslam::Console;
+    /// use shrek_superslam::files::Bin;
+    /// use shrek_superslam::classes::AttackMoveRegion;
+    ///
+    /// // Copy an existing hitbox into a new, larger hitbox
+    /// # let my_file_bytes: Vec<u8> = vec![];
+    /// let mut bin = Bin::new(my_file_bytes, Console::PC).unwrap();
+    /// let mut hitbox = bin.get_object_from_offset::<AttackMoveRegion>(0x1000).unwrap();
+    /// hitbox.radius = 5.0;
+    /// let offset = bin.add_object("my_new_hitbox", &hitbox).unwrap();
+    /// ```
+    pub fn add_object<T>(&mut self, name: &str, object: &T) -> Result<u32, Error>
+    where
+        T: SerialisedShrekSuperSlamGameObject + WriteableShrekSuperSlamGameObject,
+    {
+        // Ensure the file begins with the gf::DB we are going to add to, grab
+        // the list of entries it currently contains, and encode the name, all
+        // before anything within the file is changed
+        let c = self.console;
+        let db_begin = Bin::header_length();
+        let db_hash = c.read_u32(self.read_at(db_begin, 0x04)?)?;
+        if db_hash != GfDb::hash() {
+            return Err(classes::Error::IncorrectType { hash: db_hash }.into());
+        }
+        let entries_offset = c.read_u32(self.read_at(db_begin + 0x14, 0x04)?)?;
+        let entries_count = c.read_u32(self.read_at(db_begin + 0x18, 0x04)?)?;
+        let entries_begin = Bin::header_length() + entries_offset as usize;
+        let mut entries = self
+            .read_at(entries_begin, entries_count as usize * 0x10)?
+            .to_vec();
+        let name_begin = Bin::section_descriptions_offset(self.header) as usize;
+        let mut name_bytes = ISO_8859_1
+            .encode(name, EncoderTrap::Strict)
+            .map_err(|e| Error::from(e).at_offset(name_begin))?;
+        name_bytes.push(0x00);
+
+        // Build the new file in a scratch copy, so that the file is left
+        // unchanged if the object fails to write itself
+        let mut scratch = Bin {
+            header: self.header,
+            objects: self.objects.clone(),
+            registry: self.registry.clone(),
+            console: c,
+            raw: self.raw.clone(),
+        };
+
+        // Append a blank object with just the hash set, then let the object
+        // fill in the rest of its own fields
+        let mut object_bytes = vec![0x00; T::size()];
+        object_bytes.splice(0x00..0x04, c.write_u32(T::hash())?);
+        let object_offset = scratch.append_data(&object_bytes)?;
+        object.write(&mut scratch, Bin::header_length() + object_offset as usize)?;
+
+        // Append the name, then a new gf::DB list containing the new entry.
+        // The old list is blanked so that it is not mistaken for references to
+        // the objects it contains.
+        scratch.raw.splice(
+            entries_begin..entries_begin + entries.len(),
+            vec![0x00; entries.len()],
+        );
+        let name_offset = scratch.append_data(&name_bytes)?;
+        entries.extend(c.write_u32(name_offset)?);
+        entries.extend(c.write_u32(object_offset)?);
+        entries.extend(&[0x00; 0x08]);
+        let new_entries_offset = scratch.append_data(&entries)?;
+        scratch.raw.splice(
+            db_begin + 0x14..db_begin + 0x18,
+            c.write_u32(new_entries_offset)?,
+        );
+        scratch.raw.splice(
+            db_begin + 0x18..db_begin + 0x1C,
+            c.write_u32(entries_count + 1)?,
+        );
+
+        // Finally, list the new object alongside all the others
+        scratch.append_object_offset(object_offset)?;
+        scratch.objects.push(BinObject::with_registry(
+            &scratch.raw,
+            object_offset,
+            c,
+            &scratch.registry,
+        )?);
+
+        *self = scratch;
+        Ok(object_offset)
+    }
+
+    /// Remove the object at the given `offset` from the .bin file, shrinking
+    /// the file.
+    ///
+    /// The object is removed from the `gf::DB`, its bytes are dropped, and
+    /// every offset within the file that pointed past it is moved back to
+    /// match.
+    ///
+    /// # Remarks
+    ///
+    /// The bytes dropped run from the object to the next location within the
+    /// file that is either another object or pointed to from outside the
+    /// object, so data owned only by the object, such as its name, is removed
+    /// alongside it.
+    ///
+    /// The .bin format does not describe which fields of an object are
+    /// offsets, so only the offsets known to be held by the `gf::DB`, the
+    /// section lists and the supported classes are moved back. Any other
+    /// aligned 4-byte value that looks like an offset past the removed object
+    /// may be an offset in a field of an unsupported class, so the object is
+    /// not removed if one is found.
+    ///
+    /// # Errors
+    ///
+    /// If no object begins at the given `offset`, the object is the `gf::DB`,
+    /// any other part of the file still references the object, or a value
+    /// that may be an offset past the object is not in a field known to hold
+    /// an offset, then an error is returned and the file is left unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use shrek_superslam::Console;
+    /// use shrek_superslam::files::Bin;
+    ///
+    /// // Remove an unused object from the .bin file
+    /// # let my_file_bytes: Vec<u8> = vec![];
+    /// let mut bin = Bin::new(my_file_bytes, Console::PC).unwrap();
+    /// bin.remove_object(0x1000).unwrap();
+    /// ```
+    pub fn remove_object(&mut self, offset: u32) -> Result<(), Error> {
+        let c = self.console;
+        let object = self
+            .objects
+            .iter()
+            .find(|o| o.offset == offset)
+            .ok_or(classes::Error::ObjectNotFound { offset })?;
+        if object.hash == GfDb::hash() {
+            return Err(classes::Error::IncorrectType { hash: object.hash }.into());
+        }
+
+        let header = self.header;
+        let data_end = Bin::section_descriptions_offset(header) as usize;
+        let mut data = self.raw[Bin::header_length()..data_end].to_vec();
+
+        // Remove the object's entry from the gf::DB first, so that it is not
+        // counted as a reference to the object
+        let entries_offset = c.read_u32(&data[0x14..0x18])? as usize;
+        let entries_count = c.read_u32(&data[0x18..0x1C])? as usize;
+        let entries_end = entries_offset + (entries_count * 0x10);
+        let entry = (0..entries_count)
+            .map(|i| entries_offset + (i * 0x10))
+            .find(|e| c.read_u32(&data[e + 0x04..e + 0x08]).ok() == Some(offset));
+        if let Some(entry) = entry {
+            let mut entries = data[entry + 0x10..entries_end].to_vec();
+            entries.resize(entries_end - entry, 0x00);
+            data.splice(entry..entries_end, entries);
+            data.splice(0x18..0x1C, c.write_u32(entries_count as u32 - 1)?);
+        }
+
+        // Work out how far the object runs - up to the next object, or the next
+        // location pointed to by something outside of the object
+        let next_object = self
+            .objects
+            .iter()
+            .map(|o| o.offset)
+            .filter(|o| *o > offset)
+            .min()
+            .unwrap_or(header.offset1);
+        let mut end = next_object;
+        let pointers = Bin::find_words(&data, c, |p, v| {
+            v > offset && v < next_object && (p < offset || p >= v)
+        });
+        for position in pointers {
+            let position = position as usize;
+            end = end.min(c.read_u32(&data[position..position + 4])?);
+        }
+
+        // Refuse to remove the object if anything outside of it still points
+        // into it
+        let references = Bin::find_words(&data, c, |p, v| {
+            (p < offset || p >= end) && v >= offset && v < end
+        });
+        if !references.is_empty() {
+            return Err(classes::Error::ObjectReferenced { offset, references }.into());
+        }
+
+        // Refuse to remove the object if anything that looks like an offset
+        // past it is not known to be one, as it cannot be safely moved back
+        let sites = self.offset_fields();
+        let (known, unknown): (Vec<u32>, Vec<u32>) = Bin::find_words(&data, c, |p, v| {
+            (p < offset || p >= end) && v >= end && v <= header.offset1
+        })
+        .into_iter()
+        .partition(|p| sites.contains(p));
+        if !unknown.is_empty() {
+            return Err(classes::Error::UnknownReferences {
+                offset,
+                references: unknown,
+            }
+            .into());
+        }
+
+        // Drop the object, then move back every offset that pointed past it.
+        // The amount dropped is kept to a multiple of 16 bytes so that the
+        // alignment of everything after the object is preserved.
+        let removed = (end - offset) & !0x0F;
+        data.splice(
+            offset as usize..end as usize,
+            vec![0x00; (end - offset - removed) as usize],
+        );
+        for position in known {
+            let position = if position >= end { position - removed } else { position } as usize;
+            let value = c.read_u32(&data[position..position + 4])?;
+            data.splice(position..position + 4, c.write_u32(value - removed)?);
+        }
+
+        // Rebuild each section's list in the same way - dropping anything
+        // within the removed object, and moving back anything past it
+        let sections = Bin::sections(&self.raw, header, c)?;
+        let lists_begin = sections
+            .first()
+            .map(|s| s.offset as usize)
+            .unwrap_or(self.raw.len());
+        let lists_end = sections
+            .last()
+            .map(|s| (s.offset + s.size * 4) as usize)
+            .unwrap_or(self.raw.len());
+        let mut descriptions = self.raw[data_end..lists_begin].to_vec();
+        let mut lists = vec![];
+        for (i, section) in sections.iter().enumerate() {
+            let mut size = 0;
+            for j in 0..section.size {
+                let position = (section.offset + j * 4) as usize;
+                let value = c.read_u32(&self.raw[position..position + 4])?;
+                if value < offset || value >= end {
+                    let value = if value >= end { value - removed } else { value };
+                    lists.extend(c.write_u32(value)?);
+                    size += 1;
+                }
+            }
+            descriptions.splice(i * 0x10 + 0x04..i * 0x10 + 0x08, c.write_u32(size)?);
+        }
+
+        let mut raw = self.raw[0x00..Bin::header_length()].to_vec();
+        raw.splice(0x10..0x14, c.write_u32(header.offset1 - removed)?);
+        raw.extend(data);
+        raw.extend(descriptions);
+        raw.extend(lists);
+        raw.extend(&self.raw[lists_end..]);
+
+        *self = Bin::with_registry(raw, c, self.registry.clone())?;
+
+        Ok(())
+    }
+
+    /// Returns a description of the header of the .bin file.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use shrek_superslam::Console;
+    /// use shrek_superslam::files::Bin;
+    ///
+    /// // Print the number of sections within the file
+    /// # let my_file_bytes: Vec<u8> = vec![];
+    /// let bin = Bin::new(my_file_bytes, Console::PC).unwrap();
+    /// println!("The file has {} sections", bin.header().sections);
+    /// ```
+    pub fn header(&self) -> BinHeaderInfo {
+        BinHeaderInfo {
+            gf_db_size: self.header.offset1,
+            sections: self.header.sections,
+            dependencies: self.header.dependencies,
+            offset4: self.header.offset4,
+        }
+    }
+
+    /// Check the structure of the .bin file, returning every problem found.
+    ///
+    /// This checks that the lists described by the header fit within the file,
+    /// that each entry in the `gf::DB` points to a known class and has a valid
+    /// name, and that the strings and lists of the supported classes (such as
+    /// the hitboxes of a `Game::AttackMoveType` or keyframes of a
+    /// `Game::Spitter`) lie within the file. An empty list means no problems
+    /// were found.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use shrek_superslam::Console;
+    /// use shrek_superslam::files::Bin;
+    ///
+    /// // Check a .bin file for problems before using it
+    /// # let my_file_bytes: Vec<u8> = vec![];
+    /// let bin = Bin::new(my_file_bytes, Console::PC).unwrap();
+    /// for issue in bin.validate() {
+    ///     println!("{:?}", issue);
+    /// }
+    /// ```
+    pub fn validate(&self) -> Vec<BinValidationIssue> {
+        let mut issues = vec![];
+
+        // Check the sections and their lists, as described by the header
+        match Bin::sections(&self.raw, self.header, self.console) {
+            Ok(sections) => {
+                if !sections.iter().any(|s| s.number == 1) {
+                    issues.push(BinValidationIssue::MissingObjectSection);
+                }
+                if let Some(last) = sections.last() {
+                    let end = last.offset as usize + last.size as usize * 4;
+                    if end > self.raw.len() {
+                        issues.push(BinValidationIssue::SectionsOutOfBounds {
+                            end,
+                            file_size: self.raw.len(),
+                        });
+                    }
+                }
+            }
+            Err(_) => issues.push(BinValidationIssue::SectionsOutOfBounds {
+                end: Bin::section_descriptions_offset(self.header) as usize
+                    + self.header.sections as usize * 0x10,
+                file_size: self.raw.len(),
+            }),
+        }
+
+        // Check every entry of the gf::DB points to a named, known object
+        if self.word(0x00) != Some(GfDb::hash()) {
+            issues.push(BinValidationIssue::UnknownObject {
+                referenced_from: 0x00,
+                offset: 0x00,
+            });
+        } else if let Some(entries) = self.validate_list(0x14, 0x18, 0x10, &mut issues) {
+            for entry in entries {
+                self.validate_string(entry, &mut issues);
+                self.validate_object(entry + 0x04, &mut issues);
+            }
+        }
+
+        // Check the strings and lists within each supported object
+        for object in &self.objects {
+            let offset = object.offset;
+            match object.hash {
+                h if h == AttackMoveType::hash() => {
+                    self.validate_string(offset + 0x28, &mut issues);
+                    let list = self.validate_list(offset + 0x20, offset + 0x24, 0x04, &mut issues);
+                    if let Some(hitboxes) = list {
+                        for hitbox in hitboxes {
+                            self.validate_object(hitbox, &mut issues);
+                        }
+                    }
+                    if self.word(offset + 0x9C).unwrap_or(0) != 0 {
+                        self.validate_object(offset + 0x9C, &mut issues);
+                    }
+                }
+                h if h == Spitter::hash() => {
+                    let list = self.validate_list(offset + 0x20, offset + 0x24, 0x04, &mut issues);
+                    if let Some(keyframes) = list {
+                        for keyframe in keyframes {
+                            self.validate_object(keyframe, &mut issues);
+                        }
+                    }
+                }
+                h if h == LocalizedString::hash() => {
+                    self.validate_string(offset + 0x08, &mut issues)
+                }
+                h if h == EffectStringReference::hash() => {
+                    self.validate_string(offset + 0x04, &mut issues)
+                }
+                _ => {}
+            }
+        }
+
+        issues
+    }
+
+    /// Returns the offset of every field within the file's objects that is
+    /// known to hold an offset - those of the `gf::DB` and its entries, and
+    /// the strings, lists and the items of those lists of the supported
+    /// classes.
+    fn offset_fields(&self) -> HashSet<u32> {
+        // Lists that do not fit within the objects are skipped, in the same
+        // way as they are reported by Bin::validate()
+        let list = |list_from: u32, count_from: u32, item_size: u32| -> Vec<u32> {
+            let offset = self.word(list_from).unwrap_or(u32::MAX);
+            let count = self.word(count_from).unwrap_or(0);
+            if offset as u64 + (count as u64 * item_size as u64) > self.header.offset1 as u64 {
+                return vec![];
+            }
+            (0..count).map(|i| offset + (i * item_size)).collect()
+        };
+
+        let mut fields = HashSet::new();
+        fields.insert(0x14);
+        for entry in list(0x14, 0x18, 0x10) {
+            fields.insert(entry);
+            fields.insert(entry + 0x04);
+        }
+
+        for object in &self.objects {
+            let offset = object.offset;
+            match object.hash {
+                h if h == AttackMoveType::hash() => {
+                    fields.extend(list(offset + 0x20, offset + 0x24, 0x04));
+                    fields.extend(&[offset + 0x20, offset + 0x28, offset + 0x9C]);
+                }
+                h if h == Spitter::hash() => {
+                    fields.extend(list(offset + 0x20, offset + 0x24, 0x04));
+                    fields.insert(offset + 0x20);
+                }
+                h if h == SpitterKeyframe::hash() => {
+                    fields.insert(offset + 0xBC);
+                }
+                h if h == EventSequence::hash() => {
+                    fields.extend(list(offset + 0x04, offset + 0x08, 0x04));
+                    fields.insert(offset + 0x04);
+                }
+                h if h == LocalizedString::hash() => {
+                    fields.insert(offset + 0x08);
+                }
+                h if h == EffectStringReference::hash() => {
+                    fields.insert(offset + 0x04);
+                }
+                _ => {}
+            }
+        }
+
+        fields
+    }
+
+    /// Returns the value at the given `offset` within the file's objects, or
+    /// None if the offset lies outside of the objects.
+    fn word(&self, offset: u32) -> Option<u32> {
+        if offset as u64 + 4 > self.header.offset1 as u64 {
+            return None;
+        }
+        let begin = Bin::header_length() + offset as usize;
+        self.console.read_u32(&self.raw[begin..begin + 4]).ok()
+    }
+
+    /// Checks that the value at `referenced_from` points to a complete string
+    /// within the file's objects, recording any problem in `issues`.
+    fn validate_string(&self, referenced_from: u32, issues: &mut Vec<BinValidationIssue>) {
+        let offset = self.word(referenced_from).unwrap_or(u32::MAX);
+        let data_end = Bin::section_descriptions_offset(self.header) as usize;
+        let terminated = offset < self.header.offset1
+            && self.raw[Bin::header_length() + offset as usize..data_end].contains(&0x00);
+        if !terminated {
+            issues.push(BinValidationIssue::StringOutOfBounds {
+                referenced_from,
+                offset,
+            });
+        }
+    }
+
+    /// Checks that the value at `referenced_from` points to a known object,
+    /// recording any problem in `issues`.
+    fn validate_object(&self, referenced_from: u32, issues: &mut Vec<BinValidationIssue>) {
+        let offset = self.word(referenced_from).unwrap_or(u32::MAX);
+        let known = self
+            .word(offset)
+            .map(|hash| self.registry.lookup(hash).is_some())
+            .unwrap_or(false);
+        if !known {
+            issues.push(BinValidationIssue::UnknownObject {
+                referenced_from,
+                offset,
+            });
+        }
+    }
+
+    /// Checks that the list pointed to by the value at `list_from`, with a
+    /// count of items at `count_from`, lies within the file's objects,
+    /// recording any problem in `issues`.
+    ///
+    /// Returns the offset of each item of `item_size` bytes in the list, or
+    /// None if the list does not fit.
+    fn validate_list(
+        &self,
+        list_from: u32,
+        count_from: u32,
+        item_size: u32,
+        issues: &mut Vec<BinValidationIssue>,
+    ) -> Option<Vec<u32>> {
+        let offset = self.word(list_from).unwrap_or(u32::MAX);
+        let count = self.word(count_from).unwrap_or(0);
+        let end = offset as u64 + (count as u64 * item_size as u64);
+        if count > 0 && end > self.header.offset1 as u64 {
+            issues.push(BinValidationIssue::ListOutOfBounds {
+                referenced_from: list_from,
+                offset,
+                count,
+            });
+            return None;
+        }
+
+        Some((0..count).map(|i| offset + (i * item_size)).collect())
+    }
+
+    /// Returns the raw bytes of the .bin file.
+    ///
+    /// The bytes include the header, so an offset stored within the file must
+    /// be converted with [`Bin::file_offset`] before indexing them.
+    pub fn raw(&self) -> &[u8] {
+        &self.raw
+    }
+
+    /// Returns a list of objects within the .bin file.
+    pub fn objects(&self) -> &Vec<BinObject> {
+        &self.objects
+    }
+}
+
+/// Escape the given `text` for use within a quoted string in the DOT language.
+///
+/// # Parameters
+///
+/// - `text`: The text to escape
+///
+/// # Returns
+///
+/// The text with its backslashes and quotes escaped.
+fn dot_escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::classes::{
+        AttackMoveRegion, AttackMoveTypeBuilder, EventSequence, GameWorld, PhysicsFighting,
+        ProjectileType, SpitterKeyframe,
+    };
+
+    /// Creates the bytes of a small .bin file, containing a `gf::DB` with a
+    /// single entry named "hitbox" pointing to a `Game::AttackMoveRegion`.
+    fn test_bin_bytes(c: Console) -> Vec<u8> {
+        let mut data = vec![0x00; 0x90];
+
+        // gf::DB at +00, with its single entry at +30
+        data.splice(0x00..0x04, c.write_u32(0x9B3DDBED).unwrap());
+        data.splice(0x14..0x18, c.write_u32(0x30).unwrap());
+        data.splice(0x18..0x1C, c.write_u32(1).unwrap());
+        data.splice(0x30..0x34, c.write_u32(0x40).unwrap());
+        data.splice(0x34..0x38, c.write_u32(0x50).unwrap());
+        data.splice(0x40..0x46, b"hitbox".iter().cloned());
+
+        // Game::AttackMoveRegion at +50
+        data.splice(0x50..0x54, c.write_u32(0xF2CFE08D).unwrap());
+        data.splice(0x54..0x58, c.write_f32(0.5).unwrap());
+        data.splice(0x80..0x84, c.write_f32(1.0).unwrap());
+        data.splice(0x88..0x8C, c.write_f32(2.0).unwrap());
+
+        let mut header = vec![0x00; Bin::header_length()];
+        header.splice(0x10..0x14, c.write_u32(data.len() as u32).unwrap());
+        header.splice(0x18..0x1C, c.write_u32(1).unwrap());
+
+        // A single section listing both objects
+        let mut section = vec![0x00; 0x10];
+        section.splice(0x00..0x04, c.write_u32(1).unwrap());
+        section.splice(0x04..0x08, c.write_u32(2).unwrap());
+
+        let mut raw = header;
+        raw.extend(data);
+        raw.extend(section);
+        raw.extend(c.write_u32(0x00).unwrap());
+        raw.extend(c.write_u32(0x50).unwrap());
+        raw
+    }
+
+    #[test]
+    fn add_object() {
+        for c in &[Console::PC, Console::Gamecube] {
+            let mut bin = Bin::new(test_bin_bytes(*c), *c).unwrap();
+            let hitbox = AttackMoveRegion {
+                delay: 1.5,
+                arc: 3.0,
+                radius: 4.0,
+                horizontal_angle: 0.0,
+                unknown_024: 0.0,
+            };
+            let offset = bin.add_object("new_hitbox", &hitbox).unwrap();
+
+            // Re-parse the modified file from scratch to ensure everything
+            // was written correctly
+            let bin = Bin::new(bin.raw().to_vec(), *c).unwrap();
+            assert_eq!(bin.objects().len(), 3);
+            assert_eq!(bin.objects()[2].offset, offset);
+
+            let db = bin.get_object_from_offset::<GfDb>(0x00).unwrap();
+            assert_eq!(db.entries.len(), 2);
+            assert_eq!(db.entries[0].0, "hitbox");
+            assert_eq!(db.entries[0].1.offset, 0x50);
+            assert_eq!(db.entries[1].0, "new_hitbox");
+            assert_eq!(db.entries[1].1.offset, offset);
+
+            let new_hitbox = bin
+                .get_object_from_offset::<AttackMoveRegion>(offset)
+                .unwrap();
+            assert_eq!(new_hitbox.delay, 1.5);
+            assert_eq!(new_hitbox.arc, 3.0);
+            assert_eq!(new_hitbox.radius, 4.0);
+
+            let old_hitbox = bin.get_object_from_offset::<AttackMoveRegion>(0x50).unwrap();
+            assert_eq!(old_hitbox.delay, 0.5);
+        }
+    }
+
+    #[test]
+    fn remove_object() {
+        for c in &[Console::PC, Console::Gamecube] {
+            let raw = test_bin_bytes(*c);
+            let mut bin = Bin::new(raw.clone(), *c).unwrap();
+            bin.remove_object(0x50).unwrap();
+
+            let bin = Bin::new(bin.raw().to_vec(), *c).unwrap();
+            assert_eq!(bin.objects().len(), 1);
+            assert_eq!(bin.raw().len(), raw.len() - 0x40 - 0x04);
+            let db = bin.get_object_from_offset::<GfDb>(0x00).unwrap();
+            assert!(db.entries.is_empty());
+        }
+    }
+
+    #[test]
+    fn remove_object_moves_later_offsets() {
+        for c in &[Console::PC, Console::Gamecube] {
+            let mut bin = Bin::new(test_bin_bytes(*c), *c).unwrap();
+            let hitbox = AttackMoveRegion {
+                delay: 1.5,
+                arc: 3.0,
+                radius: 4.0,
+                horizontal_angle: 0.0,
+                unknown_024: 0.0,
+            };
+            let offset = bin.add_object("new_hitbox", &hitbox).unwrap();
+            bin.remove_object(0x50).unwrap();
+
+            let bin = Bin::new(bin.raw().to_vec(), *c).unwrap();
+            assert_eq!(bin.objects().len(), 2);
+            let db = bin.get_object_from_offset::<GfDb>(0x00).unwrap();
+            assert_eq!(db.entries.len(), 1);
+            assert_eq!(db.entries[0].0, "new_hitbox");
+            assert_eq!(db.entries[0].1.offset, offset - 0x40);
+
+            let new_hitbox = bin
+                .get_object_from_offset::<AttackMoveRegion>(offset - 0x40)
+                .unwrap();
+            assert_eq!(new_hitbox.delay, 1.5);
+        }
+    }
+
+    #[test]
+    fn remove_object_unknown_offset() {
+        let c = Console::PC;
+        let mut bin = Bin::new(test_bin_bytes(c), c).unwrap();
+        let offset = bin.add_object("new_hitbox", &AttackMoveRegion::default()).unwrap();
+
+        // A field of the gf::DB that is not known to hold an offset, but looks
+        // like an offset to the new hitbox
+        let begin = Bin::header_length() + 0x20;
+        bin.raw.splice(begin..begin + 4, c.write_u32(offset).unwrap());
+        let original = bin.raw().to_vec();
+        match bin.remove_object(0x50) {
+            Err(Error::ClassDeserialiseError(classes::Error::UnknownReferences {
+                offset: 0x50,
+                references,
+            })) => assert_eq!(references, vec![0x20]),
+            _ => panic!("Expected an unknown reference"),
+        }
+        assert_eq!(bin.raw(), &original[..]);
+    }
+
+    #[test]
+    fn remove_referenced_object() {
+        let c = Console::PC;
+        let mut raw = test_bin_bytes(c);
+        raw.splice(0x60..0x64, c.write_u32(0x50).unwrap());
+        let mut bin = Bin::new(raw.clone(), c).unwrap();
+
+        assert!(bin.remove_object(0x50).is_err());
+        assert!(bin.remove_object(0x00).is_err());
+        assert!(bin.remove_object(0x10).is_err());
+        assert_eq!(bin.raw(), &raw[..]);
+    }
+
+    #[test]
+    fn header() {
+        let c = Console::PC;
+        let mut bin = Bin::new(test_bin_bytes(c), c).unwrap();
+        let header = bin.header();
+        assert_eq!(header.gf_db_size, 0x90);
+        assert_eq!(header.sections, 1);
+        assert_eq!(header.dependencies, 0);
+        assert_eq!(header.offset4, 0);
+
+        bin.remove_object(0x50).unwrap();
+        assert_eq!(bin.header().gf_db_size, 0x50);
+        assert_eq!(Bin::new(bin.raw().to_vec(), c).unwrap().header(), bin.header());
+    }
+
+    #[test]
+    fn offset_conversion() {
+        let c = Console::PC;
+        let bin = Bin::new(test_bin_bytes(c), c).unwrap();
+        for object in bin.objects() {
+            let begin = Bin::file_offset(object.offset);
+            assert_eq!(c.read_u32(&bin.raw()[begin..begin + 4]).unwrap(), object.hash);
+            assert_eq!(Bin::stored_offset(begin), object.offset);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn stored_offset_within_header() {
+        Bin::stored_offset(0x3C);
+    }
+
+    #[test]
+    fn invalid_header() {
+        for c in &[Console::PC, Console::Gamecube] {
+            let raw = test_bin_bytes(*c);
+            let values = [(0x10, 0x1000), (0x10, 0x08), (0x18, 0xFFFF_FFFF), (0x24, 0x10)];
+            for (offset, value) in &values {
+                let mut invalid = raw.clone();
+                invalid.splice(*offset..*offset + 4, c.write_u32(*value).unwrap());
+                match Bin::new(invalid, *c) {
+                    Err(Error::InvalidBinHeaderError { offset: o }) => assert_eq!(o, *offset),
+                    _ => panic!("expected an invalid header at 0x{:X}", offset),
+                }
+            }
+
+            // Too short to hold a header at all
+            assert!(Bin::new(raw[..0x20].to_vec(), *c).is_err());
+        }
+    }
+
+    #[test]
+    fn random_bytes() {
+        let mut state: u32 = 0x1234_5678;
+        for length in &[0x00, 0x10, 0x40, 0x41, 0x100, 0x1000] {
+            let raw: Vec<u8> = (0..*length)
+                .map(|_| {
+                    state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+                    (state >> 16) as u8
+                })
+                .collect();
+            for c in &[Console::PC, Console::Gamecube] {
+                assert!(Bin::new(raw.clone(), *c).is_err());
+            }
+        }
+    }
+
+    #[test]
+    fn validate() {
+        let c = Console::PC;
+        let bin = Bin::new(test_bin_bytes(c), c).unwrap();
+        assert!(bin.validate().is_empty());
+
+        // Point the gf::DB entry's name past the end of the objects, and its
+        // object to somewhere without an object
+        let mut raw = test_bin_bytes(c);
+        raw.splice(0x70..0x74, c.write_u32(0x1000).unwrap());
+        raw.splice(0x74..0x78, c.write_u32(0x60).unwrap());
+        let bin = Bin::new(raw, c).unwrap();
+        assert_eq!(
+            bin.validate(),
+            vec![
+                BinValidationIssue::StringOutOfBounds {
+                    referenced_from: 0x30,
+                    offset: 0x1000
+                },
+                BinValidationIssue::UnknownObject {
+                    referenced_from: 0x34,
+                    offset: 0x60
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn named_objects() {
+        let c = Console::PC;
+        let bin = Bin::new(test_bin_bytes(c), c).unwrap();
+        let names: Vec<(String, u32)> = bin
+            .named_objects()
+            .map(|(name, object)| (name, object.offset))
+            .collect();
+        assert_eq!(names, vec![(String::new(), 0x00), (String::from("hitbox"), 0x50)]);
+    }
+
+    #[test]
+    fn references_to() {
+        for c in &[Console::PC, Console::Gamecube] {
+            let bin = Bin::new(test_bin_bytes(*c), *c).unwrap();
+            assert_eq!(bin.references_to(0x50), vec![0x34]);
+            assert_eq!(bin.references_to(0x40), vec![0x30]);
+            assert!(bin.references_to(0x60).is_empty());
+        }
+    }
+
+    #[test]
+    fn to_dot() {
+        let c = Console::PC;
+        let bin = Bin::new(test_bin_bytes(c), c).unwrap();
+        assert_eq!(
+            bin.to_dot(),
+            "digraph {\n\
+             \x20   \"00000000\" [label=\"gf::DB\"];\n\
+             \x20   \"00000050\" [label=\"hitbox\\nGame::AttackMoveRegion\"];\n\
+             \x20   \"00000000\" -> \"00000050\";\n\
+             }\n"
+        );
+    }
+
+    #[test]
+    fn objects_referenced_from() {
+        let c = Console::PC;
+        let mut bin = Bin::new(test_bin_bytes(c), c).unwrap();
+        let offset = bin.add_object("world", &GameWorld::default()).unwrap();
+
+        // A new world refers to nothing but itself
+        assert!(bin.objects_referenced_from(offset).unwrap().is_empty());
+
+        // Point the world at the hitbox twice, which is only listed once
+        let begin = offset as usize + Bin::header_length();
+        bin.raw.splice(begin + 0x100..begin + 0x104, c.write_u32(0x50).unwrap());
+        bin.raw.splice(begin + 0x200..begin + 0x204, c.write_u32(0x50).unwrap());
+        let objects = bin.objects_referenced_from(offset).unwrap();
+        assert_eq!(objects.len(), 1);
+        assert_eq!(objects[0].offset, 0x50);
+        assert_eq!(objects[0].name, "Game::AttackMoveRegion");
+
+        // No object begins part way through the world
+        assert!(bin.objects_referenced_from(offset + 0x04).is_err());
+    }
+
+    #[test]
+    fn parse_ordered() {
+        let c = Console::PC;
+        let mut bin = Bin::new(test_bin_bytes(c), c).unwrap();
+        let hitbox = AttackMoveRegion {
+            delay: 1.5,
+            arc: 3.0,
+            radius: 4.0,
+            horizontal_angle: 0.0,
+            unknown_024: 0.0,
+        };
+        bin.add_object("a_new_hitbox", &hitbox).unwrap();
+
+        let names: Vec<String> = bin
+            .parse_ordered()
+            .unwrap()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+        assert_eq!(names, vec!["hitbox", "a_new_hitbox"]);
+
+        let objects = bin.parse().unwrap();
+        assert_eq!(objects.len(), 2);
+        match &objects["a_new_hitbox"] {
+            ShrekSuperSlamObject::AttackMoveRegion(h) => assert_eq!(h.delay, 1.5),
+            _ => panic!("Incorrect object type"),
+        }
+    }
+
+    #[test]
+    fn serialise_parsed_objects() {
+        let c = Console::PC;
+        let bin = Bin::new(test_bin_bytes(c), c).unwrap();
+
+        let json = serde_json::to_value(bin.parse().unwrap()).unwrap();
+        assert_eq!(json["hitbox"]["class"], "Game::AttackMoveRegion");
+        assert_eq!(json["hitbox"]["delay"], 0.5);
+        assert_eq!(json["hitbox"]["radius"], 2.0);
+    }
+
+    #[test]
+    fn serialise_objects() {
+        let c = Console::PC;
+        let bin = Bin::new(test_bin_bytes(c), c).unwrap();
+
+        let json = serde_json::to_value(bin.objects()).unwrap();
+        assert_eq!(json[1]["hash"], 0xF2CFE08Du32);
+        assert_eq!(json[1]["name"], "Game::AttackMoveRegion");
+        assert_eq!(json[1]["offset"], 0x50);
+    }
+
+    #[test]
+    fn serialise_to_json_and_apply() {
+        let c = Console::PC;
+        let mut bin = Bin::new(test_bin_bytes(c), c).unwrap();
+        let original = bin.raw().to_vec();
+
+        let json = bin.to_json().unwrap();
+        let mut value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value[0]["name"], "hitbox");
+        assert_eq!(value[0]["object"]["class"], "Game::AttackMoveRegion");
+
+        // Applying the unmodified JSON leaves the file unchanged
+        bin.apply_json(&json).unwrap();
+        assert_eq!(bin.raw(), &original[..]);
+
+        value[0]["object"]["radius"] = serde_json::Value::from(8.0);
+        bin.apply_json(&value.to_string()).unwrap();
+        let hitbox = bin.get_object_from_offset::<AttackMoveRegion>(0x50).unwrap();
+        assert_eq!(hitbox.radius, 8.0);
+        assert!(bin.apply_json("not json").is_err());
+
+        // Nothing is written if any object cannot be written, even those
+        // before it
+        let edited = bin.raw().to_vec();
+        value[0]["object"]["radius"] = serde_json::Value::from(16.0);
+        let mut misplaced = value[0].clone();
+        misplaced["offset"] = serde_json::Value::from(0x00);
+        value.as_array_mut().unwrap().push(misplaced);
+        assert!(bin.apply_json(&value.to_string()).is_err());
+        assert_eq!(bin.raw(), &edited[..]);
+    }
+
+    #[test]
+    fn object_region() {
+        let c = Console::PC;
+        let bin = Bin::new(test_bin_bytes(c), c).unwrap();
+        assert_eq!(bin.object_region(0x00).len(), 0x50);
+        let hitbox = bin.object_region(0x50);
+        assert_eq!(hitbox.len(), 0x40);
+        assert_eq!(&hitbox[..4], &c.write_u32(AttackMoveRegion::hash()).unwrap()[..]);
+    }
+
+    #[test]
+    fn object_bytes() {
+        for c in &[Console::PC, Console::Gamecube] {
+            let mut bin = Bin::new(test_bin_bytes(*c), *c).unwrap();
+
+            // Both the gf::DB and hitbox have a known size
+            assert_eq!(bin.object_bytes(0x00).unwrap().len(), GfDb::size());
+            let hitbox = bin.object_bytes(0x50).unwrap();
+            assert_eq!(hitbox.len(), AttackMoveRegion::size());
+            assert_eq!(&hitbox[..4], &c.write_u32(AttackMoveRegion::hash()).unwrap()[..]);
+
+            // Objects of an unsupported class, here a Game::PlayerEntity, run up
+            // to the end of the objects
+            let offset = bin.add_object("new_hitbox", &AttackMoveRegion::default()).unwrap();
+            let end = Bin::section_descriptions_offset(bin.header) as usize;
+            let start = offset as usize + Bin::header_length();
+            let mut raw = bin.raw().to_vec();
+            raw.splice(start..start + 4, c.write_u32(0xE2BB19C3).unwrap());
+            let bin = Bin::new(raw, *c).unwrap();
+            assert_eq!(bin.object_bytes(offset).unwrap().len(), end - start);
+
+            match bin.object_bytes(0x04) {
+                Err(Error::ClassDeserialiseError(classes::Error::ObjectNotFound {
+                    offset: 0x04,
+                })) => (),
+                _ => panic!("expected ObjectNotFound"),
+            }
+        }
+    }
+
+    #[test]
+    fn read_at() {
+        let c = Console::PC;
+        let bin = Bin::new(test_bin_bytes(c), c).unwrap();
+        let len = bin.raw().len();
+        assert_eq!(bin.read_at(len - 4, 4).unwrap().len(), 4);
+        assert_eq!(bin.read_at(len, 0).unwrap().len(), 0);
+        match bin.read_at(len - 2, 4) {
+            Err(Error::ClassDeserialiseError(classes::Error::NotEnoughBytes {
+                requested: 4,
+                file_size,
+                offset,
+            })) => {
+                assert_eq!(file_size, len);
+                assert_eq!(offset, len - 2);
+            }
+            _ => panic!("expected NotEnoughBytes"),
+        }
+        assert!(bin.read_at(usize::MAX, 1).is_err());
+    }
+
+    #[test]
+    fn truncated_db_entries() {
+        for c in &[Console::PC, Console::Gamecube] {
+            let bin = Bin::new(test_bin_bytes(*c), *c).unwrap();
+
+            // Claim the gf::DB has far more entries than the file can hold
+            let mut raw = bin.raw().to_vec();
+            let count = Bin::header_length() + 0x18;
+            raw.splice(count..count + 4, c.write_u32(0x1000).unwrap());
+            let bin = Bin::new(raw, *c).unwrap();
+            match bin.get_object_from_offset::<GfDb>(0x00) {
+                Err(Error::ClassDeserialiseError(classes::Error::NotEnoughBytes {
+                    requested: 0x10000,
+                    ..
+                })) => (),
+                _ => panic!("expected NotEnoughBytes"),
+            }
+
+            // Adding to the gf::DB fails in the same way, rather than panicking
+            let mut bin = bin;
+            assert!(bin.add_object("new", &AttackMoveRegion::default()).is_err());
+        }
+    }
+
+    #[test]
+    fn overwrite_object_out_of_bounds() {
+        let c = Console::PC;
+        let mut bin = Bin::new(test_bin_bytes(c), c).unwrap();
+        let hitbox = bin.get_object_from_offset::<AttackMoveRegion>(0x50).unwrap();
+        let original = bin.raw().to_vec();
+
+        // An offset past the end of the file, and an object that would run
+        // past the end of the file
+        let past_end = bin.raw().len() as u32;
+        assert!(bin.overwrite_object(past_end, &hitbox).is_err());
+        let mut raw = test_bin_bytes(c);
+        raw.truncate(Bin::header_length() + 0x50 + 0x08);
+        let mut truncated = Bin {
+            raw,
+            ..Bin::new(test_bin_bytes(c), c).unwrap()
+        };
+        match truncated.overwrite_object(0x50, &hitbox) {
+            Err(Error::ClassDeserialiseError(classes::Error::NotEnoughBytes { .. })) => (),
+            _ => panic!("expected NotEnoughBytes"),
+        }
+        assert_eq!(bin.raw(), &original[..]);
+    }
+
+    /// Returns the test .bin, with the hitbox at +50 replaced by a
+    /// gf::LocalizedString pointing to the given `string` offset.
+    fn test_localized_string_bytes(c: Console, string: u32, unknown: u32) -> Vec<u8> {
+        let mut raw = test_bin_bytes(c);
+        let object = Bin::header_length() + 0x50;
+        raw.splice(object..object + 0x04, c.write_u32(LocalizedString::hash()).unwrap());
+        raw.splice(object + 0x04..object + 0x08, c.write_u32(unknown).unwrap());
+        raw.splice(object + 0x08..object + 0x0C, c.write_u32(string).unwrap());
+        raw.splice(object + 0x10..object + 0x14, b"text".iter().cloned());
+        raw
+    }
+
+    #[test]
+    fn set_localized_string() {
+        for c in &[Console::PC, Console::Gamecube] {
+            // Shorter text is written in place
+            let mut bin = Bin::new(test_localized_string_bytes(*c, 0x60, 1), *c).unwrap();
+            let length = bin.raw().len();
+            bin.set_localized_string(0x50, "txt").unwrap();
+            let string = bin.get_object_from_offset::<LocalizedString>(0x50).unwrap();
+            assert_eq!(string.string, "txt");
+            assert_eq!(bin.raw().len(), length);
+
+            // Longer text is appended, and the old text blanked
+            bin.set_localized_string(0x50, "much longer text").unwrap();
+            let bin = Bin::new(bin.raw().to_vec(), *c).unwrap();
+            let string = bin.get_object_from_offset::<LocalizedString>(0x50).unwrap();
+            assert_eq!(string.string, "much longer text");
+            assert!(!string.is_empty());
+            assert_eq!(bin.get_str_from_offset(0x60).unwrap(), "");
+        }
+    }
+
+    #[test]
+    fn set_localized_string_shared() {
+        let c = Console::PC;
+
+        // The string shares its text with the name of the object in the gf::DB
+        let mut bin = Bin::new(test_localized_string_bytes(c, 0x40, 0), c).unwrap();
+        bin.set_localized_string(0x50, "hit").unwrap();
+        let string = bin.get_object_from_offset::<LocalizedString>(0x50).unwrap();
+        assert_eq!(string.string, "hit");
+        assert!(string.is_empty());
+        assert_eq!(bin.get_str_from_offset(0x40).unwrap(), "hitbox");
+
+        match bin.set_localized_string(0x00, "text") {
+            Err(Error::ClassDeserialiseError(classes::Error::IncorrectType { .. })) => (),
+            _ => panic!("expected IncorrectType"),
+        }
+        assert!(bin.set_localized_string(0x50, "\u{4E2D}").is_err());
+    }
+
+    #[test]
+    fn write_attack_hitbox_mismatch() {
+        let c = Console::PC;
+        let mut bin = Bin::new(test_bin_bytes(c), c).unwrap();
+        let original = bin.raw().to_vec();
+
+        // The object at +50 has no hitboxes to write over
+        let attack = AttackMoveTypeBuilder::new("new_atk")
+            .hitbox(AttackMoveRegion::default())
+            .build();
+        match attack.write(&mut bin, Bin::header_length() + 0x50) {
+            Err(Error::ClassDeserialiseError(classes::Error::HitboxCountMismatch {
+                hitboxes: 1,
+                offsets: 0,
+            })) => {}
+            _ => panic!("Expected a hitbox count mismatch"),
+        }
+        assert_eq!(bin.raw(), &original[..]);
+    }
+
+    /// Adds a new patterned `Game::Spitter` to the `bin`, with the given
+    /// number of new patterned keyframes that run no event.
+    fn add_patterned_spitter(bin: &mut Bin, keyframes: usize) -> u32 {
+        let c = bin.console;
+        let spitter = add_patterned_object::<Spitter>(bin);
+        let mut list = vec![];
+        for _ in 0..keyframes {
+            let keyframe = add_patterned_object::<SpitterKeyframe>(bin);
+            let begin = Bin::header_length() + keyframe as usize;
+            bin.raw.splice(begin + 0xBC..begin + 0xC0, c.write_u32(0).unwrap());
+            list.extend(c.write_u32(keyframe).unwrap());
+        }
+        let list = bin.append_data(&list).unwrap();
+        let begin = Bin::header_length() + spitter as usize;
+        bin.raw.splice(begin + 0x20..begin + 0x24, c.write_u32(list).unwrap());
+        bin.raw.splice(begin + 0x24..begin + 0x28, c.write_u32(keyframes as u32).unwrap());
+        spitter
+    }
+
+    /// Adds a new object of type `T` to the `bin`, with every byte after its
+    /// hash set to a pattern that reads as a different value for each field.
+    ///
+    /// The bytes are all between 0x01 and 0x3F, so no field reads as zero and
+    /// no float reads as NaN.
+    fn add_patterned_object<T>(bin: &mut Bin) -> u32
+    where
+        T: Default + SerialisedShrekSuperSlamGameObject + WriteableShrekSuperSlamGameObject,
+    {
+        let offset = bin.add_object("patterned", &T::default()).unwrap();
+        let begin = Bin::header_length() + offset as usize;
+        for i in 0x04..T::size() {
+            bin.raw[begin + i] = (i % 0x3F) as u8 + 1;
+        }
+        offset
+    }
+
+    /// Adds a new patterned `Game::AttackMoveType` to the `bin`, pointing to
+    /// the given `hitboxes` and `projectile` and named "hitbox".
+    fn add_patterned_attack(bin: &mut Bin, hitboxes: &[u32], projectile: u32) -> u32 {
+        let c = bin.console;
+        let offset = add_patterned_object::<AttackMoveType>(bin);
+        let list: Vec<u8> = hitboxes
+            .iter()
+            .flat_map(|h| c.write_u32(*h).unwrap())
+            .collect();
+        let list_offset = if hitboxes.is_empty() { 0 } else { bin.append_data(&list).unwrap() };
+
+        let begin = Bin::header_length() + offset as usize;
+        let mut set = |at: usize, value: u32| {
+            bin.raw.splice(begin + at..begin + at + 4, c.write_u32(value).unwrap());
+        };
+        set(0x20, list_offset);
+        set(0x24, hitboxes.len() as u32);
+        set(0x28, 0x40);
+        set(0x9C, projectile);
+
+        // Each flag is read as a single byte that is either zero or one
+        let flags = [
+            0x2C, 0x2D, 0x2E, 0x2F, 0x30, 0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37, 0x38, 0x39,
+            0x3A, 0x40, 0x41, 0x42, 0x43, 0x44, 0x47, 0x4A, 0x4B,
+        ];
+        for (i, flag) in flags.iter().enumerate() {
+            bin.raw[begin + flag] = (i % 2) as u8;
+        }
+        offset
+    }
+
+    /// Reads the object of type `T` at the given `offset`, and checks that
+    /// writing it straight back leaves the file unchanged.
+    fn assert_write_unchanged<T>(bin: &mut Bin, offset: u32) -> T
+    where
+        T: SerialisedShrekSuperSlamGameObject + WriteableShrekSuperSlamGameObject + PartialEq,
+    {
+        let original = bin.raw().to_vec();
+        let object = bin.get_object_from_offset::<T>(offset).unwrap();
+        bin.overwrite_object(offset, &object).unwrap();
+        let changed = bin.raw().iter().zip(&original).position(|(a, b)| a != b);
+        assert_eq!(changed, None, "writing a {} changed the file", T::name());
+        assert!(bin.get_object_from_offset::<T>(offset).unwrap() == object);
+        object
+    }
+
+    /// Writes the given `object` into a new blank object, and reads it back.
+    fn copy_object<T>(bin: &mut Bin, object: &T) -> T
+    where
+        T: SerialisedShrekSuperSlamGameObject + WriteableShrekSuperSlamGameObject,
+    {
+        let offset = bin.add_object("copy", object).unwrap();
+        bin.get_object_from_offset::<T>(offset).unwrap()
+    }
+
+    #[test]
+    fn round_trip_attack_move_region() {
+        for c in &[Console::PC, Console::Gamecube] {
+            let mut bin = Bin::new(test_bin_bytes(*c), *c).unwrap();
+            let offset = add_patterned_object::<AttackMoveRegion>(&mut bin);
+            let hitbox = assert_write_unchanged::<AttackMoveRegion>(&mut bin, offset);
+            assert!(copy_object(&mut bin, &hitbox) == hitbox);
+        }
+    }
+
+    #[test]
+    fn round_trip_projectile_type() {
+        for c in &[Console::PC, Console::Gamecube] {
+            let mut bin = Bin::new(test_bin_bytes(*c), *c).unwrap();
+            let offset = add_patterned_object::<ProjectileType>(&mut bin);
+            let projectile = assert_write_unchanged::<ProjectileType>(&mut bin, offset);
+            assert!(copy_object(&mut bin, &projectile) == projectile);
+        }
+    }
+
+    #[test]
+    fn round_trip_attack_move_type() {
+        for c in &[Console::PC, Console::Gamecube] {
+            let mut bin = Bin::new(test_bin_bytes(*c), *c).unwrap();
+            let offset = add_patterned_attack(&mut bin, &[], 0);
+            let attack = assert_write_unchanged::<AttackMoveType>(&mut bin, offset);
+            assert_eq!(attack.name, "hitbox");
+
+            // The name is never written, as it would change the size of the file
+            let mut copy = copy_object(&mut bin, &attack);
+            copy.name = attack.name.clone();
+            assert!(copy == attack);
+        }
+    }
+
+    #[test]
+    fn round_trip_attack_move_type_hitboxes() {
+        for c in &[Console::PC, Console::Gamecube] {
+            let mut bin = Bin::new(test_bin_bytes(*c), *c).unwrap();
+            let hitbox = add_patterned_object::<AttackMoveRegion>(&mut bin);
+            let projectile = add_patterned_object::<ProjectileType>(&mut bin);
+            let offset = add_patterned_attack(&mut bin, &[0x50, hitbox], projectile);
+            let attack = assert_write_unchanged::<AttackMoveType>(&mut bin, offset);
+            assert_eq!(attack.hitboxes.len(), 2);
+            assert!(attack.projectile.is_some());
+        }
+    }
+
+    #[test]
+    fn copy_attack_move_type_leaves_source_unchanged() {
+        let c = Console::PC;
+        let mut bin = Bin::new(test_bin_bytes(c), c).unwrap();
+        let hitbox = add_patterned_object::<AttackMoveRegion>(&mut bin);
+        let projectile = add_patterned_object::<ProjectileType>(&mut bin);
+        let source = add_patterned_attack(&mut bin, &[hitbox], projectile);
+        let mut attack = bin.get_object_from_offset::<AttackMoveType>(source).unwrap();
+        attack.hitboxes_mut()[0].radius = 999.0;
+        attack.projectile.as_mut().unwrap().arc = 999.0;
+
+        // A new object has no hitbox for the attack to write over
+        let original = bin.raw().to_vec();
+        assert!(bin.add_object("copy", &attack).is_err());
+        assert_eq!(bin.raw(), &original[..]);
+
+        // Another attack has its own hitbox and projectile written over instead
+        let other_hitbox = add_patterned_object::<AttackMoveRegion>(&mut bin);
+        let other_projectile = add_patterned_object::<ProjectileType>(&mut bin);
+        let other = add_patterned_attack(&mut bin, &[other_hitbox], other_projectile);
+        bin.overwrite_object(other, &attack).unwrap();
+        let source = bin.get_object_from_offset::<AttackMoveType>(source).unwrap();
+        assert_ne!(source.hitboxes[0].radius, 999.0);
+        assert_ne!(source.projectile.unwrap().arc, 999.0);
+        let other = bin.get_object_from_offset::<AttackMoveType>(other).unwrap();
+        assert_eq!(other.hitboxes[0].radius, 999.0);
+        assert_eq!(other.projectile.unwrap().arc, 999.0);
+    }
+
+    #[test]
+    fn round_trip_physics_fighting() {
+        for c in &[Console::PC, Console::Gamecube] {
+            let mut bin = Bin::new(test_bin_bytes(*c), *c).unwrap();
+            let offset = add_patterned_object::<PhysicsFighting>(&mut bin);
+            let physics = assert_write_unchanged::<PhysicsFighting>(&mut bin, offset);
+            assert!(copy_object(&mut bin, &physics) == physics);
+        }
+    }
+
+    #[test]
+    fn moved_objects_are_equal() {
+        let c = Console::PC;
+        let mut bin = Bin::new(test_bin_bytes(c), c).unwrap();
+
+        // Two identical attacks, each with its own identical hitbox
+        let hitboxes = [
+            add_patterned_object::<AttackMoveRegion>(&mut bin),
+            add_patterned_object::<AttackMoveRegion>(&mut bin),
+        ];
+        let attacks = [
+            add_patterned_attack(&mut bin, &hitboxes[..1], 0),
+            add_patterned_attack(&mut bin, &hitboxes[1..], 0),
+        ];
+        assert!(
+            bin.get_object_from_offset::<AttackMoveType>(attacks[0]).unwrap()
+                == bin.get_object_from_offset::<AttackMoveType>(attacks[1]).unwrap()
+        );
+
+        // Two identical spitters, each with its own identical keyframe
+        let spitters = [
+            add_patterned_spitter(&mut bin, 1),
+            add_patterned_spitter(&mut bin, 1),
+        ];
+        assert!(
+            bin.get_object_from_offset::<Spitter>(spitters[0]).unwrap()
+                == bin.get_object_from_offset::<Spitter>(spitters[1]).unwrap()
+        );
+    }
+
+    #[test]
+    fn copy_spitter_leaves_source_unchanged() {
+        let c = Console::PC;
+        let mut bin = Bin::new(test_bin_bytes(c), c).unwrap();
+        let source = add_patterned_spitter(&mut bin, 1);
+        let other = add_patterned_spitter(&mut bin, 1);
+        let mut spitter = bin.get_object_from_offset::<Spitter>(source).unwrap();
+        spitter.unknown_004 = 999;
+        spitter.keyframes[0].unknown_00c = 999;
+
+        // The keyframes are written over those of the other spitter
+        bin.overwrite_object(other, &spitter).unwrap();
+        let copied = bin.get_object_from_offset::<Spitter>(source).unwrap();
+        assert_ne!(copied.unknown_004, 999);
+        assert_ne!(copied.keyframes[0].unknown_00c, 999);
+        let copied = bin.get_object_from_offset::<Spitter>(other).unwrap();
+        assert_eq!(copied.unknown_004, 999);
+        assert_eq!(copied.keyframes[0].unknown_00c, 999);
+
+        // A spitter with a different number of keyframes is not written
+        let larger = add_patterned_spitter(&mut bin, 2);
+        let larger = bin.get_object_from_offset::<Spitter>(larger).unwrap();
+        let original = bin.raw().to_vec();
+        match bin.overwrite_object(source, &larger) {
+            Err(Error::ClassDeserialiseError(classes::Error::KeyframeCountMismatch {
+                keyframes: 2,
+                offsets: 1,
+            })) => {}
+            _ => panic!("Expected a keyframe count mismatch"),
+        }
+        assert_eq!(bin.raw(), &original[..]);
+    }
+
+    #[test]
+    fn spitter_resolved_keyframes() {
+        for c in &[Console::PC, Console::Gamecube] {
+            let mut bin = Bin::new(test_bin_bytes(*c), *c).unwrap();
+            let spitter = add_patterned_object::<Spitter>(&mut bin);
+            let keyframes = [
+                add_patterned_object::<SpitterKeyframe>(&mut bin),
+                add_patterned_object::<SpitterKeyframe>(&mut bin),
+            ];
+
+            // An event sequence running the event at +50
+            let mut event = vec![0x00; EventSequence::size()];
+            event.splice(0x00..0x04, c.write_u32(EventSequence::hash()).unwrap());
+            let event = bin.append_data(&event).unwrap();
+            let events = bin.append_data(&c.write_u32(0x50).unwrap()).unwrap();
+            let list: Vec<u8> = keyframes
+                .iter()
+                .flat_map(|k| c.write_u32(*k).unwrap())
+                .collect();
+            let list = bin.append_data(&list).unwrap();
+
+            let mut set = |offset: u32, at: usize, value: u32| {
+                let begin = Bin::header_length() + offset as usize + at;
+                bin.raw.splice(begin..begin + 4, c.write_u32(value).unwrap());
+            };
+            set(event, 0x04, events);
+            set(event, 0x08, 1);
+            set(spitter, 0x20, list);
+            set(spitter, 0x24, 2);
+            set(keyframes[0], 0xBC, event);
+            set(keyframes[1], 0xBC, 0);
+
+            let resolved = bin
+                .get_object_from_offset::<Spitter>(spitter)
+                .unwrap()
+                .resolved_keyframes(&bin)
+                .unwrap();
+            assert_eq!(resolved.len(), 2);
+            assert!(resolved[0].0.event == resolved[0].1);
+            assert_eq!(resolved[0].1.as_ref().unwrap().event_offsets, vec![0x50]);
+            assert!(resolved[1].1.is_none());
+
+            // The keyframes are written back to where they were read from
+            assert_write_unchanged::<Spitter>(&mut bin, spitter);
+        }
+    }
+
+    #[test]
+    fn ps2_offsets() {
+        // The PS2 release stores its .bin files in the same way as the PC
+        let raw = test_bin_bytes(Console::PS2);
+        assert_eq!(raw, test_bin_bytes(Console::PC));
+
+        let mut bin = Bin::new(raw, Console::PS2).unwrap();
+        let names: Vec<(String, u32)> = bin
+            .named_objects()
+            .map(|(name, object)| (name, object.offset))
+            .collect();
+        assert_eq!(names, vec![(String::new(), 0x00), (String::from("hitbox"), 0x50)]);
+        let hitbox = bin.get_object_from_offset::<AttackMoveRegion>(0x50).unwrap();
+        assert_eq!(hitbox.delay, 0.5);
+        assert_eq!(hitbox.arc, 1.0);
+        assert_eq!(hitbox.radius, 2.0);
+
+        let offset = add_patterned_object::<AttackMoveRegion>(&mut bin);
+        assert_write_unchanged::<AttackMoveRegion>(&mut bin, offset);
+        assert!(bin.validate().is_empty());
+    }
+
+    #[test]
+    fn add_object_unencodable_name() {
+        let c = Console::PC;
+        let mut bin = Bin::new(test_bin_bytes(c), c).unwrap();
+        let original = bin.raw().to_vec();
+        let end = Bin::section_descriptions_offset(bin.header) as usize;
+        match bin.add_object("hitbox_\u{263A}", &AttackMoveRegion::default()) {
+            Err(Error::StringError { offset, .. }) => assert_eq!(offset, end),
+            _ => panic!("Expected a string error"),
+        }
+        assert_eq!(bin.raw(), &original[..]);
+        assert_eq!(bin.objects().len(), 2);
+    }
+
+    #[test]
+    fn add_object_write_error() {
+        let c = Console::PC;
+        let mut bin = Bin::new(test_bin_bytes(c), c).unwrap();
+        let original = bin.raw().to_vec();
+
+        // The new object has no hitboxes for the attack to write over
+        let attack = AttackMoveTypeBuilder::new("new_atk")
+            .hitbox(AttackMoveRegion::default())
+            .build();
+        match bin.add_object("new", &attack) {
+            Err(Error::ClassDeserialiseError(classes::Error::HitboxCountMismatch { .. })) => {}
+            _ => panic!("Expected a hitbox count mismatch"),
+        }
+        assert_eq!(bin.raw(), &original[..]);
+        assert_eq!(bin.objects().len(), 2);
+        assert!(bin.validate().is_empty());
+    }
+
+    #[test]
+    fn with_registry() {
+        let c = Console::PC;
+        let mut raw = test_bin_bytes(c);
+        let hash = 0x1F2E3D4C;
+        raw.splice(0x90..0x94, c.write_u32(hash).unwrap());
+        assert!(Bin::new(raw.clone(), c).is_err());
+
+        let mut registry = ClassRegistry::new();
+        registry.register(hash, "Game::MyNewClass");
+        let bin = Bin::with_registry(raw, c, registry).unwrap();
+        let names: Vec<(String, &str)> = bin
+            .named_objects()
+            .map(|(name, object)| (name, object.name.as_str()))
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                (String::new(), "gf::DB"),
+                (String::from("hitbox"), "Game::MyNewClass")
+            ]
+        );
+        assert!(bin.validate().is_empty());
     }
 }
@@ -0,0 +1,120 @@
+use std::convert::TryInto;
+
+/// The size of the magic bytes and header at the start of every DDS file
+const HEADER_SIZE: usize = 0x80;
+
+/// Flag set within the DDS header when the mipmap count field is valid
+const DDSD_MIPMAPCOUNT: u32 = 0x20000;
+
+/// Flag set within the DDS pixel format when the FourCC field is valid
+const DDPF_FOURCC: u32 = 0x04;
+
+/// Information about a [DirectDraw Surface](https://en.wikipedia.org/wiki/DirectDraw_Surface)
+/// texture, read from its header.
+///
+/// The PC and Xbox releases of the game store their textures as DDS files.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DdsInfo {
+    /// The width of the texture, in pixels
+    pub width: u32,
+
+    /// The height of the texture, in pixels
+    pub height: u32,
+
+    /// The number of mipmaps in the texture, including the full-size image
+    pub mip_count: u32,
+
+    /// The FourCC code naming the compression format, such as `DXT1` or
+    /// `DXT5`. None if the texture is uncompressed.
+    pub four_cc: Option<[u8; 4]>,
+}
+
+impl DdsInfo {
+    /// Returns the information from the header of the DDS file in `data`, or
+    /// None if `data` does not begin with a DDS header.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::fs;
+    /// use shrek_superslam::files::DdsInfo;
+    ///
+    /// let info = DdsInfo::from_bytes(&fs::read("bk_cape.dds").unwrap()).unwrap();
+    /// println!("{}x{}", info.width, info.height);
+    /// ```
+    pub fn from_bytes(data: &[u8]) -> Option<DdsInfo> {
+        if data.len() < HEADER_SIZE || &data[0x00..0x04] != b"DDS " || le_u32(data, 0x04) != 0x7C {
+            return None;
+        }
+
+        // The header is always little-endian, even for the Xbox release
+        let flags = le_u32(data, 0x08);
+        let pixel_format_flags = le_u32(data, 0x50);
+        Some(DdsInfo {
+            height: le_u32(data, 0x0C),
+            width: le_u32(data, 0x10),
+            mip_count: match flags & DDSD_MIPMAPCOUNT {
+                0 => 1,
+                _ => le_u32(data, 0x1C).max(1),
+            },
+            four_cc: match pixel_format_flags & DDPF_FOURCC {
+                0 => None,
+                _ => data[0x54..0x58].try_into().ok(),
+            },
+        })
+    }
+}
+
+/// Read a little-endian u32 from the given `offset` within `data`.
+///
+/// # Parameters
+///
+/// - `data`: The bytes to read from
+/// - `offset`: The offset of the value within `data`
+///
+/// # Returns
+///
+/// The value at the offset.
+fn le_u32(data: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Creates the header of a 256x128 DXT5 texture with 9 mipmaps.
+    fn dxt5_header() -> Vec<u8> {
+        let mut data = vec![0x00; HEADER_SIZE];
+        data.splice(0x00..0x04, b"DDS ".iter().cloned());
+        data.splice(0x04..0x08, 0x7Cu32.to_le_bytes().iter().cloned());
+        data.splice(0x08..0x0C, 0x000A1007u32.to_le_bytes().iter().cloned());
+        data.splice(0x0C..0x10, 128u32.to_le_bytes().iter().cloned());
+        data.splice(0x10..0x14, 256u32.to_le_bytes().iter().cloned());
+        data.splice(0x1C..0x20, 9u32.to_le_bytes().iter().cloned());
+        data.splice(0x4C..0x50, 0x20u32.to_le_bytes().iter().cloned());
+        data.splice(0x50..0x54, DDPF_FOURCC.to_le_bytes().iter().cloned());
+        data.splice(0x54..0x58, b"DXT5".iter().cloned());
+        data
+    }
+
+    #[test]
+    fn dds_info() {
+        assert_eq!(
+            DdsInfo::from_bytes(&dxt5_header()),
+            Some(DdsInfo {
+                width: 256,
+                height: 128,
+                mip_count: 9,
+                four_cc: Some(*b"DXT5"),
+            })
+        );
+    }
+
+    #[test]
+    fn dds_info_not_dds() {
+        assert_eq!(DdsInfo::from_bytes(b"GCNT"), None);
+        assert_eq!(DdsInfo::from_bytes(&[0x00; HEADER_SIZE]), None);
+        assert_eq!(DdsInfo::from_bytes(&dxt5_header()[..0x7F]), None);
+    }
+}
@@ -1,6 +1,8 @@
 //! Module containing parsers for the various file types within the decompressed
 //! Shrek SuperSlam game files.
 mod bin;
+mod dds;
 mod texpack;
-pub use bin::{Bin, BinObject};
-pub use texpack::{Texpack, TexpackEntryType};
+pub use bin::{Bin, BinHeaderInfo, BinObject, BinValidationIssue};
+pub use dds::DdsInfo;
+pub use texpack::{Texpack, TexpackEntryInfo, TexpackEntryType, TexpackFile};
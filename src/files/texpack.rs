@@ -1,19 +1,18 @@
 use std::cmp::Ordering;
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::Path;
 
-use encoding::all::ISO_8859_1;
-use encoding::{DecoderTrap, Encoding};
-use itertools::Itertools;
-
 use crate::console::Console;
 use crate::errors::Error;
+use crate::files::DdsInfo;
 use crate::hash::hash;
 
 /// The different types of entry within a texpack
-#[derive(Copy, Clone, PartialEq, PartialOrd)]
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
 pub enum TexpackEntryType {
-    /// An actual texture file - DDS on PC, GCT on Gamecube
+    /// An actual texture file - DDS on PC and Xbox, GCT on Gamecube, TM2 on
+    /// PS2
     Texture,
 
     /// Plain text file that contain lists of texture filenames, used to
@@ -21,6 +20,50 @@ pub enum TexpackEntryType {
     Tga,
 }
 
+impl TexpackEntryType {
+    /// Determine the type of the file with the given `data`, using the magic
+    /// bytes it begins with.
+    ///
+    /// The magic bytes of every console's texture format are recognised, so
+    /// that a texture is not mistaken for a list of textures when it is added
+    /// to a texpack for a different console.
+    ///
+    /// # Parameters
+    ///
+    /// - `data`: The contents of the file
+    ///
+    /// # Returns
+    ///
+    /// `Texture` if the file begins with the magic bytes of a DDS, GCT or TM2
+    /// texture, otherwise `Tga`.
+    fn detect(data: &[u8]) -> TexpackEntryType {
+        const TEXTURE_MAGICS: [&[u8]; 3] = [b"DDS ", b"GCNT", b"TIM2"];
+
+        match data.get(0x00..0x04) {
+            Some(header) if TEXTURE_MAGICS.contains(&header) => TexpackEntryType::Texture,
+            _ => TexpackEntryType::Tga,
+        }
+    }
+
+    /// Returns the entry type for the given `value` of the type field of a
+    /// texpack entry.
+    fn from_u32(value: u32) -> Result<TexpackEntryType, Error> {
+        match value {
+            0x00 => Ok(TexpackEntryType::Texture),
+            0x02 => Ok(TexpackEntryType::Tga),
+            _ => Err(Error::UnknownTexpackEntryTypeError { value }),
+        }
+    }
+
+    /// Returns the value of the type field of a texpack entry for this type.
+    fn to_u32(self) -> u32 {
+        match self {
+            TexpackEntryType::Texture => 0x00,
+            TexpackEntryType::Tga => 0x02,
+        }
+    }
+}
+
 /// Structure defining the header of a texpack
 struct TexpackHeader {
     /// The number of entries within the texpack
@@ -42,9 +85,27 @@ impl TexpackHeader {
         0x10
     }
 
+    /// Returns the magic bytes that begin a texpack from the `console`
+    /// version of the game.
+    ///
+    /// The 'KPXT' magic bytes are backwards for Gamecube texpacks.
+    fn magic(console: Console) -> &'static [u8; 4] {
+        match console {
+            Console::Gamecube => b"TXPK",
+            _ => b"KPXT",
+        }
+    }
+
     /// Construct a new TexpackHeader from the passed `raw` bytes of a file
     /// from the `console` version of the game.
     fn from_bytes(raw: &[u8], console: Console) -> Result<TexpackHeader, Error> {
+        let magic = &raw[0x00..0x04];
+        if magic != TexpackHeader::magic(console) {
+            return Err(Error::InvalidTexpackMagicError {
+                magic: magic.to_vec(),
+            });
+        }
+
         Ok(TexpackHeader {
             entries: console.read_u32(&raw[0x08..0x0C])?,
             console,
@@ -53,12 +114,8 @@ impl TexpackHeader {
 
     // Construct the bytes for the texpack header
     fn to_bytes(&self) -> Result<Vec<u8>, Error> {
-        // Start with the constant 'KPXT' magic bytes. This constant is backwards
-        // for Gamecube texpacks.
-        let mut header_bytes = match self.console {
-            Console::Gamecube => vec![b'T', b'X', b'P', b'K'],
-            _ => vec![b'K', b'P', b'X', b'T'],
-        };
+        // Start with the constant magic bytes
+        let mut header_bytes = TexpackHeader::magic(self.console).to_vec();
 
         // Add the fields
         header_bytes.extend(self.console.write_u32(1)?);
@@ -94,6 +151,7 @@ struct TexpackEntry {
 impl TexpackEntry {
     /// Create a new TexpackEntry structure with the fields manually set.
     fn new(
+        hash: u32,
         filename: String,
         offset: u32,
         size: u32,
@@ -101,16 +159,7 @@ impl TexpackEntry {
         console: Console,
     ) -> TexpackEntry {
         TexpackEntry {
-            // Due to long filenames being truncated, we cannot accurately recreate
-            // the hashes of names that have been truncated.. We therefore have to
-            // special case these names, thankfully there are not too many.
-            hash: match filename.as_str() {
-                "levelrender_fairytalevillag" => hash(&"levelrender_fairytalevillage"),
-                "levelrender_gepettosworksho" => hash(&"levelrender_gepettosworkshop"),
-                "levelrender_gingerbreadhous" => hash(&"levelrender_gingerbreadhouse"),
-                "loadingscreen_gingerbreadho" => hash(&"loadingscreen_gingerbreadhouse"),
-                _ => hash(&filename),
-            },
+            hash,
             filename,
             offset,
             size,
@@ -123,17 +172,10 @@ impl TexpackEntry {
     /// texpack file from the given `console`.
     fn from_bytes(raw: &[u8], console: Console) -> Result<TexpackEntry, Error> {
         let hash = console.read_u32(&raw[0x00..0x04])?;
-        let filename = ISO_8859_1
-            .decode(&raw[0x04..0x20].to_vec(), DecoderTrap::Strict)?
-            .trim_end_matches(char::from(0))
-            .to_owned();
+        let filename = console.read_str(&raw[0x04..0x20])?;
         let offset = console.read_u32(&raw[0x20..0x24])?;
         let size = console.read_u32(&raw[0x24..0x28])?;
-        let filetype = match console.read_u32(&raw[0x28..0x2C])? {
-            0x00 => TexpackEntryType::Texture,
-            0x02 => TexpackEntryType::Tga,
-            _ => panic!("uh oh!"),
-        };
+        let filetype = TexpackEntryType::from_u32(console.read_u32(&raw[0x28..0x2C])?)?;
 
         Ok(TexpackEntry {
             hash,
@@ -160,9 +202,9 @@ impl TexpackEntry {
         // Write in the name. It is always exactly 28 bytes - shorter
         // names are padded with zeroes, longer names are truncated.
         let mut name_bytes = self.filename.as_bytes().to_owned();
-        match self.filename.len().cmp(&0x1C) {
-            Ordering::Less => name_bytes.extend(vec![0x00; 0x1B - self.filename.len()]),
-            Ordering::Greater => name_bytes.truncate(0x1B),
+        match self.filename.len().cmp(&MAX_NAME_LENGTH) {
+            Ordering::Less => name_bytes.extend(vec![0x00; MAX_NAME_LENGTH - self.filename.len()]),
+            Ordering::Greater => name_bytes.truncate(MAX_NAME_LENGTH),
             _ => {}
         };
         entry_bytes.extend(&name_bytes);
@@ -171,10 +213,7 @@ impl TexpackEntry {
         // Write the remaining fields
         entry_bytes.extend(self.console.write_u32(self.offset)?);
         entry_bytes.extend(self.console.write_u32(self.size)?);
-        match self.filetype {
-            TexpackEntryType::Texture => entry_bytes.extend(self.console.write_u32(0x00)?),
-            TexpackEntryType::Tga => entry_bytes.extend(self.console.write_u32(0x02)?),
-        };
+        entry_bytes.extend(self.console.write_u32(self.filetype.to_u32())?);
         entry_bytes.extend(&[0x00, 0x00, 0x00, 0x00]);
 
         Ok(entry_bytes)
@@ -184,8 +223,14 @@ impl TexpackEntry {
 /// Structure for the files within a texpack
 pub struct TexpackFile {
     /// The filename of the file, without the extension (which differs by platform)
+    ///
+    /// Names longer than 27 characters are truncated within the texpack, so
+    /// this is only the full name if the file was added with it.
     filename: String,
 
+    /// The hash of the full filename
+    hash: u32,
+
     /// The console the file comes from
     console: Console,
 
@@ -200,12 +245,14 @@ impl TexpackFile {
     /// Construct a new TexpackFile structure.
     fn new(
         filename: String,
+        hash: u32,
         filetype: TexpackEntryType,
         data: &[u8],
         console: Console,
     ) -> TexpackFile {
         TexpackFile {
             filename,
+            hash,
             console,
             filetype,
             data: data.to_vec(),
@@ -225,6 +272,88 @@ impl TexpackFile {
         }
     }
 
+    /// Replaces the contents of the file with the given `data`, keeping its
+    /// name.
+    ///
+    /// The type of the file is detected again from the new `data`, in the
+    /// same way as [`Texpack::add_file`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::fs;
+    /// use std::path::Path;
+    /// use shrek_superslam::Console;
+    /// use shrek_superslam::files::Texpack;
+    ///
+    /// let mut texpack = Texpack::from_file(Path::new("data\\spawns\\players\\shrek\\object.texpack"), Console::PC).unwrap();
+    /// if let Some(file) = texpack.get_file_mut("bk_cape") {
+    ///     file.set_data(&fs::read("my_cape.dds").unwrap());
+    /// }
+    /// ```
+    pub fn set_data(&mut self, data: &[u8]) {
+        self.filetype = TexpackEntryType::detect(data);
+        self.data = data.to_vec();
+    }
+
+    /// Returns the hash of the file's full name, which identifies the file
+    /// within the game.
+    pub fn hash(&self) -> u32 {
+        self.hash
+    }
+
+    /// Returns the console the file comes from.
+    ///
+    /// # Remarks
+    ///
+    /// Xbox textures begin with the same DDS header as PC textures, and so are
+    /// given the same .dds extension. However, the pixel data of uncompressed
+    /// Xbox textures can be swizzled for the Xbox GPU, so a texture should not
+    /// be assumed to display correctly on the other platform without checking
+    /// which console it is from.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::path::Path;
+    /// use shrek_superslam::Console;
+    /// use shrek_superslam::files::Texpack;
+    ///
+    /// let texpack = Texpack::from_file(Path::new("data\\spawns\\players\\shrek\\object.texpack"), Console::Xbox).unwrap();
+    /// for file in texpack.files() {
+    ///     if file.console() == Console::Xbox && file.dds_info().is_some() {
+    ///         println!("{} is an Xbox DDS texture", file.filename());
+    ///     }
+    /// }
+    /// ```
+    pub fn console(&self) -> Console {
+        self.console
+    }
+
+    /// Returns the dimensions and format of the file, if it is a DDS texture
+    /// from the PC or Xbox version of the game.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::path::Path;
+    /// use shrek_superslam::Console;
+    /// use shrek_superslam::files::Texpack;
+    ///
+    /// let texpack = Texpack::from_file(Path::new("data\\spawns\\players\\shrek\\object.texpack"), Console::PC).unwrap();
+    /// for file in texpack.files() {
+    ///     if let Some(info) = file.dds_info() {
+    ///         println!("{}: {}x{}", file.filename(), info.width, info.height);
+    ///     }
+    /// }
+    /// ```
+    pub fn dds_info(&self) -> Option<DdsInfo> {
+        match self.filetype {
+            TexpackEntryType::Texture => DdsInfo::from_bytes(&self.data),
+            TexpackEntryType::Tga => None,
+        }
+    }
+
     /// Return the padded form of the file, for writing back to a texpack file.
     fn padded(&self) -> Vec<u8> {
         let mut padded = self.data.clone();
@@ -288,7 +417,12 @@ impl Texpack {
     /// ```
     pub fn from_bytes(raw: &[u8], console: Console) -> Result<Texpack, Error> {
         // Read the header
-        let header = TexpackHeader::from_bytes(&raw[0x00..0x10], console)?;
+        let header_bytes = raw.get(0x00..TexpackHeader::size()).ok_or_else(|| {
+            Error::InvalidTexpackMagicError {
+                magic: raw.iter().take(4).cloned().collect(),
+            }
+        })?;
+        let header = TexpackHeader::from_bytes(header_bytes, console)?;
 
         // Parse each entry from the header
         let entries: Result<Vec<TexpackEntry>, Error> = (0..header.entries as usize)
@@ -296,6 +430,7 @@ impl Texpack {
                 let begin = (i * TexpackEntry::size()) + 0x10;
                 let end = begin + TexpackEntry::size();
                 TexpackEntry::from_bytes(&raw[begin..end], console)
+                    .map_err(|e| e.at_offset(begin + 0x04))
             })
             .collect();
 
@@ -305,6 +440,7 @@ impl Texpack {
             .map(|e| {
                 TexpackFile::new(
                     e.filename,
+                    e.hash,
                     e.filetype,
                     &raw[e.offset as usize..(e.offset + e.size) as usize],
                     console,
@@ -331,40 +467,231 @@ impl Texpack {
     /// ```
     pub fn from_file(path: &Path, console: Console) -> Result<Texpack, Error> {
         // Read all of the file to a byte array
-        let file_contents = fs::read(&path)?;
+        let file_contents = fs::read(path)?;
 
         // Parse the bytes to a Texpack object
         Texpack::from_bytes(&file_contents, console)
     }
 
-    /// Add a new file with the given `name` and `data`, of the given `kind` to
-    /// the texpack.
+    /// Creates a new Texpack structure for the given `console` from the files
+    /// within the directory at `dir`, such as one created by extracting a
+    /// texpack.
+    ///
+    /// Each file is added with [`Texpack::add_file`], named after the file
+    /// without its extension, in order of filename. Subdirectories are
+    /// ignored, and an empty directory gives an empty texpack.
+    ///
+    /// Files listed in the `hashes.json` file written by
+    /// [`Texpack::to_directory`] are instead added with
+    /// [`Texpack::add_file_with_hash`], so that files whose names were
+    /// truncated within the texpack keep the hash of their full name.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory or any file within it cannot be
+    /// read, or if the `hashes.json` file is malformed.
     ///
     /// # Example
     ///
     /// ```no_run
+    /// use std::path::Path;
     /// use shrek_superslam::Console;
     /// use shrek_superslam::files::Texpack;
     ///
-    /// let mut texpack = Texpack::new(Console::PC);
-    /// texpack.add_file("data\\test.dds".to_string(), &Vec::new());
+    /// let texpack = Texpack::from_directory(Path::new("object.texpack-extracted"), Console::PC).unwrap();
+    /// let texpack_bytes = texpack.to_bytes().unwrap();
     /// ```
-    pub fn add_file(&mut self, name: String, data: &[u8]) {
-        // Determine the filetype based on the console version and the header
-        let header = &data[0x00..0x04];
-        let filetype = if (self.console == Console::Gamecube && header == [0x47, 0x43, 0x4E, 0x54])
-            || ((self.console == Console::PC || self.console == Console::Xbox)
-                && header == [0x44, 0x44, 0x53, 0x20])
-            || (self.console == Console::PS2 && header == [0x54, 0x49, 0x4D, 0x32])
-        {
-            TexpackEntryType::Texture
+    pub fn from_directory(dir: &Path, console: Console) -> Result<Texpack, Error> {
+        let mut paths = vec![];
+        for entry in fs::read_dir(dir)? {
+            let entry = entry?;
+            if entry.file_type()?.is_file() && entry.file_name() != HASHES_FILENAME {
+                paths.push(entry.path());
+            }
+        }
+        paths.sort();
+
+        let hashes_path = dir.join(HASHES_FILENAME);
+        let hashes: BTreeMap<String, u32> = if hashes_path.is_file() {
+            serde_json::from_slice(&fs::read(&hashes_path)?)?
         } else {
-            TexpackEntryType::Tga
+            BTreeMap::new()
         };
 
+        let mut texpack = Texpack::new(console);
+        for path in paths {
+            let name = path
+                .file_stem()
+                .map(|s| s.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            let data = fs::read(&path)?;
+            let filename = path
+                .file_name()
+                .map(|s| s.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            match hashes.get(&filename) {
+                Some(name_hash) => texpack.add_file_with_hash(name, *name_hash, &data),
+                None => texpack.add_file(name, &data),
+            };
+        }
+
+        Ok(texpack)
+    }
+
+    /// Writes each file within the texpack to the directory at `dir`, creating
+    /// the directory if it does not exist.
+    ///
+    /// The names of files within a texpack are truncated to 27 characters, so
+    /// the hash of a truncated name cannot be calculated again from the name.
+    /// The hash of each such file is written to a `hashes.json` file within
+    /// the directory, which [`Texpack::from_directory`] reads back.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory or any file within it cannot be
+    /// written.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::path::Path;
+    /// use shrek_superslam::Console;
+    /// use shrek_superslam::files::Texpack;
+    ///
+    /// let texpack = Texpack::from_file(Path::new("data\\spawns\\players\\shrek\\object.texpack"), Console::PC).unwrap();
+    /// texpack.to_directory(Path::new("object.texpack-extracted")).unwrap();
+    /// ```
+    pub fn to_directory(&self, dir: &Path) -> Result<(), Error> {
+        fs::create_dir_all(dir)?;
+
+        let mut hashes = BTreeMap::new();
+        for file in &self.files {
+            fs::write(dir.join(file.filename()), &file.data)?;
+            if file.hash != name_hash(&file.filename) {
+                hashes.insert(file.filename(), file.hash);
+            }
+        }
+        if !hashes.is_empty() {
+            fs::write(
+                dir.join(HASHES_FILENAME),
+                serde_json::to_string_pretty(&hashes)?,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Add a new file with the given `name` and `data` to the texpack,
+    /// returning the type of file it was detected as.
+    ///
+    /// The type is detected from the magic bytes at the start of `data`: any
+    /// DDS, GCT or TM2 texture is a `Texture`, whichever console the texpack
+    /// is for, and anything else is a `Tga` list of textures. The file is
+    /// identified by the hash of `name`, or by the hash of the full name if
+    /// `name` is a truncated form of one of the long names used in the game.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use shrek_superslam::Console;
+    /// use shrek_superslam::files::Texpack;
+    ///
+    /// let mut texpack = Texpack::new(Console::PC);
+    /// texpack.add_file("data\\test.dds".to_string(), &Vec::new());
+    /// ```
+    pub fn add_file(&mut self, name: String, data: &[u8]) -> TexpackEntryType {
+        let name_hash = name_hash(&name);
+        self.add_file_with_hash(name, name_hash, data)
+    }
+
+    /// Add a new file with the given `name`, `hash` and `data` to the
+    /// texpack, returning the type of file it was detected as.
+    ///
+    /// Use this rather than [`Texpack::add_file`] when `name` is a name that
+    /// was truncated within a texpack and is not one of the long names known
+    /// to be in the game, and so does not hash to the hash of the file's full
+    /// name. The type is detected in the same way as
+    /// [`Texpack::add_file`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use shrek_superslam::Console;
+    /// use shrek_superslam::files::Texpack;
+    /// use shrek_superslam::hash;
+    ///
+    /// let mut texpack = Texpack::new(Console::PC);
+    /// let full_hash = hash("levelrender_fairytalevillage");
+    /// texpack.add_file_with_hash("levelrender_fairytalevillag".to_string(), full_hash, &Vec::new());
+    /// assert_eq!(texpack.files()[0].hash(), full_hash);
+    /// ```
+    pub fn add_file_with_hash(&mut self, name: String, hash: u32, data: &[u8]) -> TexpackEntryType {
+        // Determine the filetype based on the header
+        let filetype = TexpackEntryType::detect(data);
+
         // Add the new file to the list of files
         self.files
-            .push(TexpackFile::new(name, filetype, data, self.console));
+            .push(TexpackFile::new(name, hash, filetype, data, self.console));
+
+        filetype
+    }
+
+    /// Returns the file with the given `name`, if it is within the texpack.
+    ///
+    /// The extension of `name`, if any, is ignored, so "bk_cape" and
+    /// "bk_cape.dds" both find the same file.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::path::Path;
+    /// use shrek_superslam::Console;
+    /// use shrek_superslam::files::Texpack;
+    ///
+    /// let texpack = Texpack::from_file(Path::new("data\\spawns\\players\\shrek\\object.texpack"), Console::PC).unwrap();
+    /// if let Some(file) = texpack.get_file("bk_cape") {
+    ///     println!("{}: {} bytes", file.filename(), file.data.len());
+    /// }
+    /// ```
+    pub fn get_file(&self, name: &str) -> Option<&TexpackFile> {
+        let stem = file_stem(name);
+        self.files.iter().find(|f| f.filename == stem)
+    }
+
+    /// Returns a mutable reference to the file with the given `name`, if it
+    /// is within the texpack.
+    ///
+    /// The extension of `name`, if any, is ignored, in the same way as
+    /// [`Texpack::get_file`].
+    pub fn get_file_mut(&mut self, name: &str) -> Option<&mut TexpackFile> {
+        let stem = file_stem(name);
+        self.files.iter_mut().find(|f| f.filename == stem)
+    }
+
+    /// Removes the file with the given `name` from the texpack, returning
+    /// true if the file was found and removed.
+    ///
+    /// The extension of `name`, if any, is ignored, so "bk_cape" and
+    /// "bk_cape.dds" both remove the same file.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::fs;
+    /// use std::path::Path;
+    /// use shrek_superslam::Console;
+    /// use shrek_superslam::files::Texpack;
+    ///
+    /// // Replace a single texture within the texpack
+    /// let mut texpack = Texpack::from_file(Path::new("data\\spawns\\players\\shrek\\object.texpack"), Console::PC).unwrap();
+    /// texpack.remove_file("bk_cape");
+    /// texpack.add_file("bk_cape".to_string(), &fs::read("bk_cape.dds").unwrap());
+    /// ```
+    pub fn remove_file(&mut self, name: &str) -> bool {
+        let stem = file_stem(name);
+        let count = self.files.len();
+        self.files.retain(|f| f.filename != stem);
+        self.files.len() != count
     }
 
     /// Returns the list of files within the texpack
@@ -404,47 +731,439 @@ impl Texpack {
         let header = TexpackHeader::new(self.files.len() as u32, self.console);
         texpack_bytes.extend(header.to_bytes()?);
 
-        // Calculations to determine where the offset of each texture file
-        // will begin within the texpack, needed for each metadata entry.
-        let entries_start = TexpackHeader::size();
-        let entries_end = entries_start + (self.files.len() * TexpackEntry::size());
-        let header_padding_size = 0x1000 - (entries_end % 0x1000);
-        let files_start = entries_end + header_padding_size;
-        let mut cumulative_offset = files_start;
-
-        // Create each entry to point to the actual files.
-        //
-        // The text file entries need to be after the files they reference,
-        // so we sort the files by their type so that the text files all come
-        // last.
-        for file in self.files.iter().sorted_by(|a, b| {
-            a.filetype
-                .partial_cmp(&b.filetype)
-                .unwrap_or(Ordering::Equal)
-        }) {
+        // Create each entry to point to the actual files
+        let ordered_files = self.ordered_files();
+        for (file, info) in ordered_files.iter().zip(self.entries()) {
             let entry = TexpackEntry::new(
+                info.hash,
                 file.filename.clone(),
-                cumulative_offset as u32,
-                file.data.len() as u32,
-                file.filetype,
+                info.offset,
+                info.size,
+                info.filetype,
                 self.console,
             );
             texpack_bytes.extend(&entry.to_bytes()?);
-            cumulative_offset += file.padded_size();
         }
 
         // Add the padding between the header and the files
-        texpack_bytes.extend(&vec![0xEE; header_padding_size]);
+        texpack_bytes.extend(&vec![0xEE; self.header_padding_size()]);
 
         // Add the contents of each file
-        for file in self.files.iter().sorted_by(|a, b| {
-            a.filetype
-                .partial_cmp(&b.filetype)
-                .unwrap_or(Ordering::Equal)
-        }) {
+        for file in &ordered_files {
             texpack_bytes.extend(&file.padded());
         }
 
         Ok(texpack_bytes)
     }
+
+    /// Returns the entry for each file that is written to the texpack by
+    /// [`Texpack::to_bytes`], in the order they are written, describing where
+    /// each file is placed.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::path::Path;
+    /// use shrek_superslam::Console;
+    /// use shrek_superslam::files::Texpack;
+    ///
+    /// let texpack = Texpack::from_file(Path::new("data\\spawns\\players\\shrek\\object.texpack"), Console::PC).unwrap();
+    /// for entry in texpack.entries() {
+    ///     println!("{} at 0x{:X}: {} bytes", entry.filename, entry.offset, entry.padded_size);
+    /// }
+    /// ```
+    pub fn entries(&self) -> Vec<TexpackEntryInfo> {
+        // Files begin after the header and entries, padded to the next 0x1000
+        let entries_end = TexpackHeader::size() + (self.files.len() * TexpackEntry::size());
+        let mut offset = entries_end + self.header_padding_size();
+
+        self.ordered_files()
+            .into_iter()
+            .map(|file| {
+                let entry = TexpackEntryInfo {
+                    filename: file.filename(),
+                    hash: file.hash,
+                    offset: offset as u32,
+                    size: file.data.len() as u32,
+                    padded_size: file.padded_size() as u32,
+                    filetype: file.filetype,
+                };
+                offset += file.padded_size();
+                entry
+            })
+            .collect()
+    }
+
+    /// Returns the files of the texpack in the order they are written.
+    ///
+    /// The text file entries need to be after the files they reference, so
+    /// the text files are moved to the end. Otherwise the files keep the
+    /// order they were read or added in, so that rewriting an unmodified
+    /// texpack gives back the same bytes.
+    fn ordered_files(&self) -> Vec<&TexpackFile> {
+        self.files
+            .iter()
+            .filter(|f| f.filetype == TexpackEntryType::Texture)
+            .chain(
+                self.files
+                    .iter()
+                    .filter(|f| f.filetype == TexpackEntryType::Tga),
+            )
+            .collect()
+    }
+
+    /// Returns the size of the padding between the entries and the first
+    /// file in the texpack.
+    fn header_padding_size(&self) -> usize {
+        let entries_end = TexpackHeader::size() + (self.files.len() * TexpackEntry::size());
+        0x1000 - (entries_end % 0x1000)
+    }
+}
+
+/// Description of where a file is placed within a texpack, as returned by
+/// [`Texpack::entries`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct TexpackEntryInfo {
+    /// The filename of the file, including extension
+    pub filename: String,
+
+    /// The hash of the full filename
+    pub hash: u32,
+
+    /// The offset of the file within the texpack
+    pub offset: u32,
+
+    /// The size of the file in bytes
+    pub size: u32,
+
+    /// The size of the file in bytes, including the padding after it
+    pub padded_size: u32,
+
+    /// The type of the file
+    pub filetype: TexpackEntryType,
+}
+
+/// The length that filenames are truncated to within a texpack
+const MAX_NAME_LENGTH: usize = 0x1B;
+
+/// The full names of the files within the game's texpacks whose names are
+/// longer than [`MAX_NAME_LENGTH`], and so are truncated within the texpack.
+const LONG_NAMES: &[&str] = &[
+    "levelrender_fairytalevillage",
+    "levelrender_gepettosworkshop",
+    "levelrender_gingerbreadhouse",
+    "loadingscreen_gingerbreadhouse",
+];
+
+/// The name of the file written alongside the files of a texpack by
+/// [`Texpack::to_directory`], recording the hash of each file whose name was
+/// truncated.
+const HASHES_FILENAME: &str = "hashes.json";
+
+/// Calculate the hash of the file with the given `name`, as used by
+/// [`Texpack::add_file`].
+///
+/// # Parameters
+///
+/// - `name`: The name of the file, which may have been truncated
+///
+/// # Returns
+///
+/// The hash of the full name if `name` is a truncated form of a name in
+/// [`LONG_NAMES`], otherwise the hash of `name`.
+fn name_hash(name: &str) -> u32 {
+    if name.len() == MAX_NAME_LENGTH {
+        if let Some(full_name) = LONG_NAMES.iter().find(|full| full.starts_with(name)) {
+            return hash(full_name);
+        }
+    }
+
+    hash(name)
+}
+
+/// Strips the extension from the given texpack file `name`, if it has one.
+///
+/// # Parameters
+///
+/// - `name`: The name of a file within a texpack, with or without extension
+///
+/// # Returns
+///
+/// The name without its extension.
+fn file_stem(name: &str) -> &str {
+    Path::new(name)
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or(name)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const DDS_DATA: [u8; 8] = [0x44, 0x44, 0x53, 0x20, 0x01, 0x02, 0x03, 0x04];
+
+    #[test]
+    fn get_and_remove_file() {
+        let mut texpack = Texpack::new(Console::PC);
+        texpack.add_file("first".to_string(), &DDS_DATA);
+        texpack.add_file("second".to_string(), &DDS_DATA);
+
+        assert!(texpack.get_file("first.dds").is_some());
+        assert!(texpack.remove_file("first.dds"));
+        assert!(!texpack.remove_file("first"));
+        assert!(texpack.get_file("first").is_none());
+
+        assert_eq!(texpack.files().len(), 1);
+        assert_eq!(texpack.get_file("second").unwrap().filename(), "second.dds");
+    }
+
+    #[test]
+    fn set_data() {
+        let mut texpack = Texpack::new(Console::PC);
+        texpack.add_file("first".to_string(), &DDS_DATA);
+
+        let file = texpack.get_file_mut("first").unwrap();
+        file.set_data(b"first0.dds\r\nfirst1.dds");
+        assert_eq!(file.filename(), "first.tga");
+        assert!(file.dds_info().is_none());
+
+        file.set_data(&DDS_DATA);
+        assert_eq!(file.filename(), "first.dds");
+        assert_eq!(file.data, DDS_DATA);
+    }
+
+    #[test]
+    fn roundtrip() {
+        let mut texpack = Texpack::new(Console::PC);
+        texpack.add_file("second".to_string(), &DDS_DATA);
+        texpack.add_file("anim".to_string(), b"second.dds\r\nfirst.dds");
+        texpack.add_file("first".to_string(), &DDS_DATA);
+        let raw = texpack.to_bytes().unwrap();
+
+        let reread = Texpack::from_bytes(&raw, Console::PC).unwrap();
+        let names: Vec<String> = reread.files().iter().map(|f| f.filename()).collect();
+        assert_eq!(names, vec!["second.dds", "first.dds", "anim.tga"]);
+        assert_eq!(reread.to_bytes().unwrap(), raw);
+    }
+
+    #[test]
+    fn roundtrip_ps2() {
+        let mut texpack = Texpack::new(Console::PS2);
+        texpack.add_file("first".to_string(), b"TIM2\x04\x00\x01\x00");
+        texpack.add_file("anim".to_string(), b"first.tm2");
+        let raw = texpack.to_bytes().unwrap();
+
+        // PS2 texpacks are little-endian, the same as PC texpacks
+        let mut pc_texpack = Texpack::new(Console::PC);
+        pc_texpack.add_file("first".to_string(), b"TIM2\x04\x00\x01\x00");
+        pc_texpack.add_file("anim".to_string(), b"first.tm2");
+        assert_eq!(pc_texpack.to_bytes().unwrap(), raw);
+
+        let reread = Texpack::from_bytes(&raw, Console::PS2).unwrap();
+        let names: Vec<String> = reread.files().iter().map(|f| f.filename()).collect();
+        assert_eq!(names, vec!["first.tm2", "anim.tga"]);
+        assert!(reread.get_file("first.tm2").is_some());
+        assert_eq!(reread.to_bytes().unwrap(), raw);
+    }
+
+    #[test]
+    fn roundtrip_xbox() {
+        let mut dds = vec![0x00; 0x80];
+        dds.splice(0x00..0x04, b"DDS ".iter().cloned());
+        dds.splice(0x04..0x08, 0x7Cu32.to_le_bytes().iter().cloned());
+        dds.splice(0x0C..0x10, 0x40u32.to_le_bytes().iter().cloned());
+        dds.splice(0x10..0x14, 0x20u32.to_le_bytes().iter().cloned());
+
+        let mut texpack = Texpack::new(Console::Xbox);
+        assert_eq!(
+            texpack.add_file("first".to_string(), &dds),
+            TexpackEntryType::Texture
+        );
+        texpack.add_file("anim".to_string(), b"first.dds");
+        let raw = texpack.to_bytes().unwrap();
+
+        // Xbox texpacks are laid out in the same way as PC texpacks
+        let mut pc_texpack = Texpack::new(Console::PC);
+        pc_texpack.add_file("first".to_string(), &dds);
+        pc_texpack.add_file("anim".to_string(), b"first.dds");
+        assert_eq!(pc_texpack.to_bytes().unwrap(), raw);
+
+        let reread = Texpack::from_bytes(&raw, Console::Xbox).unwrap();
+        let names: Vec<String> = reread.files().iter().map(|f| f.filename()).collect();
+        assert_eq!(names, vec!["first.dds", "anim.tga"]);
+        let file = reread.get_file("first").unwrap();
+        assert_eq!(file.console(), Console::Xbox);
+        assert_eq!(file.data, dds);
+        let info = file.dds_info().unwrap();
+        assert_eq!((info.width, info.height), (0x20, 0x40));
+        assert_eq!(reread.to_bytes().unwrap(), raw);
+    }
+
+    #[test]
+    fn long_name_hashes() {
+        let long_names = [
+            "levelrender_fairytalevillage",
+            "levelrender_gepettosworkshop",
+            "levelrender_gingerbreadhouse",
+            "loadingscreen_gingerbreadhouse",
+        ];
+        let mut texpack = Texpack::new(Console::PC);
+        for name in &long_names {
+            let truncated = name[..MAX_NAME_LENGTH].to_string();
+            texpack.add_file_with_hash(truncated, hash(name), &DDS_DATA);
+        }
+        texpack.add_file("a_long_name_that_is_not_in_the_game".to_string(), &DDS_DATA);
+        let raw = texpack.to_bytes().unwrap();
+
+        // Each hash is of the full name, and is kept when read back in
+        let reread = Texpack::from_bytes(&raw, Console::PC).unwrap();
+        let hashes: Vec<u32> = reread.files().iter().map(|f| f.hash).collect();
+        let mut expected: Vec<u32> = long_names.iter().map(|n| hash(n)).collect();
+        expected.push(hash("a_long_name_that_is_not_in_the_game"));
+        assert_eq!(hashes, expected);
+        assert_eq!(
+            reread.files()[4].filename(),
+            "a_long_name_that_is_not_in_.dds"
+        );
+        assert_eq!(reread.to_bytes().unwrap(), raw);
+
+        // The hashes are also kept through extracting the files to a directory
+        let dir = std::env::temp_dir().join("shrek-superslam-texpack-long-names");
+        reread.to_directory(&dir).unwrap();
+        let packed = Texpack::from_directory(&dir, Console::PC).unwrap();
+        let mut hashes: Vec<u32> = packed.files().iter().map(|f| f.hash).collect();
+        hashes.sort();
+        expected.sort();
+        assert_eq!(hashes, expected);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn add_file_long_names() {
+        let mut texpack = Texpack::new(Console::PC);
+        for name in LONG_NAMES {
+            texpack.add_file(name[..MAX_NAME_LENGTH].to_string(), &DDS_DATA);
+        }
+
+        let hashes: Vec<u32> = texpack.files().iter().map(|f| f.hash()).collect();
+        let expected: Vec<u32> = LONG_NAMES.iter().map(|n| hash(n)).collect();
+        assert_eq!(hashes, expected);
+    }
+
+    #[test]
+    fn from_directory() {
+        let dir = std::env::temp_dir().join("shrek-superslam-texpack-from-directory");
+        fs::create_dir_all(dir.join("subdirectory")).unwrap();
+        fs::write(dir.join("b.dds"), DDS_DATA).unwrap();
+        fs::write(dir.join("a.tga"), b"b.dds").unwrap();
+
+        let texpack = Texpack::from_directory(&dir, Console::PC).unwrap();
+        let names: Vec<String> = texpack.files().iter().map(|f| f.filename()).collect();
+        assert_eq!(names, vec!["a.tga", "b.dds"]);
+        fs::remove_dir_all(&dir).unwrap();
+
+        fs::create_dir_all(&dir).unwrap();
+        let texpack = Texpack::from_directory(&dir, Console::PC).unwrap();
+        assert!(texpack.files().is_empty());
+        assert!(
+            Texpack::from_bytes(&texpack.to_bytes().unwrap(), Console::PC)
+                .unwrap()
+                .files()
+                .is_empty()
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn detect_filetype() {
+        let magics: [&[u8]; 3] = [b"DDS ", b"GCNT", b"TIM2"];
+        for console in &[Console::Gamecube, Console::PC, Console::PS2, Console::Xbox] {
+            let mut texpack = Texpack::new(*console);
+            for magic in &magics {
+                assert_eq!(
+                    texpack.add_file("texture".to_string(), magic),
+                    TexpackEntryType::Texture
+                );
+            }
+            assert_eq!(
+                texpack.add_file("list".to_string(), b"texture.dds"),
+                TexpackEntryType::Tga
+            );
+            assert_eq!(
+                texpack.add_file("short".to_string(), b"DD"),
+                TexpackEntryType::Tga
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_magic() {
+        let mut texpack = Texpack::new(Console::Gamecube);
+        texpack.add_file("first".to_string(), b"GCNT");
+        let raw = texpack.to_bytes().unwrap();
+        assert_eq!(&raw[0x00..0x04], b"TXPK");
+        assert!(Texpack::from_bytes(&raw, Console::Gamecube).is_ok());
+
+        // A Gamecube texpack read as a PC one has its magic the wrong way
+        // around, and a .bin file has no magic at all
+        for (raw, console) in &[
+            (raw.clone(), Console::PC),
+            (vec![0x00; 0x100], Console::PC),
+            (b"KP".to_vec(), Console::PC),
+        ] {
+            match Texpack::from_bytes(raw, *console) {
+                Err(Error::InvalidTexpackMagicError { magic }) => {
+                    assert_eq!(magic, raw.iter().take(4).cloned().collect::<Vec<u8>>())
+                }
+                _ => panic!("Expected an invalid magic error"),
+            }
+        }
+    }
+
+    #[test]
+    fn entries() {
+        let mut texpack = Texpack::new(Console::PC);
+        texpack.add_file("anim".to_string(), b"first.dds");
+        texpack.add_file("first".to_string(), &[DDS_DATA; 0x201].concat());
+        let raw = texpack.to_bytes().unwrap();
+
+        let entries = texpack.entries();
+        assert_eq!(
+            entries,
+            vec![
+                TexpackEntryInfo {
+                    filename: "first.dds".to_string(),
+                    hash: hash("first"),
+                    offset: 0x1000,
+                    size: 0x1008,
+                    padded_size: 0x2000,
+                    filetype: TexpackEntryType::Texture,
+                },
+                TexpackEntryInfo {
+                    filename: "anim.tga".to_string(),
+                    hash: hash("anim"),
+                    offset: 0x3000,
+                    size: 0x09,
+                    padded_size: 0x1000,
+                    filetype: TexpackEntryType::Tga,
+                },
+            ]
+        );
+        assert_eq!(raw.len(), 0x4000);
+        assert_eq!(texpack.files()[0].hash(), hash("anim"));
+    }
+
+    #[test]
+    fn unknown_entry_type() {
+        let mut texpack = Texpack::new(Console::PC);
+        texpack.add_file("first".to_string(), &DDS_DATA);
+        let mut raw = texpack.to_bytes().unwrap();
+        raw.splice(0x38..0x3C, Console::PC.write_u32(0x07).unwrap());
+
+        match Texpack::from_bytes(&raw, Console::PC) {
+            Err(Error::UnknownTexpackEntryTypeError { value: 0x07 }) => {}
+            _ => panic!("Expected an unknown entry type error"),
+        }
+    }
 }
@@ -1,5 +1,32 @@
+//! The hashing algorithm used by the game to identify files and texpack
+//! entries by name, along with utilities for working with hashes.
+
 /// Hashing algorithm used throughout the game. Creates a 4-byte code from the
 /// given input `name`.
+///
+/// The game uses this to identify each file within a texpack by its name. The
+/// class hashes of serialised objects within .bin files are not produced by
+/// this algorithm.
+///
+/// # Remarks
+///
+/// Starting from 0, for each character of the name the hash is rotated left
+/// by 5 bits, and then XORed with the lowercase form of the character. The
+/// hash is therefore case-insensitive.
+///
+/// Texpack entries only store the first 27 characters of a file's name, but
+/// their hash is of the full name. The hash of a truncated name cannot be
+/// recalculated from the texpack, so must be kept as it is when rebuilding
+/// one.
+///
+/// # Example
+///
+/// ```
+/// use shrek_superslam::hash;
+///
+/// assert_eq!(hash("bk_cape"), 0x53C00A7D);
+/// assert_eq!(hash("BK_CAPE"), hash("bk_cape"));
+/// ```
 pub fn hash(name: &str) -> u32 {
     let mut a: u32 = 0;
 
@@ -14,6 +41,64 @@ pub fn hash(name: &str) -> u32 {
     a
 }
 
+/// Searches for a string, made up of bytes from `charset` and no longer than
+/// `max_len`, that hashes to `target`.
+///
+/// This can be used to recover the name behind a hash found in the game
+/// files, where the name is short or partially known.
+///
+/// # Remarks
+///
+/// The search is exhaustive, trying every string from shortest to longest,
+/// so it tries up to `charset.len()` to the power of `max_len` strings. With
+/// the 26 lowercase letters this is around 300 million strings at a
+/// `max_len` of 6, and becomes impractical much beyond that. Keep `max_len`
+/// small to bound how long the search runs for.
+///
+/// As the hash is case-insensitive, there is no need to include both the
+/// uppercase and lowercase forms of a letter in `charset`. The first match
+/// found is returned, but other strings may hash to the same value.
+///
+/// # Example
+///
+/// ```
+/// use shrek_superslam::hash::{find_preimage, hash};
+///
+/// let charset = b"abcdefghijklmnopqrstuvwxyz";
+/// assert_eq!(find_preimage(hash("cape"), charset, 4), Some("cape".to_owned()));
+/// assert_eq!(find_preimage(hash("cape"), charset, 3), None);
+/// ```
+pub fn find_preimage(target: u32, charset: &[u8], max_len: usize) -> Option<String> {
+    if target == 0 {
+        return Some(String::new());
+    }
+    if charset.is_empty() {
+        return None;
+    }
+
+    for len in 1..=max_len {
+        // Treat the candidate as a number in base charset.len(), counting up
+        // through every string of this length
+        let mut indices = vec![0; len];
+        loop {
+            let candidate: String = indices.iter().map(|&i| charset[i] as char).collect();
+            if hash(&candidate) == target {
+                return Some(candidate);
+            }
+
+            match indices.iter().rposition(|&i| i + 1 < charset.len()) {
+                Some(pos) => {
+                    indices[pos] += 1;
+                    indices[pos + 1..].iter_mut().for_each(|i| *i = 0);
+                }
+                None => break,
+            }
+        }
+    }
+
+    None
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -23,21 +108,35 @@ mod test {
 
     #[test]
     fn hash_1() {
-        assert_eq!(hash(&"1"), 0x00000031);
+        assert_eq!(hash("1"), 0x00000031);
     }
 
     #[test]
     fn hash_bkcape() {
-        assert_eq!(hash(&"bk_cape"), 0x53C00A7D);
+        assert_eq!(hash("bk_cape"), 0x53C00A7D);
     }
 
     #[test]
     fn hash_levelrender_gepettosworkshop() {
-        assert_eq!(hash(&"levelrender_gepettosworkshop"), 0x7535E7DC);
+        assert_eq!(hash("levelrender_gepettosworkshop"), 0x7535E7DC);
     }
 
     #[test]
     fn hash_shrekpuppet_shirtfrontr() {
-        assert_eq!(hash(&"shrekpuppet_shirtfrontr"), 0x873DD7A1);
+        assert_eq!(hash("shrekpuppet_shirtfrontr"), 0x873DD7A1);
+    }
+
+    #[test]
+    fn find_preimage_found() {
+        let charset = b"abcdefghijklmnopqrstuvwxyz0123456789_";
+        let found = find_preimage(0x00000031, charset, 2).unwrap();
+        assert_eq!(hash(&found), 0x00000031);
+        assert_eq!(find_preimage(hash("bk_c"), charset, 4), Some("bk_c".to_owned()));
+    }
+
+    #[test]
+    fn find_preimage_bounded() {
+        assert_eq!(find_preimage(0x53C00A7D, b"abc", 3), None);
+        assert_eq!(find_preimage(0x53C00A7D, b"", 8), None);
     }
 }
@@ -58,6 +58,13 @@
 //!   format to house multiple texture files. These can be opened and
 //!   manipulated with the [`Texpack`](crate::files::Texpack) structure.
 //!
+//! ## Compression
+//!
+//! Every file within the MASTER.DAT is compressed with a simple LZ-style
+//! scheme. The [`compress`] and [`decompress`] functions, along with the
+//! streaming [`Decompressor`], can be used on compressed files found outside
+//! of a MASTER.DAT too.
+//!
 //! ## Example programs
 //!
 //! This crate also contains multiple example programs to assist in working
@@ -65,31 +72,56 @@
 //!
 //! * **shreksuperslam-classes**: Extracts the name and offset of every
 //!   class from every .db.bin file.
+//! * **shreksuperslam-csv**: Exports the damage, knockback, stun and
+//!   hitbox count of every character attack to a single CSV file.
 //! * **shreksuperslam-db**: Extracts every entry in a `gf::DB` object that
 //!   prefaces the given .db.bin file.
+//! * **shreksuperslam-diff**: Lists the files added, removed and changed
+//!   between two MASTER.DAT / MASTER.DIR pairs, along with the `gf::DB`
+//!   objects changed within each .db.bin file.
+//! * **shreksuperslam-dot**: Writes a Graphviz graph of the objects within
+//!   the given .db.bin file and the references between them.
 //! * **shreksuperslam-extract**: Extracts the given MASTER.DAT / MASTER.DIR
 //!   pair to the filesystem.
+//! * **shreksuperslam-find**: Lists every object of the given class, by name
+//!   or hash, across every .db.bin file in the given MASTER.DAT.
+//! * **shreksuperslam-manifest**: Prints the path, sizes, offset and content
+//!   hash of every file within the given MASTER.DAT / MASTER.DIR pair as
+//!   JSON.
 //! * **shreksuperslam-repackage**: Repackages an extracted `data\` folder
 //!   into a MASTER.DAT and MASTER.DIR pair. This can be used in combination
 //!   with the extract program to extract the files, modify them externally,
 //!   and repackage them so that the game runs with the changes.
+//! * **shreksuperslam-replace**: Replaces a single file within the given
+//!   MASTER.DAT / MASTER.DIR pair, writing out a new pair with every other
+//!   file unchanged.
+//! * **shreksuperslam-texpack**: Extracts the textures within a texpack,
+//!   along with a JSON description of each, or packs a directory of textures
+//!   into a new texpack.
+//! * **shreksuperslam-validate**: Decompresses every file within the given
+//!   MASTER.DAT / MASTER.DIR pair and checks the structure of every .db.bin
+//!   file, exiting with an error if any file fails.
 
 // Public interface
+mod compression;
+pub use compression::{
+    compress, decompress, estimated_compressed_size, verify_roundtrip, Decompressor,
+};
+
 mod console;
-pub use console::Console;
+pub use console::{Console, Endianness};
 
 mod errors;
 pub use errors::Error;
 
+pub mod hash;
+pub use hash::hash;
+
 mod master_dat;
-pub use master_dat::MasterDat;
+pub use master_dat::{FileManifestEntry, MasterDat, MergePolicy};
 
 mod master_dir;
-pub use master_dir::MasterDir;
+pub use master_dir::{MasterDir, MasterDirEntry};
 
 pub mod classes;
 pub mod files;
-
-// Internal interface
-mod compression;
-mod hash;
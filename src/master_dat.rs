@@ -1,14 +1,54 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::fs::File;
 use std::io::Write;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::thread;
 
-use crate::compression::{compress, decompress};
+use serde::{Deserialize, Serialize};
+
+use crate::classes;
+use crate::classes::{class_name_for_hash, size_lookup, ClassRegistry};
+use crate::compression::{compress, decompress, Decompressor};
 use crate::console::Console;
 use crate::errors::Error;
+use crate::files::Bin;
 use crate::master_dir::{MasterDir, MasterDirEntry};
 
+/// How [`MasterDat::merge`] handles a file that exists in both archives.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MergePolicy {
+    /// Keep the file already in the archive being merged into
+    KeepExisting,
+
+    /// Replace the file with the one from the archive being merged in
+    Overwrite,
+
+    /// Stop the merge with an error, leaving the archive unchanged
+    Error,
+}
+
+/// Description of a single file within a MASTER.DAT, as returned by
+/// [`MasterDat::manifest`].
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct FileManifestEntry {
+    /// The path of the file within the MASTER.DAT
+    pub path: String,
+
+    /// The size of the file as stored in the MASTER.DAT
+    pub compressed_size: usize,
+
+    /// The size of the file once decompressed
+    pub decompressed_size: usize,
+
+    /// The offset of the file within the MASTER.DAT
+    pub offset: u32,
+
+    /// A hash of the decompressed contents of the file
+    pub content_hash: u64,
+}
+
 /// Structure representing the MASTER.DAT file, which contains all of the
 /// compressed files for Shrek SuperSlam.
 pub struct MasterDat {
@@ -38,17 +78,43 @@ impl MasterDat {
 
     /// Load an existing MASTER.DAT from the bytes that make up the file, using
     /// the given `master_dir` file for the mapping.
-    pub fn from_bytes(master_dat: &[u8], master_dir: MasterDir) -> MasterDat {
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any entry within the `master_dir` describes a file
+    /// that does not fit within the given `master_dat` bytes, such as when the
+    /// MASTER.DAT is truncated or does not belong to the MASTER.DIR.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::fs;
+    /// use std::path::Path;
+    /// use shrek_superslam::{Console, MasterDat, MasterDir};
+    ///
+    /// let master_dir = MasterDir::from_file(Path::new("MASTER.DIR"), Console::PC).unwrap();
+    /// let master_dat_bytes = fs::read("MASTER.DAT").unwrap();
+    /// let master_dat = MasterDat::from_bytes(&master_dat_bytes, master_dir).unwrap();
+    /// ```
+    pub fn from_bytes(master_dat: &[u8], master_dir: MasterDir) -> Result<MasterDat, Error> {
         // Iterate over the entries within the associated MASTER.DIR, and use it
         // to read out each compressed file from the MASTER.DAT
         let mut files: HashMap<String, Vec<u8>> = HashMap::new();
         for entry in &master_dir.entries {
+            let name = entry.name.trim_end_matches(char::from(0)).to_owned();
             let o = entry.offset as usize;
-            let file = master_dat[o..o + entry.comp_size as usize].to_owned();
-            files.insert(entry.name.trim_end_matches(char::from(0)).to_owned(), file);
+            let end = o + entry.comp_size as usize;
+            if end > master_dat.len() {
+                return Err(Error::TruncatedFileError {
+                    path: name,
+                    end,
+                    file_size: master_dat.len(),
+                });
+            }
+            files.insert(name, master_dat[o..end].to_owned());
         }
 
-        MasterDat { files, master_dir }
+        Ok(MasterDat { files, master_dir })
     }
 
     /// Load an existing MASTER.DAT file from the given `path`, using the given
@@ -56,7 +122,8 @@ impl MasterDat {
     ///
     /// # Errors
     ///
-    /// Returns an `Err(std::io::Error)` if there is an error reading the file.
+    /// Returns an error if there is an error reading the file, or if the file
+    /// does not contain every file described by the `master_dir`.
     ///
     /// # Example
     ///
@@ -69,9 +136,9 @@ impl MasterDat {
     /// ```
     pub fn from_file(path: &Path, master_dir: MasterDir) -> Result<MasterDat, Error> {
         // Read all of the file to a byte array
-        match fs::read(&path) {
+        match fs::read(path) {
             // Parse the bytes to a MasterDir object
-            Ok(file_contents) => Ok(MasterDat::from_bytes(&file_contents, master_dir)),
+            Ok(file_contents) => MasterDat::from_bytes(&file_contents, master_dir),
             Err(io_err) => Err(Error::FileError(io_err)),
         }
     }
@@ -88,25 +155,59 @@ impl MasterDat {
     /// master_dat.add_file("data\\test.dds".to_string(), &Vec::new());
     /// ```
     pub fn add_file(&mut self, path: String, data: &[u8]) {
-        // Compress the file
-        let compressed = compress(data);
+        self.add_compressed_file(&path, data.len(), compress(data));
+    }
 
-        // Create an entry for the file in the MASTER.DAT. The offset of the
-        // file within the MASTER.DAT (which is a field in the MASTER.DIR) is
-        // determined by the sum of all the padded sizes of the current files.
-        let offset = self
-            .files
-            .values()
-            .fold(0, |acc, x| acc + padded_size(x.len()));
-        self.master_dir.entries.push(MasterDirEntry {
-            offset: offset as u32,
-            decomp_size: data.len() as u32,
-            comp_size: compressed.len() as u32,
-            name: path.clone(),
+    /// Add each of the given `files`, as pairs of a path and data, to the
+    /// MASTER.DAT, splitting the compression across a thread for each
+    /// available CPU.
+    ///
+    /// The files are added in the order given, so the resulting MASTER.DAT is
+    /// identical to one built by calling [`MasterDat::add_file`] for each file
+    /// in turn.
+    ///
+    /// # Remarks
+    ///
+    /// Only the compression of each file is split across the threads. The
+    /// compressed files are then added one at a time on the calling thread.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use shrek_superslam::{Console, MasterDat};
+    ///
+    /// let mut master_dat = MasterDat::new(Console::PC);
+    /// master_dat.add_files_parallel(vec![
+    ///     ("data\\a.dds".to_string(), vec![0x00; 0x10]),
+    ///     ("data\\b.dds".to_string(), vec![0x01; 0x10]),
+    /// ]);
+    /// assert_eq!(master_dat.files().len(), 2);
+    /// ```
+    pub fn add_files_parallel(&mut self, files: Vec<(String, Vec<u8>)>) {
+        let threads = thread::available_parallelism().map_or(1, |n| n.get());
+        let chunk_size = std::cmp::max(1, files.len().div_ceil(threads));
+
+        // Each thread compresses a contiguous chunk of the files, and the
+        // chunks are joined in order, so the order of the files is kept
+        let compressed: Vec<Vec<u8>> = thread::scope(|scope| {
+            let handles: Vec<_> = files
+                .chunks(chunk_size)
+                .map(|chunk| {
+                    scope.spawn(move || -> Vec<Vec<u8>> {
+                        chunk.iter().map(|(_, data)| compress(data)).collect()
+                    })
+                })
+                .collect();
+
+            handles
+                .into_iter()
+                .flat_map(|handle| handle.join().expect("compress thread panicked"))
+                .collect()
         });
 
-        // Add the compressed file to the MASTER.DAT
-        self.files.insert(path, compress(data));
+        for ((path, data), compressed) in files.into_iter().zip(compressed) {
+            self.add_compressed_file(&path, data.len(), compressed);
+        }
     }
 
     /// Returns the compressed file at the given `path` in the MASTER.DAT if
@@ -123,11 +224,15 @@ impl MasterDat {
     /// let compressed_file = master_dat.compressed_file("data\\players\\shrek\\player.db.bin").unwrap();
     /// ```
     pub fn compressed_file(&self, path: &str) -> Option<Vec<u8>> {
-        self.files.get(path).cloned()
+        self.files.get(&normalise_path(path)).cloned()
     }
 
-    /// Returns the decompressed file at the given `path` in the MASTER.DAT if
-    /// it exists.
+    /// Returns the decompressed file at the given `path` in the MASTER.DAT.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is no file at the given `path`, or if the
+    /// file's compressed data is malformed.
     ///
     /// # Example
     ///
@@ -139,8 +244,342 @@ impl MasterDat {
     /// let master_dat = MasterDat::from_file(Path::new("MASTER.DAT"), master_dir).unwrap();
     /// let decompressed_file = master_dat.decompressed_file("data\\players\\shrek\\player.db.bin").unwrap();
     /// ```
-    pub fn decompressed_file(&self, path: &str) -> Option<Vec<u8>> {
-        self.files.get(path).map(|bytes| decompress(&bytes))
+    pub fn decompressed_file(&self, path: &str) -> Result<Vec<u8>, Error> {
+        let path = normalise_path(path);
+        match self.files.get(&path) {
+            Some(bytes) => decompress(bytes),
+            None => Err(Error::MissingFileError(path)),
+        }
+    }
+
+    /// Returns a reader that decompresses the file at the given `path` in the
+    /// MASTER.DAT as it is read, rather than all at once.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is no file at the given `path`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::io::Read;
+    /// use std::path::Path;
+    /// use shrek_superslam::{Console, MasterDat, MasterDir};
+    ///
+    /// let master_dir = MasterDir::from_file(Path::new("MASTER.DIR"), Console::PC).unwrap();
+    /// let master_dat = MasterDat::from_file(Path::new("MASTER.DAT"), master_dir).unwrap();
+    /// let mut reader = master_dat.decompressed_reader("data\\players\\shrek\\object.texpack").unwrap();
+    /// let mut header = [0; 0x10];
+    /// reader.read_exact(&mut header).unwrap();
+    /// ```
+    pub fn decompressed_reader(&self, path: &str) -> Result<Decompressor<&[u8]>, Error> {
+        let path = normalise_path(path);
+        match self.files.get(&path) {
+            Some(bytes) => Ok(Decompressor::new(bytes.as_slice())),
+            None => Err(Error::MissingFileError(path)),
+        }
+    }
+
+    /// Returns true if there is a file at the given `path` in the MASTER.DAT.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use shrek_superslam::{Console, MasterDat};
+    ///
+    /// let mut master_dat = MasterDat::new(Console::PC);
+    /// master_dat.add_file("data\\test.dds".to_string(), &[0x00; 0x10]);
+    /// assert!(master_dat.contains("data\\test.dds"));
+    /// assert!(!master_dat.contains("data\\missing.dds"));
+    /// ```
+    pub fn contains(&self, path: &str) -> bool {
+        self.files.contains_key(&normalise_path(path))
+    }
+
+    /// Returns the size of the compressed file at the given `path` in the
+    /// MASTER.DAT if it exists.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use shrek_superslam::{Console, MasterDat};
+    ///
+    /// let mut master_dat = MasterDat::new(Console::PC);
+    /// master_dat.add_file("data\\test.dds".to_string(), &[0x00; 0x10]);
+    /// assert!(master_dat.compressed_size("data\\test.dds").is_some());
+    /// assert_eq!(master_dat.compressed_size("data\\missing.dds"), None);
+    /// ```
+    pub fn compressed_size(&self, path: &str) -> Option<usize> {
+        self.files.get(&normalise_path(path)).map(|f| f.len())
+    }
+
+    /// Returns the size of the file at the given `path` in the MASTER.DAT once
+    /// decompressed, if it exists.
+    ///
+    /// The size is read from the MASTER.DIR, so the file is not decompressed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use shrek_superslam::{Console, MasterDat};
+    ///
+    /// let mut master_dat = MasterDat::new(Console::PC);
+    /// master_dat.add_file("data\\test.dds".to_string(), &[0x00; 0x10]);
+    /// assert_eq!(master_dat.decompressed_size("data\\test.dds"), Some(0x10));
+    /// assert_eq!(master_dat.decompressed_size("data\\missing.dds"), None);
+    /// ```
+    pub fn decompressed_size(&self, path: &str) -> Option<usize> {
+        self.entry(&normalise_path(path))
+            .map(|e| e.decomp_size as usize)
+    }
+
+    /// Write every file within the MASTER.DAT to the `dest` directory,
+    /// decompressing each file first if `decompress` is set.
+    ///
+    /// The directory structure of the MASTER.DAT is kept, so for example the
+    /// file "data\\players\\shrek\\player.db.bin" is written to
+    /// "data/players/shrek/player.db.bin" within `dest`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is a problem creating any directory or
+    /// writing any file.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::path::Path;
+    /// use shrek_superslam::{Console, MasterDat, MasterDir};
+    ///
+    /// let master_dir = MasterDir::from_file(Path::new("MASTER.DIR"), Console::PC).unwrap();
+    /// let master_dat = MasterDat::from_file(Path::new("MASTER.DAT"), master_dir).unwrap();
+    /// master_dat.extract_all(Path::new("extracted"), true).unwrap();
+    /// ```
+    pub fn extract_all(&self, dest: &Path, decompress: bool) -> Result<(), Error> {
+        self.extract_all_with_progress(dest, decompress, |_, _| {})
+    }
+
+    /// Write every file within the MASTER.DAT to the `dest` directory in the
+    /// same way as [`MasterDat::extract_all`], calling `progress` after each
+    /// file is written.
+    ///
+    /// The `progress` callback is given the number of files written so far
+    /// and the total number of files.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is a problem creating any directory or
+    /// writing any file.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::path::Path;
+    /// use shrek_superslam::{Console, MasterDat, MasterDir};
+    ///
+    /// let master_dir = MasterDir::from_file(Path::new("MASTER.DIR"), Console::PC).unwrap();
+    /// let master_dat = MasterDat::from_file(Path::new("MASTER.DAT"), master_dir).unwrap();
+    /// master_dat
+    ///     .extract_all_with_progress(Path::new("extracted"), true, |done, total| {
+    ///         println!("{}/{}", done, total);
+    ///     })
+    ///     .unwrap();
+    /// ```
+    pub fn extract_all_with_progress<F>(
+        &self,
+        dest: &Path,
+        decompress: bool,
+        mut progress: F,
+    ) -> Result<(), Error>
+    where
+        F: FnMut(usize, usize),
+    {
+        let files = self.files();
+        for (done, path) in files.iter().enumerate() {
+            self.extract_file(dest, path, decompress)?;
+            progress(done + 1, files.len());
+        }
+
+        Ok(())
+    }
+
+    /// Write every file within the MASTER.DAT to the `dest` directory,
+    /// decompressing each file first if `decompress` is set, splitting the
+    /// work across a thread for each available CPU.
+    ///
+    /// The written files are identical to those written by
+    /// [`MasterDat::extract_all`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is a problem creating any directory or
+    /// writing any file.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::path::Path;
+    /// use shrek_superslam::{Console, MasterDat, MasterDir};
+    ///
+    /// let master_dir = MasterDir::from_file(Path::new("MASTER.DIR"), Console::PC).unwrap();
+    /// let master_dat = MasterDat::from_file(Path::new("MASTER.DAT"), master_dir).unwrap();
+    /// master_dat.extract_all_parallel(Path::new("extracted"), true).unwrap();
+    /// ```
+    pub fn extract_all_parallel(&self, dest: &Path, decompress: bool) -> Result<(), Error> {
+        self.extract_all_parallel_with_progress(dest, decompress, |_, _| {})
+    }
+
+    /// Write every file within the MASTER.DAT to the `dest` directory in the
+    /// same way as [`MasterDat::extract_all_parallel`], calling `progress`
+    /// after each file is written.
+    ///
+    /// The `progress` callback is given the number of files written so far
+    /// and the total number of files. It may be called from any of the
+    /// threads, but never from more than one at once, and the number of files
+    /// written always increases between calls.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is a problem creating any directory or
+    /// writing any file.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::path::Path;
+    /// use shrek_superslam::{Console, MasterDat, MasterDir};
+    ///
+    /// let master_dir = MasterDir::from_file(Path::new("MASTER.DIR"), Console::PC).unwrap();
+    /// let master_dat = MasterDat::from_file(Path::new("MASTER.DAT"), master_dir).unwrap();
+    /// master_dat
+    ///     .extract_all_parallel_with_progress(Path::new("extracted"), true, |done, total| {
+    ///         println!("{}/{}", done, total);
+    ///     })
+    ///     .unwrap();
+    /// ```
+    pub fn extract_all_parallel_with_progress<F>(
+        &self,
+        dest: &Path,
+        decompress: bool,
+        progress: F,
+    ) -> Result<(), Error>
+    where
+        F: FnMut(usize, usize) + Send,
+    {
+        let files = self.files();
+        let total = files.len();
+
+        // The count is kept alongside the callback so that each call sees a
+        // higher count than the last
+        let progress = Mutex::new((0, progress));
+        let threads = thread::available_parallelism().map_or(1, |n| n.get());
+        let chunk_size = std::cmp::max(1, files.len().div_ceil(threads));
+
+        thread::scope(|scope| {
+            let handles: Vec<_> = files
+                .chunks(chunk_size)
+                .map(|paths| {
+                    let progress = &progress;
+                    scope.spawn(move || -> Result<(), Error> {
+                        for path in paths {
+                            self.extract_file(dest, path, decompress)?;
+
+                            let mut progress = progress.lock().expect("progress lock poisoned");
+                            let (done, callback) = &mut *progress;
+                            *done += 1;
+                            callback(*done, total);
+                        }
+                        Ok(())
+                    })
+                })
+                .collect();
+
+            handles
+                .into_iter()
+                .try_for_each(|handle| handle.join().expect("extract thread panicked"))
+        })
+    }
+
+    /// Merge the files from the `other` MASTER.DAT into this one.
+    ///
+    /// Files within `other` that are not already in this MASTER.DAT are added
+    /// after the existing files. Files present in both are handled using the
+    /// given `on_conflict` policy.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `on_conflict` is [`MergePolicy::Error`] and any file
+    /// exists in both, or if the MASTER.DIR of `other` lists a file that
+    /// `other` does not contain. In either case this MASTER.DAT is left
+    /// unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::path::Path;
+    /// use shrek_superslam::{Console, MasterDat, MasterDir, MergePolicy};
+    ///
+    /// // Apply a mod's files on top of the game's files
+    /// let master_dir = MasterDir::from_file(Path::new("MASTER.DIR"), Console::PC).unwrap();
+    /// let mut master_dat = MasterDat::from_file(Path::new("MASTER.DAT"), master_dir).unwrap();
+    /// let mod_dir = MasterDir::from_file(Path::new("MOD.DIR"), Console::PC).unwrap();
+    /// let mod_dat = MasterDat::from_file(Path::new("MOD.DAT"), mod_dir).unwrap();
+    /// master_dat.merge(&mod_dat, MergePolicy::Overwrite).unwrap();
+    /// ```
+    pub fn merge(&mut self, other: &MasterDat, on_conflict: MergePolicy) -> Result<(), Error> {
+        if on_conflict == MergePolicy::Error {
+            if let Some(conflict) = other.files.keys().find(|p| self.files.contains_key(*p)) {
+                return Err(Error::FileConflictError(conflict.clone()));
+            }
+        }
+
+        // Find the compressed file for each MASTER.DIR entry before anything is
+        // changed, in case the other MASTER.DIR lists a file that is missing
+        let other_files = other
+            .master_dir
+            .entries
+            .iter()
+            .map(|entry| {
+                let path = entry.name.trim_end_matches(char::from(0));
+                other
+                    .files
+                    .get(path)
+                    .map(|data| (entry, path, data))
+                    .ok_or_else(|| Error::MissingFileError(path.to_string()))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        // Copy over the compressed file and MASTER.DIR entry of each file,
+        // keeping the order of the other MASTER.DAT
+        for (other_entry, path, data) in other_files {
+            match self
+                .master_dir
+                .entries
+                .iter_mut()
+                .find(|e| e.name.trim_end_matches(char::from(0)) == path)
+            {
+                Some(entry) => {
+                    if on_conflict == MergePolicy::Overwrite {
+                        entry.decomp_size = other_entry.decomp_size;
+                        entry.comp_size = other_entry.comp_size;
+                        self.files.insert(path.to_string(), data.clone());
+                    }
+                }
+                None => {
+                    self.master_dir.entries.push(MasterDirEntry {
+                        offset: 0,
+                        decomp_size: other_entry.decomp_size,
+                        comp_size: other_entry.comp_size,
+                        name: path.to_string(),
+                    });
+                    self.files.insert(path.to_string(), data.clone());
+                }
+            }
+        }
+
+        self.recalculate_offsets();
+
+        Ok(())
     }
 
     /// Returns the filenames within the MASTER.DAT file.
@@ -162,6 +601,299 @@ impl MasterDat {
         self.files.keys().cloned().collect()
     }
 
+    /// Returns an iterator over the path and decompressed contents of every
+    /// file within the MASTER.DAT, in the order of the MASTER.DIR.
+    ///
+    /// Each file is only decompressed as it is reached, giving an error in
+    /// place of its contents if its compressed data is malformed.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::path::Path;
+    /// use shrek_superslam::{Console, MasterDat, MasterDir};
+    ///
+    /// let master_dir = MasterDir::from_file(Path::new("MASTER.DIR"), Console::PC).unwrap();
+    /// let master_dat = MasterDat::from_file(Path::new("MASTER.DAT"), master_dir).unwrap();
+    /// for (path, data) in master_dat.iter() {
+    ///     println!("{} decompressed size {}", path, data.unwrap().len());
+    /// }
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Result<Vec<u8>, Error>)> {
+        self.iter_compressed()
+            .map(|(path, data)| (path, decompress(data)))
+    }
+
+    /// Returns an iterator over the path and compressed contents of every file
+    /// within the MASTER.DAT, in the order of the MASTER.DIR.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::path::Path;
+    /// use shrek_superslam::{Console, MasterDat, MasterDir};
+    ///
+    /// let master_dir = MasterDir::from_file(Path::new("MASTER.DIR"), Console::PC).unwrap();
+    /// let master_dat = MasterDat::from_file(Path::new("MASTER.DAT"), master_dir).unwrap();
+    /// for (path, data) in master_dat.iter_compressed() {
+    ///     println!("{} compressed size {}", path, data.len());
+    /// }
+    /// ```
+    pub fn iter_compressed(&self) -> impl Iterator<Item = (&str, &[u8])> {
+        self.master_dir.entries.iter().filter_map(move |entry| {
+            let path = entry.name.trim_end_matches(char::from(0));
+            self.files
+                .get_key_value(path)
+                .map(|(path, data)| (path.as_str(), data.as_slice()))
+        })
+    }
+
+    /// Returns a description of every file within the MASTER.DAT, in the
+    /// order of the MASTER.DIR.
+    ///
+    /// Each entry holds the path, sizes and offset of a file, along with a
+    /// hash of its contents. The hash is of the decompressed contents, so a
+    /// file that is recompressed differently keeps the same hash.
+    ///
+    /// # Remarks
+    ///
+    /// The hash is the 64-bit FNV-1a hash, which is quick to compute but not
+    /// suitable for detecting deliberate tampering.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any file within the MASTER.DAT fails to
+    /// decompress, or if the MASTER.DIR lists a file that the MASTER.DAT does
+    /// not contain.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use shrek_superslam::{Console, MasterDat};
+    ///
+    /// let mut master_dat = MasterDat::new(Console::PC);
+    /// master_dat.add_file("data\\test.dds".to_string(), &[0x00; 0x10]);
+    /// let manifest = master_dat.manifest().unwrap();
+    /// assert_eq!(manifest[0].path, "data\\test.dds");
+    /// assert_eq!(manifest[0].decompressed_size, 0x10);
+    /// ```
+    pub fn manifest(&self) -> Result<Vec<FileManifestEntry>, Error> {
+        self.master_dir
+            .entries
+            .iter()
+            .map(|entry| {
+                let path = entry.name.trim_end_matches(char::from(0));
+                let compressed = self
+                    .files
+                    .get(path)
+                    .ok_or_else(|| Error::MissingFileError(path.to_string()))?;
+                let decompressed = decompress(compressed)?;
+                Ok(FileManifestEntry {
+                    path: path.to_owned(),
+                    compressed_size: compressed.len(),
+                    decompressed_size: decompressed.len(),
+                    offset: entry.offset,
+                    content_hash: content_hash(&decompressed),
+                })
+            })
+            .collect()
+    }
+
+    /// Check that every file described by the MASTER.DIR is correctly stored
+    /// within the MASTER.DAT.
+    ///
+    /// Each MASTER.DIR entry is checked to lie within the MASTER.DAT, to hold
+    /// exactly its compressed size, and to decompress to exactly its
+    /// decompressed size. Entries may share the same data, as after
+    /// [`MasterDat::deduplicate`], but must not otherwise overlap.
+    ///
+    /// # Remarks
+    ///
+    /// The MASTER.DAT checked is the one that [`MasterDat::to_bytes`] would
+    /// write, counting files that share an offset only once. A MASTER.DIR
+    /// paired with the wrong MASTER.DAT usually fails
+    /// [`MasterDat::from_bytes`] outright, but if its files happen to fit then
+    /// this finds them by their contents instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns the path of every file that fails any of the checks, once
+    /// each, in the order they are listed in the MASTER.DIR.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use shrek_superslam::{Console, MasterDat};
+    ///
+    /// let mut master_dat = MasterDat::new(Console::PC);
+    /// master_dat.add_file("data\\test.dds".to_string(), &[0x00; 0x10]);
+    /// assert!(master_dat.verify_against_dir().is_ok());
+    /// ```
+    pub fn verify_against_dir(&self) -> Result<(), Vec<String>> {
+        let size = self.data_size();
+        let mut problems: HashSet<&str> = HashSet::new();
+        let mut ranges: Vec<(usize, usize, &str)> = vec![];
+        for entry in &self.master_dir.entries {
+            let path = entry.name.trim_end_matches(char::from(0));
+            let (offset, comp_size) = (entry.offset as usize, entry.comp_size as usize);
+            let valid = match self.files.get(path) {
+                Some(compressed) => {
+                    offset + comp_size <= size
+                        && compressed.len() == comp_size
+                        && decompress(compressed)
+                            .map(|d| d.len() == entry.decomp_size as usize)
+                            .unwrap_or(false)
+                }
+                None => false,
+            };
+            if !valid {
+                problems.insert(path);
+            }
+            ranges.push((offset, comp_size, path));
+        }
+
+        // Compare each file against the furthest-reaching file before it
+        ranges.sort_unstable();
+        let mut furthest: Option<(usize, usize, &str)> = None;
+        for (offset, comp_size, path) in ranges {
+            if let Some((other_offset, other_size, other_path)) = furthest {
+                let shared = offset == other_offset && comp_size == other_size;
+                if offset < other_offset + other_size && !shared {
+                    problems.insert(path);
+                    problems.insert(other_path);
+                }
+                if offset + comp_size <= other_offset + other_size {
+                    continue;
+                }
+            }
+            furthest = Some((offset, comp_size, path));
+        }
+
+        if problems.is_empty() {
+            return Ok(());
+        }
+        let mut listed = HashSet::new();
+        Err(self
+            .master_dir
+            .entries
+            .iter()
+            .map(|e| e.name.trim_end_matches(char::from(0)))
+            .filter(|path| problems.contains(path) && listed.insert(*path))
+            .map(|path| path.to_owned())
+            .collect())
+    }
+
+    /// Returns the hash of every class found within the .db.bin files of the
+    /// MASTER.DAT that this library cannot yet read, along with the name of
+    /// the class if it is known and the number of objects of that class.
+    ///
+    /// The list is sorted with the most common class first, which makes it a
+    /// guide to which classes are most worth reverse-engineering next.
+    ///
+    /// # Remarks
+    ///
+    /// Files that fail to decompress or to be read as a .bin file are skipped.
+    /// The `gf::DB` that begins every .bin file is not counted, as it can be
+    /// read. Classes whose hash is not in the table of known classes are
+    /// counted under their hash with no name, rather than the file holding
+    /// them being skipped.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::path::Path;
+    /// use shrek_superslam::{Console, MasterDat, MasterDir};
+    ///
+    /// let master_dir = MasterDir::from_file(Path::new("MASTER.DIR"), Console::PC).unwrap();
+    /// let master_dat = MasterDat::from_file(Path::new("MASTER.DAT"), master_dir).unwrap();
+    /// for (hash, name, count) in master_dat.unsupported_classes() {
+    ///     println!("0x{:08X} ({}): {}", hash, name.unwrap_or("unknown"), count);
+    /// }
+    /// ```
+    pub fn unsupported_classes(&self) -> Vec<(u32, Option<&'static str>, usize)> {
+        let console = self.master_dir.console();
+        let mut counts: HashMap<u32, usize> = HashMap::new();
+        let mut registry = ClassRegistry::new();
+        for (path, data) in self.iter() {
+            if !path.ends_with(".db.bin") {
+                continue;
+            }
+            let data = match data {
+                Ok(data) => data,
+                Err(_) => continue,
+            };
+
+            // A .bin file cannot be read while it holds an object of a class
+            // that is not in the registry, so register each one as it is
+            // found and read the file again
+            let bin = loop {
+                match Bin::with_registry(data.clone(), console, registry.clone()) {
+                    Err(Error::ClassDeserialiseError(classes::Error::IncorrectType { hash }))
+                        if registry.lookup(hash).is_none() =>
+                    {
+                        registry.register(hash, &format!("0x{:08X}", hash))
+                    }
+                    result => break result,
+                }
+            };
+            let bin = match bin {
+                Ok(bin) => bin,
+                Err(_) => continue,
+            };
+
+            // Every class that can be read has a known size
+            for object in bin.objects() {
+                if size_lookup(object.hash).is_none() {
+                    *counts.entry(object.hash).or_insert(0) += 1;
+                }
+            }
+        }
+
+        let mut classes: Vec<(u32, Option<&'static str>, usize)> = counts
+            .into_iter()
+            .map(|(hash, count)| (hash, class_name_for_hash(hash), count))
+            .collect();
+        classes.sort_by(|a, b| b.2.cmp(&a.2).then(a.0.cmp(&b.0)));
+        classes
+    }
+
+    /// Store files with identical compressed contents only once, pointing each
+    /// of their MASTER.DIR entries at the same data.
+    ///
+    /// Returns the number of bytes the MASTER.DAT shrinks by.
+    ///
+    /// # Remarks
+    ///
+    /// The game reads each file by its offset, so any number of entries may
+    /// share the same region of the MASTER.DAT. Updating one of the files
+    /// afterwards gives it its own copy again.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use shrek_superslam::{Console, MasterDat};
+    ///
+    /// let mut master_dat = MasterDat::new(Console::PC);
+    /// master_dat.add_file("data\\a.dds".to_string(), &[0x00; 0x10]);
+    /// master_dat.add_file("data\\b.dds".to_string(), &[0x00; 0x10]);
+    /// assert_eq!(master_dat.deduplicate(), 0x800);
+    /// ```
+    pub fn deduplicate(&mut self) -> usize {
+        let before = self.data_size();
+
+        // Point every entry at the first entry with the same data, and then
+        // lay the files out again to close the gaps left behind
+        let files = &self.files;
+        let mut firsts: HashMap<&[u8], u32> = HashMap::new();
+        for entry in self.master_dir.entries.iter_mut() {
+            let data = files[entry.name.trim_end_matches(char::from(0))].as_slice();
+            entry.offset = *firsts.entry(data).or_insert(entry.offset);
+        }
+        self.recalculate_offsets();
+
+        before - self.data_size()
+    }
+
     /// Returns the raw bytes of the MASTER.DAT file and its associated MASTER.DIR.
     ///
     /// # Remarks
@@ -178,8 +910,13 @@ impl MasterDat {
     /// let (master_dat_bytes, master_dir_bytes) = master_dat.to_bytes().unwrap();
     /// ```
     pub fn to_bytes(&self) -> Result<(Vec<u8>, Vec<u8>), Error> {
+        // Files that share an offset are only written once
+        let mut written = HashSet::new();
         let mut master_dat_bytes = vec![];
         for master_dir_entry in &self.master_dir.entries {
+            if !written.insert(master_dir_entry.offset) {
+                continue;
+            }
             let trimmed = master_dir_entry.name.trim_end_matches(char::from(0));
             master_dat_bytes.extend(&pad(self.files.get(trimmed).unwrap()));
         }
@@ -190,15 +927,13 @@ impl MasterDat {
     /// Update a file located at `path` contained within the MASTER.DAT with
     /// the new supplied `data`.
     ///
-    /// # Notes
-    ///
-    /// Currently, the size new `data` must be the same size as the file it is
-    /// replacing.
+    /// The new `data` may be a different size to the file it is replacing, in
+    /// which case every file after it within the MASTER.DAT is moved to make
+    /// room.
     ///
     /// # Errors
     ///
-    /// Gives an `Err(usize)` with the size of the file being replaced, if the
-    /// size of the supplied `data` does not match.
+    /// Returns an error if there is no file at the given `path`.
     ///
     /// # Example
     ///
@@ -234,43 +969,134 @@ impl MasterDat {
     ///
     /// // We have now overwritten the damage of Shrek's last attack!
     /// ```
-    pub fn update_file(&mut self, path: &str, data: &[u8]) -> Result<(), usize> {
-        // Ensure the file to replace exists in the first place
-        let existing_length = match self.decompressed_file(path) {
-            Some(f) => f.len(),
-            _ => 0,
-        };
-
-        // Ensure the filesize of the new file is the same as the old
-        if data.len() != existing_length {
-            return Err(existing_length);
-        }
-
+    pub fn update_file(&mut self, path: &str, data: &[u8]) -> Result<(), Error> {
         // Since our compression algorithm is not a perfect recreation of the
         // original, we will need to update the MASTER.DIR's record of the
         // compressed size too
-        let compressed = compress(data);
-        if let Some(e) = self
+        self.replace_compressed_file(path, data.len(), compress(data))
+    }
+
+    /// Update a file located at `path` contained within the MASTER.DAT with
+    /// the new supplied `compressed` data, which is stored exactly as given.
+    ///
+    /// As with [`MasterDat::update_file`], the new file may be a different
+    /// size to the file it is replacing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is no file at the given `path`, or if the
+    /// `compressed` data fails to decompress.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use shrek_superslam::{compress, Console, MasterDat};
+    ///
+    /// let mut master_dat = MasterDat::new(Console::PC);
+    /// master_dat.add_file("data\\test.dds".to_string(), &[0x00; 0x10]);
+    /// let compressed = compress(&[0x01; 0x20]);
+    /// master_dat.update_compressed_file("data\\test.dds", &compressed).unwrap();
+    /// assert_eq!(master_dat.compressed_file("data\\test.dds"), Some(compressed));
+    /// ```
+    pub fn update_compressed_file(&mut self, path: &str, compressed: &[u8]) -> Result<(), Error> {
+        let decompressed_size = decompress(compressed)?.len();
+        self.replace_compressed_file(path, decompressed_size, compressed.to_vec())
+    }
+
+    /// Replace the file at the given `path` with the given `compressed` data,
+    /// which is `decompressed_size` bytes once decompressed.
+    fn replace_compressed_file(
+        &mut self,
+        path: &str,
+        decompressed_size: usize,
+        compressed: Vec<u8>,
+    ) -> Result<(), Error> {
+        let path = normalise_path(path);
+        let entry = self
             .master_dir
             .entries
             .iter_mut()
             .find(|e| e.name.trim_end_matches(char::from(0)) == path)
-        {
-            e.comp_size = compressed.len() as u32;
-
-            // Having updated the size of this entry, the offsets to all
-            // subsequent entries will now be invalidated, as the new file
-            // is larger and will take up more space. We therefore recalculate
-            // each offset (it's easier to redo them all)
-            let mut total_size: u32 = 0;
-            for entry in self.master_dir.entries.iter_mut() {
-                entry.offset = total_size;
-                total_size += padded_size(entry.comp_size as usize) as u32;
-            }
-        }
+            .ok_or_else(|| Error::MissingFileError(path.clone()))?;
+        entry.decomp_size = decompressed_size as u32;
+        entry.comp_size = compressed.len() as u32;
 
         // Update the contents of the existing file
-        self.files.insert(path.to_string(), compress(data));
+        self.files.insert(path, compressed);
+
+        // Having updated the size of this entry, the offsets to all subsequent
+        // entries will now be invalidated, as the new file may take up a
+        // different amount of space
+        self.recalculate_offsets();
+
+        Ok(())
+    }
+
+    /// Update a file located at `path` contained within the MASTER.DAT with
+    /// the new supplied `data`, only if the new `data` is the same size as
+    /// the file it is replacing.
+    ///
+    /// # Errors
+    ///
+    /// Gives an `Err(usize)` with the size of the file being replaced, if the
+    /// size of the supplied `data` does not match. If there is no file at the
+    /// given `path`, the size given is 0.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::path::Path;
+    /// use shrek_superslam::{Console, MasterDat, MasterDir};
+    ///
+    /// let master_dir = MasterDir::from_file(Path::new("MASTER.DIR"), Console::PC).unwrap();
+    /// let mut master_dat = MasterDat::from_file(Path::new("MASTER.DAT"), master_dir).unwrap();
+    /// let mut file = master_dat.decompressed_file("data\\players\\shrek\\player.db.bin").unwrap();
+    /// file[0x1000] = 0xFF;
+    /// master_dat.update_file_strict("data\\players\\shrek\\player.db.bin", &file).unwrap();
+    /// ```
+    pub fn update_file_strict(&mut self, path: &str, data: &[u8]) -> Result<(), usize> {
+        let path = normalise_path(path);
+
+        // Ensure the file to replace exists in the first place, and that the
+        // filesize of the new file is the same as the old
+        let existing_length = self
+            .entry(&path)
+            .map(|e| e.decomp_size as usize)
+            .ok_or(0_usize)?;
+        if data.len() != existing_length {
+            return Err(existing_length);
+        }
+
+        self.update_file(&path, data).map_err(|_| existing_length)
+    }
+
+    /// Remove the file at the given `path` from the MASTER.DAT.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is no file at the given `path`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use shrek_superslam::{Console, MasterDat};
+    ///
+    /// let mut master_dat = MasterDat::new(Console::PC);
+    /// master_dat.add_file("data\\test.dds".to_string(), &[0x00; 0x10]);
+    /// master_dat.remove_file("data\\test.dds").unwrap();
+    /// ```
+    pub fn remove_file(&mut self, path: &str) -> Result<(), Error> {
+        let path = normalise_path(path);
+        if self.files.remove(&path).is_none() {
+            return Err(Error::MissingFileError(path));
+        }
+
+        // Remove the file's entry from the MASTER.DIR, and move back every
+        // file that was after it
+        self.master_dir
+            .entries
+            .retain(|e| e.name.trim_end_matches(char::from(0)) != path);
+        self.recalculate_offsets();
 
         Ok(())
     }
@@ -305,6 +1131,133 @@ impl MasterDat {
 
         Ok(())
     }
+
+    /// Write the file at the given `path` within the MASTER.DAT to the same
+    /// path within the `dest` directory, decompressing it first if
+    /// `decompress` is set.
+    fn extract_file(&self, dest: &Path, path: &str, decompress: bool) -> Result<(), Error> {
+        let output_path = extracted_path(dest, path);
+        if let Some(parent) = output_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let data = if decompress {
+            self.decompressed_file(path)?
+        } else {
+            self.compressed_file(path)
+                .ok_or_else(|| Error::MissingFileError(path.to_string()))?
+        };
+        fs::write(output_path, data)?;
+
+        Ok(())
+    }
+
+    /// Add a new file at the given `path` to the end of the MASTER.DAT, given
+    /// its `decompressed_size` and `compressed` data.
+    fn add_compressed_file(&mut self, path: &str, decompressed_size: usize, compressed: Vec<u8>) {
+        let path = normalise_path(path);
+
+        // Create an entry for the file in the MASTER.DAT. The offset of the
+        // file within the MASTER.DAT (which is a field in the MASTER.DIR) is
+        // the end of the current files.
+        let offset = self.data_size();
+        self.master_dir.entries.push(MasterDirEntry {
+            offset: offset as u32,
+            decomp_size: decompressed_size as u32,
+            comp_size: compressed.len() as u32,
+            name: path.clone(),
+        });
+
+        // Add the compressed file to the MASTER.DAT
+        self.files.insert(path, compressed);
+    }
+
+    /// Returns the MASTER.DIR entry for the file at the given, already
+    /// normalised, `path`.
+    fn entry(&self, path: &str) -> Option<&MasterDirEntry> {
+        self.master_dir
+            .entries
+            .iter()
+            .find(|e| e.name.trim_end_matches(char::from(0)) == path)
+    }
+
+    /// Returns the size of the MASTER.DAT, counting files that share an
+    /// offset only once.
+    fn data_size(&self) -> usize {
+        let mut seen = HashSet::new();
+        self.master_dir
+            .entries
+            .iter()
+            .filter(|e| seen.insert(e.offset))
+            .map(|e| padded_size(e.comp_size as usize))
+            .sum()
+    }
+
+    /// Recalculate the offset of every file within the MASTER.DAT, so that
+    /// each file begins directly after the padded file before it.
+    ///
+    /// Entries that shared an offset keep sharing one as long as their data
+    /// is still identical.
+    fn recalculate_offsets(&mut self) {
+        let files = &self.files;
+        let mut placed: HashMap<u32, (u32, &[u8])> = HashMap::new();
+        let mut total_size: u32 = 0;
+        for entry in self.master_dir.entries.iter_mut() {
+            let data = files[entry.name.trim_end_matches(char::from(0))].as_slice();
+            match placed.get(&entry.offset) {
+                Some((offset, shared)) if *shared == data => entry.offset = *offset,
+                _ => {
+                    placed.entry(entry.offset).or_insert((total_size, data));
+                    entry.offset = total_size;
+                    total_size += padded_size(entry.comp_size as usize) as u32;
+                }
+            }
+        }
+    }
+}
+
+/// Calculate the 64-bit FNV-1a hash of the given `data`.
+///
+/// # Parameters
+///
+/// - `data`: The bytes to hash
+///
+/// # Returns
+///
+/// The hash of the bytes
+fn content_hash(data: &[u8]) -> u64 {
+    data.iter().fold(0xCBF29CE484222325, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(0x100000001B3)
+    })
+}
+
+/// Convert the given `path` to the form used within the MASTER.DIR, which
+/// separates directories with backslashes.
+///
+/// # Parameters
+///
+/// - `path`: The path to convert, using either separator
+///
+/// # Returns
+///
+/// The path using only backslashes as separators
+fn normalise_path(path: &str) -> String {
+    path.replace('/', "\\")
+}
+
+/// Get the path a file from the MASTER.DAT is extracted to
+///
+/// # Parameters
+///
+/// - `dest`: The directory the MASTER.DAT is being extracted to
+/// - `path`: The path of the file within the MASTER.DAT
+///
+/// # Returns
+///
+/// The path within `dest` to write the file to
+fn extracted_path(dest: &Path, path: &str) -> PathBuf {
+    path.split('\\')
+        .fold(dest.to_path_buf(), |acc, part| acc.join(part))
 }
 
 /// Get the padded size of a compressed file
@@ -343,3 +1296,372 @@ fn pad(data: &[u8]) -> Vec<u8> {
 
     padded
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn update_file_different_size() {
+        let mut master_dat = MasterDat::new(Console::PC);
+        master_dat.add_file("data\\a.bin".to_string(), &[0x01; 0x10]);
+        master_dat.add_file("data\\b.bin".to_string(), &[0x02; 0x10]);
+        master_dat
+            .update_file("data\\a.bin", &[0x03; 0x1000])
+            .unwrap();
+
+        let (dat, dir) = master_dat.to_bytes().unwrap();
+        let master_dir = MasterDir::from_bytes(&dir, Console::PC).unwrap();
+        let master_dat = MasterDat::from_bytes(&dat, master_dir).unwrap();
+        assert_eq!(master_dat.decompressed_file("data\\a.bin").unwrap(), vec![0x03; 0x1000]);
+        assert_eq!(master_dat.decompressed_file("data\\b.bin").unwrap(), vec![0x02; 0x10]);
+    }
+
+    #[test]
+    fn manifest() {
+        let mut master_dat = MasterDat::new(Console::PC);
+        master_dat.add_file("data\\a.bin".to_string(), &[0x01; 0x10]);
+        master_dat.add_file("data\\b.bin".to_string(), &[0x01; 0x20]);
+        master_dat.add_file("data\\c.bin".to_string(), &[0x01; 0x10]);
+
+        let manifest = master_dat.manifest().unwrap();
+        let paths: Vec<&str> = manifest.iter().map(|e| e.path.as_str()).collect();
+        assert_eq!(paths, vec!["data\\a.bin", "data\\b.bin", "data\\c.bin"]);
+        assert_eq!(manifest[1].decompressed_size, 0x20);
+        assert_eq!(manifest[1].compressed_size, master_dat.compressed_size("data\\b.bin").unwrap());
+        assert_eq!(manifest[0].content_hash, manifest[2].content_hash);
+        assert_ne!(manifest[0].content_hash, manifest[1].content_hash);
+        assert!(manifest[0].offset < manifest[1].offset);
+
+        master_dat.files.remove("data\\c.bin");
+        match master_dat.manifest() {
+            Err(Error::MissingFileError(path)) => assert_eq!(path, "data\\c.bin"),
+            _ => panic!("Expected a missing file error"),
+        }
+    }
+
+    #[test]
+    fn content_hash() {
+        assert_eq!(super::content_hash(b""), 0xCBF29CE484222325);
+        assert_eq!(super::content_hash(b"a"), 0xAF63DC4C8601EC8C);
+    }
+
+    #[test]
+    fn extract_all_progress() {
+        let mut master_dat = MasterDat::new(Console::PC);
+        for i in 0..10 {
+            master_dat.add_file(format!("data\\{}.bin", i), &[i; 0x10]);
+        }
+
+        let dest = std::env::temp_dir().join("shrek-superslam-extract-progress");
+        let mut calls = vec![];
+        master_dat
+            .extract_all_with_progress(&dest, true, |done, total| calls.push((done, total)))
+            .unwrap();
+        assert_eq!(calls, (1..=10).map(|i| (i, 10)).collect::<Vec<_>>());
+
+        let mut calls = vec![];
+        master_dat
+            .extract_all_parallel_with_progress(&dest, true, |done, total| {
+                calls.push((done, total))
+            })
+            .unwrap();
+        assert_eq!(calls, (1..=10).map(|i| (i, 10)).collect::<Vec<_>>());
+        fs::remove_dir_all(&dest).unwrap();
+    }
+
+    #[test]
+    fn add_files_parallel() {
+        let files: Vec<(String, Vec<u8>)> = (0..20u8)
+            .map(|i| (format!("data\\{}.bin", i), vec![i; 0x100 * i as usize]))
+            .collect();
+
+        let mut serial = MasterDat::new(Console::PC);
+        for (path, data) in &files {
+            serial.add_file(path.clone(), data);
+        }
+        let mut parallel = MasterDat::new(Console::PC);
+        parallel.add_files_parallel(files);
+
+        assert_eq!(parallel.to_bytes().unwrap(), serial.to_bytes().unwrap());
+    }
+
+    #[test]
+    fn update_compressed_file() {
+        let mut master_dat = MasterDat::new(Console::PC);
+        master_dat.add_file("data\\a.bin".to_string(), &[0x01; 0x10]);
+        master_dat.add_file("data\\b.bin".to_string(), &[0x02; 0x10]);
+        let b = master_dat.compressed_file("data\\b.bin").unwrap();
+
+        let compressed = compress(&[0x03; 0x1000]);
+        master_dat
+            .update_compressed_file("data\\a.bin", &compressed)
+            .unwrap();
+        assert!(master_dat
+            .update_compressed_file("data\\missing.bin", &compressed)
+            .is_err());
+
+        let (dat, dir) = master_dat.to_bytes().unwrap();
+        let master_dir = MasterDir::from_bytes(&dir, Console::PC).unwrap();
+        let master_dat = MasterDat::from_bytes(&dat, master_dir).unwrap();
+        assert_eq!(master_dat.compressed_file("data\\a.bin").unwrap(), compressed);
+        assert_eq!(master_dat.decompressed_size("data\\a.bin"), Some(0x1000));
+        assert_eq!(master_dat.compressed_file("data\\b.bin").unwrap(), b);
+    }
+
+    #[test]
+    fn mixed_path_separators() {
+        let mut master_dat = MasterDat::new(Console::PC);
+        master_dat.add_file("data/players\\shrek/player.db.bin".to_string(), &[0x01; 0x10]);
+        assert_eq!(master_dat.files(), vec!["data\\players\\shrek\\player.db.bin"]);
+
+        let paths = [
+            "data\\players\\shrek\\player.db.bin",
+            "data/players/shrek/player.db.bin",
+            "data/players\\shrek\\player.db.bin",
+        ];
+        for path in &paths {
+            assert!(master_dat.compressed_file(path).is_some());
+            assert_eq!(master_dat.decompressed_file(path).unwrap(), vec![0x01; 0x10]);
+        }
+
+        master_dat
+            .update_file("data/players/shrek/player.db.bin", &[0x02; 0x10])
+            .unwrap();
+        master_dat
+            .update_file_strict("data/players/shrek\\player.db.bin", &[0x03; 0x10])
+            .unwrap();
+        assert_eq!(master_dat.decompressed_file(paths[0]).unwrap(), vec![0x03; 0x10]);
+
+        master_dat.remove_file("data/players/shrek/player.db.bin").unwrap();
+        assert!(master_dat.files().is_empty());
+        assert!(master_dat.to_bytes().unwrap().0.is_empty());
+    }
+
+    #[test]
+    fn extract_all() {
+        let mut master_dat = MasterDat::new(Console::PC);
+        master_dat.add_file("data\\a.bin".to_string(), &[0x01; 0x10]);
+        master_dat.add_file("data\\sub\\b.bin".to_string(), &[0x02; 0x10]);
+
+        let dest = std::env::temp_dir().join("shrek-superslam-extract-all");
+        let dest_parallel = std::env::temp_dir().join("shrek-superslam-extract-all-parallel");
+        master_dat.extract_all(&dest, true).unwrap();
+        master_dat.extract_all_parallel(&dest_parallel, true).unwrap();
+        for dir in &[&dest, &dest_parallel] {
+            assert_eq!(fs::read(dir.join("data").join("a.bin")).unwrap(), vec![0x01; 0x10]);
+            assert_eq!(
+                fs::read(dir.join("data").join("sub").join("b.bin")).unwrap(),
+                vec![0x02; 0x10]
+            );
+            fs::remove_dir_all(dir).unwrap();
+        }
+    }
+
+    #[test]
+    fn iter() {
+        let mut master_dat = MasterDat::new(Console::PC);
+        master_dat.add_file("data\\b.bin".to_string(), &[0x01; 0x10]);
+        master_dat.add_file("data\\a.bin".to_string(), &[0x02; 0x10]);
+
+        let files: Vec<(&str, Vec<u8>)> = master_dat
+            .iter()
+            .map(|(path, data)| (path, data.unwrap()))
+            .collect();
+        assert_eq!(
+            files,
+            vec![("data\\b.bin", vec![0x01; 0x10]), ("data\\a.bin", vec![0x02; 0x10])]
+        );
+
+        let compressed: Vec<(&str, &[u8])> = master_dat.iter_compressed().collect();
+        assert_eq!(compressed.len(), 2);
+        assert_eq!(compressed[0].0, "data\\b.bin");
+        assert_eq!(compressed[0].1, &master_dat.compressed_file("data\\b.bin").unwrap()[..]);
+    }
+
+    #[test]
+    fn merge() {
+        let create = || {
+            let mut master_dat = MasterDat::new(Console::PC);
+            master_dat.add_file("data\\a.bin".to_string(), &[0x01; 0x10]);
+            master_dat.add_file("data\\b.bin".to_string(), &[0x02; 0x10]);
+            master_dat
+        };
+        let mut other = MasterDat::new(Console::PC);
+        other.add_file("data\\b.bin".to_string(), &[0x03; 0x20]);
+        other.add_file("data\\c.bin".to_string(), &[0x04; 0x10]);
+
+        let mut master_dat = create();
+        assert!(master_dat.merge(&other, MergePolicy::Error).is_err());
+        assert_eq!(master_dat.files().len(), 2);
+
+        let mut master_dat = create();
+        master_dat.merge(&other, MergePolicy::KeepExisting).unwrap();
+        assert_eq!(master_dat.decompressed_file("data\\b.bin").unwrap(), vec![0x02; 0x10]);
+        assert_eq!(master_dat.decompressed_file("data\\c.bin").unwrap(), vec![0x04; 0x10]);
+
+        let mut master_dat = create();
+        master_dat.merge(&other, MergePolicy::Overwrite).unwrap();
+        let (dat, dir) = master_dat.to_bytes().unwrap();
+        let master_dir = MasterDir::from_bytes(&dir, Console::PC).unwrap();
+        let master_dat = MasterDat::from_bytes(&dat, master_dir).unwrap();
+        assert_eq!(master_dat.decompressed_file("data\\a.bin").unwrap(), vec![0x01; 0x10]);
+        assert_eq!(master_dat.decompressed_file("data\\b.bin").unwrap(), vec![0x03; 0x20]);
+        assert_eq!(master_dat.decompressed_file("data\\c.bin").unwrap(), vec![0x04; 0x10]);
+    }
+
+    #[test]
+    fn merge_missing_file() {
+        let mut master_dat = MasterDat::new(Console::PC);
+        master_dat.add_file("data\\a.bin".to_string(), &[0x01; 0x10]);
+        let mut other = MasterDat::new(Console::PC);
+        other.add_file("data\\b.bin".to_string(), &[0x02; 0x10]);
+        other.add_file("data\\c.bin".to_string(), &[0x03; 0x10]);
+        other.files.remove("data\\c.bin");
+
+        match master_dat.merge(&other, MergePolicy::Overwrite) {
+            Err(Error::MissingFileError(path)) => assert_eq!(path, "data\\c.bin"),
+            _ => panic!("Expected a missing file error"),
+        }
+        assert_eq!(master_dat.files().len(), 1);
+    }
+
+    #[test]
+    fn from_bytes_truncated() {
+        let mut master_dat = MasterDat::new(Console::PC);
+        master_dat.add_file("data\\a.bin".to_string(), &[0x01; 0x10]);
+        master_dat.add_file("data\\b.bin".to_string(), &[0x02; 0x10]);
+        let (dat, dir) = master_dat.to_bytes().unwrap();
+
+        let master_dir = MasterDir::from_bytes(&dir, Console::PC).unwrap();
+        assert!(MasterDat::from_bytes(&dat[..0x801], master_dir).is_err());
+        let master_dir = MasterDir::from_bytes(&dir, Console::PC).unwrap();
+        assert!(MasterDat::from_bytes(&dat, master_dir).is_ok());
+    }
+
+    #[test]
+    fn verify_against_dir() {
+        let mut master_dat = MasterDat::new(Console::PC);
+        master_dat.add_file("data\\a.bin".to_string(), &[0x01; 0x10]);
+        master_dat.add_file("data\\b.bin".to_string(), &[0x01; 0x10]);
+        master_dat.add_file("data\\c.bin".to_string(), &[0x02; 0x20]);
+        assert!(master_dat.verify_against_dir().is_ok());
+
+        // Sharing data is expected
+        master_dat.deduplicate();
+        assert!(master_dat.verify_against_dir().is_ok());
+
+        // A file that decompresses to the wrong size
+        master_dat.master_dir.entries[2].decomp_size = 0x10;
+        assert_eq!(
+            master_dat.verify_against_dir(),
+            Err(vec!["data\\c.bin".to_string()])
+        );
+        master_dat.master_dir.entries[2].decomp_size = 0x20;
+
+        // Files that partly overlap, and a file past the end of the MASTER.DAT
+        master_dat.master_dir.entries[1].offset = 0x01;
+        master_dat.master_dir.entries[2].offset = 0x10000;
+        assert_eq!(
+            master_dat.verify_against_dir(),
+            Err(vec![
+                "data\\a.bin".to_string(),
+                "data\\b.bin".to_string(),
+                "data\\c.bin".to_string()
+            ])
+        );
+    }
+
+    #[test]
+    fn update_file_missing() {
+        let mut master_dat = MasterDat::new(Console::PC);
+        assert!(master_dat.update_file("data\\a.bin", &[0x00]).is_err());
+        assert!(master_dat.decompressed_file("data\\a.bin").is_err());
+        assert!(master_dat.decompressed_reader("data\\a.bin").is_err());
+    }
+
+    #[test]
+    fn update_file_strict() {
+        let mut master_dat = MasterDat::new(Console::PC);
+        master_dat.add_file("data\\a.bin".to_string(), &[0x01; 0x10]);
+        assert_eq!(master_dat.update_file_strict("data\\a.bin", &[0x02; 0x20]), Err(0x10));
+        assert_eq!(master_dat.update_file_strict("data\\b.bin", &[0x02; 0x10]), Err(0));
+        assert_eq!(master_dat.update_file_strict("data\\a.bin", &[0x02; 0x10]), Ok(()));
+        assert_eq!(master_dat.decompressed_file("data\\a.bin").unwrap(), vec![0x02; 0x10]);
+    }
+
+    #[test]
+    fn deduplicate() {
+        let mut master_dat = MasterDat::new(Console::PC);
+        master_dat.add_file("data\\a.bin".to_string(), &[0x01; 0x10]);
+        master_dat.add_file("data\\b.bin".to_string(), &[0x02; 0x10]);
+        master_dat.add_file("data\\c.bin".to_string(), &[0x01; 0x10]);
+        master_dat.add_file("data\\d.bin".to_string(), &[0x03; 0x10]);
+        assert_eq!(master_dat.deduplicate(), 0x800);
+        assert_eq!(master_dat.deduplicate(), 0);
+
+        let (dat, dir) = master_dat.to_bytes().unwrap();
+        assert_eq!(dat.len(), 0x1800);
+        let master_dir = MasterDir::from_bytes(&dir, Console::PC).unwrap();
+        let mut master_dat = MasterDat::from_bytes(&dat, master_dir).unwrap();
+        assert_eq!(master_dat.decompressed_file("data\\a.bin").unwrap(), vec![0x01; 0x10]);
+        assert_eq!(master_dat.decompressed_file("data\\c.bin").unwrap(), vec![0x01; 0x10]);
+        assert_eq!(master_dat.decompressed_file("data\\d.bin").unwrap(), vec![0x03; 0x10]);
+
+        // Changing one of the shared files gives it its own copy again
+        master_dat.update_file("data\\c.bin", &[0x04; 0x10]).unwrap();
+        master_dat.add_file("data\\e.bin".to_string(), &[0x05; 0x10]);
+        let (dat, dir) = master_dat.to_bytes().unwrap();
+        assert_eq!(dat.len(), 0x2800);
+        let master_dir = MasterDir::from_bytes(&dir, Console::PC).unwrap();
+        let master_dat = MasterDat::from_bytes(&dat, master_dir).unwrap();
+        assert_eq!(master_dat.decompressed_file("data\\a.bin").unwrap(), vec![0x01; 0x10]);
+        assert_eq!(master_dat.decompressed_file("data\\c.bin").unwrap(), vec![0x04; 0x10]);
+        assert_eq!(master_dat.decompressed_file("data\\e.bin").unwrap(), vec![0x05; 0x10]);
+    }
+
+    #[test]
+    fn unsupported_classes() {
+        let c = Console::PC;
+
+        // gf::DB at +00, then two Game::WeaponType, a Game::Potion, a
+        // Game::AttackMoveRegion and a class that is not known
+        let hashes = [
+            (0x00, 0x9B3DDBED),
+            (0x30, 0xFE392AB6),
+            (0x40, 0xFE392AB6),
+            (0x50, 0xF1E8852E),
+            (0x60, 0xF2CFE08D),
+            (0xA0, 0x12345678),
+        ];
+        let mut data = vec![0x00; 0xB0];
+        for (offset, hash) in &hashes {
+            data.splice(*offset..*offset + 4, c.write_u32(*hash).unwrap());
+        }
+
+        let mut bin = vec![0x00; 0x40];
+        bin.splice(0x10..0x14, c.write_u32(data.len() as u32).unwrap());
+        bin.splice(0x18..0x1C, c.write_u32(1).unwrap());
+        bin.extend(data);
+        bin.extend(c.write_u32(1).unwrap());
+        bin.extend(c.write_u32(hashes.len() as u32).unwrap());
+        bin.extend(vec![0x00; 0x08]);
+        for (offset, _) in &hashes {
+            bin.extend(c.write_u32(*offset as u32).unwrap());
+        }
+
+        let mut master_dat = MasterDat::new(c);
+        master_dat.add_file("data\\a.db.bin".to_string(), &bin);
+        master_dat.add_file("data\\b.db.bin".to_string(), &bin);
+        master_dat.add_file("data\\c.bin".to_string(), &bin);
+        master_dat.add_file("data\\d.db.bin".to_string(), &[0x01; 0x10]);
+        assert_eq!(
+            master_dat.unsupported_classes(),
+            vec![
+                (0xFE392AB6, Some("Game::WeaponType"), 4),
+                (0x12345678, None, 2),
+                (0xF1E8852E, Some("Game::Potion"), 2),
+            ]
+        );
+    }
+}
@@ -1,27 +1,23 @@
 use std::fs;
-use std::iter::repeat;
 use std::path::Path;
 
-use encoding::all::ISO_8859_1;
-use encoding::{DecoderTrap, Encoding};
-
 use crate::console::Console;
 use crate::errors::Error;
 
 /// Type representing a single entry in the game's MASTER.DIR file, which in
 /// turn describes a single compressed file in the MASTER.DAT file.
-pub(crate) struct MasterDirEntry {
+pub struct MasterDirEntry {
     /// The offset of the file in the MASTER.DAT file
-    pub offset: u32,
+    pub(crate) offset: u32,
 
     /// The decompressed size of the file
-    pub decomp_size: u32,
+    pub(crate) decomp_size: u32,
 
     /// The compressed size of the file
-    pub comp_size: u32,
+    pub(crate) comp_size: u32,
 
     /// The path of the file, relative from data/
-    pub name: String,
+    pub(crate) name: String,
 }
 
 impl MasterDirEntry {
@@ -35,7 +31,7 @@ impl MasterDirEntry {
     /// # Returns
     ///
     /// A new MASTER.DIR entry from the provided bytes
-    pub fn new(entry: &[u8], console: Console) -> Result<MasterDirEntry, Error> {
+    pub(crate) fn new(entry: &[u8], console: Console) -> Result<MasterDirEntry, Error> {
         let offset = console.read_u32(&entry[0..4])?;
         let decomp_size = console.read_u32(&entry[4..8])?;
         let comp_size = console.read_u32(&entry[8..12])?;
@@ -44,7 +40,7 @@ impl MasterDirEntry {
             offset,
             decomp_size,
             comp_size,
-            name: ISO_8859_1.decode(&entry[12..].to_vec(), DecoderTrap::Strict)?,
+            name: console.read_str(&entry[12..])?,
         })
     }
 
@@ -57,14 +53,14 @@ impl MasterDirEntry {
     /// # Returns
     ///
     /// The bytes that make up this entry, padded and for the given console
-    pub fn padded(&self, console: Console) -> Result<Vec<u8>, Error> {
+    pub(crate) fn padded(&self, console: Console) -> Result<Vec<u8>, Error> {
         let mut padded: Vec<u8> = vec![];
         padded.extend(&console.write_u32(self.offset)?);
         padded.extend(&console.write_u32(self.decomp_size)?);
         padded.extend(&console.write_u32(self.comp_size)?);
         padded.extend(self.name.as_bytes());
         padded.push(0);
-        padded.extend(repeat(0).take((self.padded_size() - self.size()) as usize));
+        padded.resize(padded.len() + (self.padded_size() - self.size()) as usize, 0);
 
         Ok(padded)
     }
@@ -72,16 +68,53 @@ impl MasterDirEntry {
     /// # Returns
     ///
     /// The size in bytes of the MASTER.DIR entry after padding
-    pub fn padded_size(&self) -> u32 {
-        self.size() + (self.size() % 4)
+    pub(crate) fn padded_size(&self) -> u32 {
+        word_aligned(self.size())
     }
 
     /// # Returns
     ///
     /// The size in bytes of the MASTER.DIR entry
-    pub fn size(&self) -> u32 {
+    pub(crate) fn size(&self) -> u32 {
         (12 + self.name.len() + 1) as u32
     }
+
+    /// Returns the offset of the file's compressed data within the
+    /// MASTER.DAT file.
+    pub fn offset(&self) -> u32 {
+        self.offset
+    }
+
+    /// Returns the size of the file's compressed data within the MASTER.DAT
+    /// file.
+    pub fn comp_size(&self) -> u32 {
+        self.comp_size
+    }
+
+    /// Returns the size of the file once decompressed.
+    pub fn decomp_size(&self) -> u32 {
+        self.decomp_size
+    }
+
+    /// Returns the path of the file, relative from data/.
+    ///
+    /// Any null bytes padding the name within the MASTER.DIR are removed.
+    pub fn name(&self) -> &str {
+        self.name.trim_end_matches(char::from(0))
+    }
+}
+
+/// Round the given `size` up to the next multiple of 4.
+///
+/// # Parameters
+///
+/// - `size`: The size to align
+///
+/// # Returns
+///
+/// The smallest multiple of 4 that is at least `size`
+const fn word_aligned(size: u32) -> u32 {
+    (size + 3) & !3
 }
 
 /// Structure representing the MASTER.DIR file, which details the contents of
@@ -111,6 +144,36 @@ impl MasterDir {
         }
     }
 
+    /// Returns the console this MASTER.DIR is from or for.
+    pub(crate) fn console(&self) -> Console {
+        self.console
+    }
+
+    /// Returns an iterator over the entries within the MASTER.DIR, in the
+    /// order they are listed in the file.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::path::Path;
+    /// use shrek_superslam::{Console, MasterDir};
+    ///
+    /// // Print where each file is within the MASTER.DAT
+    /// let master_dir = MasterDir::from_file(Path::new("MASTER.DIR"), Console::PC).unwrap();
+    /// for entry in master_dir.entries() {
+    ///     println!(
+    ///         "{} at 0x{:08X}: {} bytes, {} decompressed",
+    ///         entry.name(),
+    ///         entry.offset(),
+    ///         entry.comp_size(),
+    ///         entry.decomp_size()
+    ///     );
+    /// }
+    /// ```
+    pub fn entries(&self) -> impl Iterator<Item = &MasterDirEntry> {
+        self.entries.iter()
+    }
+
     /// Returns a new `MasterDir` object for the given `console` from the
     /// passed `master_dir` bytes.
     pub fn from_bytes(master_dir: &[u8], console: Console) -> Result<MasterDir, Error> {
@@ -152,6 +215,87 @@ impl MasterDir {
         Ok(MasterDir { entries, console })
     }
 
+    /// Attempts to determine the console the given `master_dir` bytes come
+    /// from.
+    ///
+    /// Both byte orders are tried, checking that the offsets to each entry
+    /// are within the file and that each entry's name is a printable ASCII
+    /// path.
+    ///
+    /// # Remarks
+    ///
+    /// Only the Gamecube release stores its numbers as big-endian. The PC, PS2
+    /// and Xbox releases lay out their MASTER.DIR identically, so cannot be
+    /// told apart, and all are reported as [`Console::PC`].
+    ///
+    /// # Returns
+    ///
+    /// A Some(Console) if exactly one byte order gives a valid MASTER.DIR, or
+    /// None if neither or both do.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::fs;
+    /// use shrek_superslam::{Console, MasterDir};
+    ///
+    /// let bytes = fs::read("MASTER.DIR").unwrap();
+    /// let console = MasterDir::detect_console(&bytes).unwrap_or(Console::PC);
+    /// let master_dir = MasterDir::from_bytes(&bytes, console).unwrap();
+    /// ```
+    pub fn detect_console(master_dir: &[u8]) -> Option<Console> {
+        let mut valid = [Console::Gamecube, Console::PC]
+            .iter()
+            .cloned()
+            .filter(|c| MasterDir::plausible(master_dir, *c));
+        match (valid.next(), valid.next()) {
+            (Some(console), None) => Some(console),
+            _ => None,
+        }
+    }
+
+    /// Determine if the given `master_dir` bytes make up a valid MASTER.DIR
+    /// when read for the given `console`.
+    fn plausible(master_dir: &[u8], console: Console) -> bool {
+        let read = |i: usize| {
+            master_dir
+                .get(i..i + 4)
+                .and_then(|b| console.read_u32(b).ok())
+                .map(|v| v as usize)
+        };
+
+        // The first offset is the size of the list of offsets, so must fit
+        // within the file and be a whole number of offsets
+        let first_section_length = match read(0) {
+            Some(l) if l >= 8 && l % 4 == 0 && l <= master_dir.len() => l,
+            _ => return false,
+        };
+
+        // Every other offset must point to an entry after the list, with a
+        // readable path as its name
+        let offsets: Option<Vec<usize>> = (0..first_section_length).step_by(4).map(read).collect();
+        let offsets = match offsets {
+            Some(o) if o.last() == Some(&0) => o,
+            _ => return false,
+        };
+        offsets
+            .iter()
+            .zip(offsets.iter().skip(1))
+            .filter(|(offset, _)| **offset != 0)
+            .all(|(offset, next)| {
+                let end = if *next == 0 { master_dir.len() } else { *next };
+                if *offset < first_section_length || end < offset + 12 || end > master_dir.len() {
+                    return false;
+                }
+                let name: Vec<u8> = master_dir[offset + 12..end]
+                    .iter()
+                    .cloned()
+                    .take_while(|b| *b != 0)
+                    .collect();
+                !name.is_empty() && name.iter().all(|b| b.is_ascii_graphic() || *b == b' ')
+            })
+    }
+
     /// Returns a new `MasterDir` object for the given `console` from the file
     /// at the given `path`.
     ///
@@ -169,7 +313,7 @@ impl MasterDir {
     /// ```
     pub fn from_file(path: &Path, console: Console) -> Result<MasterDir, Error> {
         // Read all of the file to a byte array
-        match fs::read(&path) {
+        match fs::read(path) {
             // Parse the bytes to a MasterDir object
             Ok(file_contents) => Ok(MasterDir::from_bytes(&file_contents, console)?),
 
@@ -186,14 +330,10 @@ impl MasterDir {
         // each entry in the second section - is determined from the total
         // number of entries, plus one for the terminator offset. Since the
         // second section starts immediately after, the first offset is also
-        // this value
+        // this value. Each subsequent offset is determined by adding the
+        // padded size of the previous entry
         let mut offset = ((self.entries.len() + 1) * 4) as u32;
-        master_dir_bytes.extend(&self.console.write_u32(offset)?);
-        offset += &self.entries[0].padded_size();
-
-        // Each subsequent offset is determined by adding the padded size of
-        // the previous entry
-        for entry in self.entries.iter().skip(1) {
+        for entry in &self.entries {
             master_dir_bytes.extend(&self.console.write_u32(offset)?);
             offset += entry.padded_size();
         }
@@ -213,6 +353,7 @@ impl MasterDir {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::MasterDat;
 
     #[test]
     fn two_entries_platform_pc() {
@@ -261,4 +402,95 @@ mod test {
         assert_eq!(master_dir.entries[1].comp_size, 0x06);
         assert_eq!(master_dir.entries[1].name, "efgh");
     }
+
+    #[test]
+    fn two_entries_platform_ps2() {
+        // The PS2 release lays out its MASTER.DIR in the same way as the PC
+        let data = vec![
+            0x0C, 0x00, 0x00, 0x00, 0x1C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00,
+            0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x61, 0x62, 0x63, 0x64,
+            0x04, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x65, 0x66,
+            0x67, 0x68,
+        ];
+
+        let master_dir = MasterDir::from_bytes(&data, Console::PS2).unwrap();
+        let pc_master_dir = MasterDir::from_bytes(&data, Console::PC).unwrap();
+
+        assert_eq!(master_dir.entries.len(), 2);
+        for (entry, pc_entry) in master_dir.entries.iter().zip(pc_master_dir.entries.iter()) {
+            assert_eq!(entry.offset, pc_entry.offset);
+            assert_eq!(entry.decomp_size, pc_entry.decomp_size);
+            assert_eq!(entry.comp_size, pc_entry.comp_size);
+            assert_eq!(entry.name, pc_entry.name);
+        }
+
+        // Which also means a PS2 MASTER.DIR cannot be told apart from a PC one
+        let mut master_dat = MasterDat::new(Console::PS2);
+        master_dat.add_file("data\\test.tm2".to_string(), &[0x02; 0x10]);
+        let (_, master_dir) = master_dat.to_bytes().unwrap();
+        assert!(MasterDir::detect_console(&master_dir) == Some(Console::PC));
+        let master_dir = MasterDir::from_bytes(&master_dir, Console::PS2).unwrap();
+        assert_eq!(master_dir.entries[0].name, "data\\test.tm2");
+    }
+
+    #[test]
+    fn entries() {
+        let data = vec![
+            0x0C, 0x00, 0x00, 0x00, 0x1C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00,
+            0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x61, 0x62, 0x63, 0x00,
+            0x04, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x65, 0x66,
+            0x67, 0x68,
+        ];
+
+        let master_dir = MasterDir::from_bytes(&data, Console::PC).unwrap();
+        let entries: Vec<(&str, u32, u32, u32)> = master_dir
+            .entries()
+            .map(|e| (e.name(), e.offset(), e.comp_size(), e.decomp_size()))
+            .collect();
+        assert_eq!(entries, vec![("abc", 0x01, 0x03, 0x02), ("efgh", 0x04, 0x06, 0x05)]);
+    }
+
+    #[test]
+    fn detect_console() {
+        for console in &[Console::Gamecube, Console::PC] {
+            let mut master_dat = MasterDat::new(*console);
+            master_dat.add_file(
+                "data\\players\\shrek\\player.db.bin".to_string(),
+                &[0x01; 0x10],
+            );
+            master_dat.add_file("data\\test.dds".to_string(), &[0x02; 0x10]);
+            let (_, master_dir) = master_dat.to_bytes().unwrap();
+            assert!(MasterDir::detect_console(&master_dir) == Some(*console));
+        }
+
+        assert!(MasterDir::detect_console(&[]).is_none());
+        assert!(MasterDir::detect_console(&[0xFF; 0x20]).is_none());
+    }
+
+    #[test]
+    fn word_aligned_sizes() {
+        let expected = [12, 16, 16, 16, 16, 20, 20, 20, 20];
+        for (size, expected) in (12..=20).zip(expected.iter()) {
+            assert_eq!(word_aligned(size), *expected);
+        }
+    }
+
+    #[test]
+    fn entry_padded_size() {
+        for name_length in 0..8 {
+            let entry = MasterDirEntry {
+                offset: 0,
+                decomp_size: 0,
+                comp_size: 0,
+                name: "a".repeat(name_length),
+            };
+            assert_eq!(entry.padded_size() % 4, 0);
+            assert!(entry.padded_size() >= entry.size());
+            assert!(entry.padded_size() < entry.size() + 4);
+            assert_eq!(
+                entry.padded(Console::PC).unwrap().len(),
+                entry.padded_size() as usize
+            );
+        }
+    }
 }
@@ -0,0 +1,180 @@
+//! Regression tests against the files of a real copy of the game.
+//!
+//! The game's files cannot be committed, so these tests only run when the
+//! `SHREK_MASTER_DAT` environment variable is set to the path of a
+//! MASTER.DAT file, and pass without checking anything otherwise. The
+//! MASTER.DIR is read from the same directory, unless the `SHREK_MASTER_DIR`
+//! environment variable is set to its path. The console is detected from the
+//! MASTER.DIR.
+//!
+//! ```text
+//! SHREK_MASTER_DAT=/path/to/game/MASTER.DAT cargo test --test game_data
+//! ```
+
+use std::env;
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+
+extern crate shrek_superslam;
+use shrek_superslam::classes::{
+    AttackMoveRegion, AttackMoveType, GameWorld, PhysicsFighting, ProjectileType,
+    SerialisedShrekSuperSlamGameObject, Spitter, SpitterKeyframe,
+    WriteableShrekSuperSlamGameObject,
+};
+use shrek_superslam::files::Bin;
+use shrek_superslam::{compress, decompress, verify_roundtrip, Console, MasterDat, MasterDir};
+
+/// Read the MASTER.DAT and MASTER.DIR named by the environment variables.
+///
+/// # Returns
+///
+/// The MASTER.DAT and the console it is from, or None if `SHREK_MASTER_DAT`
+/// is not set.
+fn master_dat() -> Option<(MasterDat, Console)> {
+    let master_dat_path = PathBuf::from(env::var_os("SHREK_MASTER_DAT")?);
+    let master_dir_path = match env::var_os("SHREK_MASTER_DIR") {
+        Some(path) => PathBuf::from(path),
+        None => master_dat_path.with_file_name("MASTER.DIR"),
+    };
+
+    let master_dir_bytes = fs::read(&master_dir_path).unwrap();
+    let console = MasterDir::detect_console(&master_dir_bytes).unwrap_or(Console::PC);
+    let master_dir = MasterDir::from_bytes(&master_dir_bytes, console).unwrap();
+    let master_dat = MasterDat::from_file(&master_dat_path, master_dir).unwrap();
+    Some((master_dat, console))
+}
+
+/// Read and write back every object of type `T` within the `bin`.
+///
+/// # Returns
+///
+/// A description of each object that could not be read, or that changed the
+/// file when written back unchanged.
+fn round_trip<T>(bin: &mut Bin) -> Vec<String>
+where
+    T: SerialisedShrekSuperSlamGameObject + WriteableShrekSuperSlamGameObject,
+{
+    let offsets: Vec<u32> = bin
+        .objects()
+        .iter()
+        .filter(|o| o.hash == T::hash())
+        .map(|o| o.offset)
+        .collect();
+
+    let mut problems = vec![];
+    for offset in offsets {
+        let original = bin.raw().to_vec();
+        let object = match bin.get_object_from_offset::<T>(offset) {
+            Ok(object) => object,
+            Err(e) => {
+                problems.push(format!("{} at {:08X}: {}", T::name(), offset, e));
+                continue;
+            }
+        };
+        bin.overwrite_object(offset, &object).unwrap();
+        if let Some(changed) = bin.raw().iter().zip(&original).position(|(a, b)| a != b) {
+            problems.push(format!(
+                "{} at {:08X}: writing back changed byte {:08X}",
+                T::name(),
+                offset,
+                changed
+            ));
+        }
+    }
+    problems
+}
+
+/// Check a single .db.bin file from the game.
+///
+/// # Returns
+///
+/// A description of each problem found with the file.
+fn check_bin(data: Vec<u8>, console: Console) -> Vec<String> {
+    let mut bin = match Bin::new(data, console) {
+        Ok(bin) => bin,
+        Err(e) => return vec![format!("failed to read: {}", e)],
+    };
+
+    let mut problems = vec![];
+    if let Err(e) = bin.parse_ordered() {
+        problems.push(format!("failed to parse: {}", e));
+    }
+    problems.extend(round_trip::<AttackMoveRegion>(&mut bin));
+    problems.extend(round_trip::<AttackMoveType>(&mut bin));
+    problems.extend(round_trip::<GameWorld>(&mut bin));
+    problems.extend(round_trip::<PhysicsFighting>(&mut bin));
+    problems.extend(round_trip::<ProjectileType>(&mut bin));
+    problems.extend(round_trip::<Spitter>(&mut bin));
+    problems.extend(round_trip::<SpitterKeyframe>(&mut bin));
+    problems
+}
+
+#[test]
+fn every_bin_reads_and_writes_back() {
+    let (master_dat, console) = match master_dat() {
+        Some(master_dat) => master_dat,
+        None => {
+            println!("SHREK_MASTER_DAT is not set, skipping");
+            return;
+        }
+    };
+
+    let mut failures = vec![];
+    for (path, data) in master_dat.iter() {
+        if !path.ends_with(".db.bin") {
+            continue;
+        }
+
+        let problems = match data {
+            Ok(data) => panic::catch_unwind(AssertUnwindSafe(|| check_bin(data, console)))
+                .unwrap_or_else(|_| vec![String::from("panicked")]),
+            Err(e) => vec![format!("failed to decompress: {}", e)],
+        };
+        failures.extend(problems.iter().map(|p| format!("{}: {}", path, p)));
+    }
+
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
+
+#[test]
+fn every_file_compresses_and_decompresses() {
+    let (master_dat, _) = match master_dat() {
+        Some(master_dat) => master_dat,
+        None => {
+            println!("SHREK_MASTER_DAT is not set, skipping");
+            return;
+        }
+    };
+
+    let mut failures = vec![];
+    for (path, compressed) in master_dat.iter_compressed() {
+        let data = match decompress(compressed) {
+            Ok(data) => data,
+            Err(e) => {
+                failures.push(format!("{}: failed to decompress: {}", path, e));
+                continue;
+            }
+        };
+        if !verify_roundtrip(&data) {
+            failures.push(format!(
+                "{}: changed by compressing and decompressing",
+                path
+            ));
+        }
+
+        // Files that the game's own compression shrinks should also be
+        // shrunk by ours
+        let size = compress(&data).len();
+        if compressed.len() < data.len() && size >= data.len() {
+            failures.push(format!(
+                "{}: compressed to {:08X} bytes from {:08X}",
+                path,
+                size,
+                data.len()
+            ));
+        }
+    }
+
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}